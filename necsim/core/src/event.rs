@@ -33,6 +33,18 @@ impl PackedEvent {
     pub fn event_time(&self) -> PositiveF64 {
         unsafe { PositiveF64::new_unchecked(self.event_time.make_positive()) }
     }
+
+    #[must_use]
+    #[inline]
+    pub fn global_lineage_reference(&self) -> &GlobalLineageReference {
+        &self.global_lineage_reference
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn origin(&self) -> &IndexedLocation {
+        &self.origin
+    }
 }
 
 #[allow(dead_code)]
@@ -307,6 +319,22 @@ impl PartialEq for SpeciationEvent {
     }
 }
 
+impl Ord for SpeciationEvent {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Order `SpeciationEvent`s in lexicographical order:
+        //  (1) event_time
+        //  (2) origin
+        // (`global_lineage_reference` and `prior_time` are ignored)
+        (&self.event_time, &self.origin).cmp(&(&other.event_time, &other.origin))
+    }
+}
+
+impl PartialOrd for SpeciationEvent {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Eq for DispersalEvent {}
 
 impl PartialEq for DispersalEvent {
@@ -320,6 +348,27 @@ impl PartialEq for DispersalEvent {
     }
 }
 
+impl Ord for DispersalEvent {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Order `DispersalEvent`s in lexicographical order:
+        //  (1) event_time
+        //  (2) origin
+        //  (3) target
+        // (`global_lineage_reference`, `prior_time`, and `interaction` are ignored)
+        (&self.event_time, &self.origin, &self.target).cmp(&(
+            &other.event_time,
+            &other.origin,
+            &other.target,
+        ))
+    }
+}
+
+impl PartialOrd for DispersalEvent {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 trait F64 {
     fn make_positive(self) -> Self;
     fn make_negative(self) -> Self;
@@ -0,0 +1,287 @@
+use core::num::NonZeroUsize;
+
+use alloc::{
+    collections::{BTreeSet, VecDeque},
+    fmt,
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    event::{DispersalEvent, SpeciationEvent},
+    impl_finalise, impl_report,
+    reporter::Reporter,
+};
+
+/// Reporter wrapper which only forwards the first of several duplicate
+/// speciation or dispersal events to the inner reporter.
+///
+/// Duplicate events are produced by the independent algorithm and by replays
+/// of overlapping event logs. Events are compared by their `PartialEq`
+/// implementation, which ignores the lineage reference, prior time, and
+/// interaction. Reporters that need to observe duplicate events, e.g. to
+/// reconstruct parent-offspring relationships, should not be wrapped, but can
+/// use [`RecentEvents`] to detect them instead.
+///
+/// The last `window` distinct events of each type are remembered, such that
+/// duplicates are also dropped if other events are reported in between. The
+/// default window of one only drops consecutive duplicates.
+///
+/// The wrapper is (de)serialised as its inner reporter.
+#[allow(clippy::module_name_repetitions)]
+pub struct DeduplicatingReporter<R: Reporter> {
+    reporter: R,
+    speciation: RecentEvents<SpeciationEvent>,
+    dispersal: RecentEvents<DispersalEvent>,
+}
+
+impl<R: Reporter> fmt::Debug for DeduplicatingReporter<R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(DeduplicatingReporter))
+            .field("reporter", &self.reporter)
+            .field("window", &self.window())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Reporter + Serialize> Serialize for DeduplicatingReporter<R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.reporter.serialize(serializer)
+    }
+}
+
+impl<'de, R: Reporter + Deserialize<'de>> Deserialize<'de> for DeduplicatingReporter<R> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        R::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<R: Reporter> From<R> for DeduplicatingReporter<R> {
+    fn from(reporter: R) -> Self {
+        Self {
+            reporter,
+            speciation: RecentEvents::default(),
+            dispersal: RecentEvents::default(),
+        }
+    }
+}
+
+impl<R: Reporter> DeduplicatingReporter<R> {
+    #[must_use]
+    pub fn new(reporter: R, window: NonZeroUsize) -> Self {
+        Self {
+            reporter,
+            speciation: RecentEvents::new(window),
+            dispersal: RecentEvents::new(window),
+        }
+    }
+
+    #[must_use]
+    pub fn window(&self) -> NonZeroUsize {
+        self.speciation.window()
+    }
+
+    #[must_use]
+    pub fn inner(&self) -> &R {
+        &self.reporter
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> R {
+        self.reporter
+    }
+}
+
+impl<R: Reporter> Reporter for DeduplicatingReporter<R> {
+    impl_report!(speciation(&mut self, speciation: MaybeUsed<R::ReportSpeciation>) {
+        if !self.speciation.is_duplicate(speciation) {
+            self.reporter.report_speciation(speciation.into());
+        }
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: MaybeUsed<R::ReportDispersal>) {
+        if !self.dispersal.is_duplicate(dispersal) {
+            self.reporter.report_dispersal(dispersal.into());
+        }
+    });
+
+    impl_report!(progress(&mut self, progress: MaybeUsed<R::ReportProgress>) {
+        self.reporter.report_progress(progress.into());
+    });
+
+    impl_finalise!((self) {
+        self.reporter.finalise();
+    });
+
+    fn initialise(&mut self) -> Result<(), alloc::string::String> {
+        self.reporter.initialise()
+    }
+}
+
+/// The last `window` distinct events that have been observed, which are
+/// looked up in `O(log window)` time to detect duplicate events.
+///
+/// The default window of one only remembers the latest distinct event.
+#[derive(Debug, Clone)]
+pub struct RecentEvents<E: Ord + Clone> {
+    window: NonZeroUsize,
+    order: VecDeque<E>,
+    lookup: BTreeSet<E>,
+}
+
+impl<E: Ord + Clone> Default for RecentEvents<E> {
+    fn default() -> Self {
+        // Safety: 1 is non-zero
+        Self::new(unsafe { NonZeroUsize::new_unchecked(1) })
+    }
+}
+
+impl<E: Ord + Clone> RecentEvents<E> {
+    #[must_use]
+    pub fn new(window: NonZeroUsize) -> Self {
+        Self {
+            window,
+            order: VecDeque::with_capacity(window.get()),
+            lookup: BTreeSet::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn window(&self) -> NonZeroUsize {
+        self.window
+    }
+
+    /// Returns the most recently observed distinct event
+    #[must_use]
+    pub fn latest(&self) -> Option<&E> {
+        self.order.back()
+    }
+
+    /// Checks if `event` duplicates one of the recent events, and otherwise
+    /// remembers it
+    pub fn is_duplicate(&mut self, event: &E) -> bool {
+        if self.lookup.contains(event) {
+            return true;
+        }
+
+        self.remember(event.clone());
+
+        false
+    }
+
+    fn remember(&mut self, event: E) {
+        if self.order.len() >= self.window.get() {
+            if let Some(oldest) = self.order.pop_front() {
+                self.lookup.remove(&oldest);
+            }
+        }
+
+        self.lookup.insert(event.clone());
+        self.order.push_back(event);
+    }
+}
+
+impl<E: Ord + Clone> Extend<E> for RecentEvents<E> {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, events: I) {
+        for event in events {
+            if !self.lookup.contains(&event) {
+                self.remember(event);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroUsize;
+
+    use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+    use crate::{
+        event::SpeciationEvent,
+        impl_report,
+        landscape::{IndexedLocation, Location},
+        lineage::GlobalLineageReference,
+        reporter::Reporter,
+    };
+
+    use super::{DeduplicatingReporter, RecentEvents};
+
+    #[derive(Debug, Default)]
+    struct SpeciationCounter(usize);
+
+    impl Reporter for SpeciationCounter {
+        impl_report!(speciation(&mut self, _speciation: Used) {
+            self.0 += 1;
+        });
+
+        impl_report!(dispersal(&mut self, _dispersal: Ignored) {});
+
+        impl_report!(progress(&mut self, _progress: Ignored) {});
+    }
+
+    fn speciation(x: u32) -> SpeciationEvent {
+        SpeciationEvent {
+            global_lineage_reference: unsafe { GlobalLineageReference::from_inner(u64::from(x)) },
+            prior_time: NonNegativeF64::zero(),
+            event_time: PositiveF64::new(1.0).unwrap(),
+            origin: IndexedLocation::new(Location::new(x, 0), 0),
+        }
+    }
+
+    fn count_deduplicated(window: usize, events: &[SpeciationEvent]) -> usize {
+        let mut reporter = DeduplicatingReporter::new(
+            SpeciationCounter::default(),
+            NonZeroUsize::new(window).unwrap(),
+        );
+
+        for event in events {
+            reporter.report_speciation(event.into());
+        }
+
+        reporter.into_inner().0
+    }
+
+    #[test]
+    fn test_consecutive_duplicates() {
+        let events = [speciation(0), speciation(0), speciation(1), speciation(0)];
+
+        assert_eq!(count_deduplicated(1, &events), 3);
+    }
+
+    #[test]
+    fn test_windowed_duplicates() {
+        let events = [speciation(0), speciation(1), speciation(2), speciation(0)];
+
+        assert_eq!(count_deduplicated(1, &events), 4);
+        assert_eq!(count_deduplicated(2, &events), 4);
+        assert_eq!(count_deduplicated(3, &events), 3);
+    }
+
+    #[test]
+    fn test_recent_events_window() {
+        let mut recent = RecentEvents::new(NonZeroUsize::new(2).unwrap());
+
+        assert!(!recent.is_duplicate(&speciation(0)));
+        assert!(!recent.is_duplicate(&speciation(1)));
+        assert!(recent.is_duplicate(&speciation(0)));
+        assert_eq!(recent.latest(), Some(&speciation(1)));
+
+        // The oldest event is forgotten once the window is full
+        assert!(!recent.is_duplicate(&speciation(2)));
+        assert!(!recent.is_duplicate(&speciation(0)));
+        assert!(recent.is_duplicate(&speciation(2)));
+    }
+
+    #[test]
+    fn test_recent_events_extend() {
+        let mut recent = RecentEvents::default();
+
+        recent.extend(Some(speciation(0)));
+        assert!(recent.is_duplicate(&speciation(0)));
+
+        recent.extend([speciation(1), speciation(1), speciation(2)]);
+        assert_eq!(recent.latest(), Some(&speciation(2)));
+        assert!(!recent.is_duplicate(&speciation(1)));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::event::{DispersalEvent, SpeciationEvent};
 
 mod combinator;
+mod deduplicate;
 mod filter;
 mod group;
 mod r#impl;
@@ -15,6 +16,8 @@ pub mod used;
 #[allow(clippy::useless_attribute, clippy::module_name_repetitions)]
 pub use combinator::ReporterCombinator;
 #[allow(clippy::useless_attribute, clippy::module_name_repetitions)]
+pub use deduplicate::{DeduplicatingReporter, RecentEvents};
+#[allow(clippy::useless_attribute, clippy::module_name_repetitions)]
 pub use filter::FilteredReporter;
 #[allow(clippy::useless_attribute, clippy::module_name_repetitions)]
 pub use null::NullReporter;
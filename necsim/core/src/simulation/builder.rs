@@ -144,6 +144,41 @@ impl<
         A: ActiveLineageSampler<M, H, G, S, X, D, C, T, N, E, I>,
     > Simulation<M, H, G, S, X, D, C, T, N, E, I, A>
 {
+    /// Deconstructs the simulation into its components, discarding the
+    /// migration balance.
+    pub fn into_builder(self) -> SimulationBuilder<M, H, G, S, X, D, C, T, N, E, I, A> {
+        let Simulation {
+            maths,
+            habitat,
+            lineage_store,
+            dispersal_sampler,
+            coalescence_sampler,
+            turnover_rate,
+            speciation_probability,
+            emigration_exit,
+            event_sampler,
+            active_lineage_sampler,
+            rng,
+            immigration_entry,
+            migration_balance: _,
+        } = self;
+
+        SimulationBuilder {
+            maths,
+            habitat,
+            lineage_store,
+            dispersal_sampler,
+            coalescence_sampler,
+            turnover_rate,
+            speciation_probability,
+            emigration_exit,
+            event_sampler,
+            active_lineage_sampler,
+            rng,
+            immigration_entry,
+        }
+    }
+
     #[inline]
     pub fn with_mut_split_active_lineage_sampler_and_rng_and_migration_balance<
         Q,
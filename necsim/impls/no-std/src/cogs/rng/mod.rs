@@ -1,3 +1,4 @@
+pub mod philox;
 pub mod rand;
 pub mod seahash;
 pub mod wyhash;
@@ -0,0 +1,196 @@
+use core::marker::PhantomData;
+
+use necsim_core::cogs::{Backup, MathsCore, PrimeableRng, RngCore, SplittableRng};
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+// Philox4x64 constants
+// https://github.com/DEShawResearch/random123/blob/main/include/Random123/philox.h
+const PHILOX_M4X64_0: u64 = 0xd2e7_470e_e14c_6c93;
+const PHILOX_M4X64_1: u64 = 0xca5a_8263_9512_1157;
+const PHILOX_W64_0: u64 = 0x9e37_79b9_7f4a_7c15;
+const PHILOX_W64_1: u64 = 0xbb67_ae85_84ca_a73b;
+
+const PHILOX_ROUNDS: usize = 10;
+
+/// Counter-based Philox4x64-10 RNG from Salmon et al. (2011), "Parallel random
+/// numbers: as easy as 1, 2, 3".
+///
+/// The 128-bit key is made up of the seed and the stream, which is changed by
+/// [`SplittableRng`]. The 256-bit counter is made up of the location and time
+/// indices set by [`PrimeableRng::prime_with`] and a 128-bit block offset.
+/// Distinct (seed, stream, location, time) tuples therefore index disjoint
+/// subsequences of the same bijective mapping.
+#[allow(clippy::module_name_repetitions, clippy::unsafe_derive_deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, TypeLayout)]
+#[serde(from = "PhiloxState", into = "PhiloxState")]
+#[layout(free = "M")]
+#[repr(C)]
+pub struct Philox<M: MathsCore> {
+    key: [u64; 2],
+    counter: [u64; 4],
+    buffer: [u64; 4],
+    index: usize,
+    marker: PhantomData<M>,
+}
+
+#[contract_trait]
+impl<M: MathsCore> Backup for Philox<M> {
+    unsafe fn backup_unchecked(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<M: MathsCore> RngCore<M> for Philox<M> {
+    type Seed = [u8; 8];
+
+    #[must_use]
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        let seed = u64::from_le_bytes(seed);
+
+        Self::new([seed, 0_u64], [0_u64; 4], 0)
+    }
+
+    #[must_use]
+    #[inline]
+    fn sample_u64(&mut self) -> u64 {
+        if self.index >= self.buffer.len() {
+            self.increment_block();
+            self.buffer = philox4x64(self.counter, self.key);
+            self.index = 0;
+        }
+
+        let sample = self.buffer[self.index];
+
+        self.index += 1;
+
+        sample
+    }
+}
+
+impl<M: MathsCore> PrimeableRng<M> for Philox<M> {
+    #[inline]
+    fn prime_with(&mut self, location_index: u64, time_index: u64) {
+        self.counter = [0_u64, 0_u64, time_index, location_index];
+        self.buffer = philox4x64(self.counter, self.key);
+        self.index = 0;
+    }
+}
+
+impl<M: MathsCore> SplittableRng<M> for Philox<M> {
+    fn split(self) -> (Self, Self) {
+        let [seed, stream] = self.key;
+
+        let left = Self::new([seed, stream.wrapping_mul(2)], self.counter, self.index);
+        let right = Self::new(
+            [seed, stream.wrapping_mul(2).wrapping_add(1)],
+            self.counter,
+            self.index,
+        );
+
+        (left, right)
+    }
+
+    fn split_to_stream(self, stream: u64) -> Self {
+        let [seed, _] = self.key;
+
+        Self::new([seed, stream], self.counter, self.index)
+    }
+}
+
+impl<M: MathsCore> Philox<M> {
+    #[must_use]
+    fn new(key: [u64; 2], counter: [u64; 4], index: usize) -> Self {
+        Self {
+            key,
+            counter,
+            buffer: philox4x64(counter, key),
+            index,
+            marker: PhantomData::<M>,
+        }
+    }
+
+    #[inline]
+    fn increment_block(&mut self) {
+        let (lo, carry) = self.counter[0].overflowing_add(1);
+
+        self.counter[0] = lo;
+        self.counter[1] = self.counter[1].wrapping_add(u64::from(carry));
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Philox")]
+#[serde(deny_unknown_fields)]
+struct PhiloxState {
+    seed: u64,
+    stream: u64,
+    counter: [u64; 4],
+    index: u8,
+}
+
+impl<M: MathsCore> From<Philox<M>> for PhiloxState {
+    #[allow(clippy::cast_possible_truncation)]
+    fn from(rng: Philox<M>) -> Self {
+        let [seed, stream] = rng.key;
+
+        Self {
+            seed,
+            stream,
+            counter: rng.counter,
+            // index is always in 0..=4
+            index: rng.index as u8,
+        }
+    }
+}
+
+impl<M: MathsCore> From<PhiloxState> for Philox<M> {
+    fn from(state: PhiloxState) -> Self {
+        Self::new(
+            [state.seed, state.stream],
+            state.counter,
+            usize::from(state.index).min(4),
+        )
+    }
+}
+
+#[inline]
+#[must_use]
+fn philox4x64(mut counter: [u64; 4], mut key: [u64; 2]) -> [u64; 4] {
+    for _ in 0..PHILOX_ROUNDS {
+        counter = philox4x64_round(counter, key);
+
+        // Philox Weyl sequence key schedule
+        key[0] = key[0].wrapping_add(PHILOX_W64_0);
+        key[1] = key[1].wrapping_add(PHILOX_W64_1);
+    }
+
+    counter
+}
+
+#[inline]
+#[must_use]
+fn philox4x64_round(counter: [u64; 4], key: [u64; 2]) -> [u64; 4] {
+    let (hi0, lo0) = mulhilo(PHILOX_M4X64_0, counter[0]);
+    let (hi1, lo1) = mulhilo(PHILOX_M4X64_1, counter[2]);
+
+    [
+        hi1 ^ counter[1] ^ key[0],
+        lo1,
+        hi0 ^ counter[3] ^ key[1],
+        lo0,
+    ]
+}
+
+#[inline]
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+fn mulhilo(a: u64, b: u64) -> (u64, u64) {
+    let r = u128::from(a) * u128::from(b);
+
+    ((r >> 64) as u64, r as u64)
+}
@@ -0,0 +1,293 @@
+use alloc::{vec, vec::Vec};
+
+use necsim_core::cogs::{PrimeableRng, RngCore, SeedableRng, SplittableRng};
+
+use crate::cogs::maths::intrinsics::IntrinsicsMathsCore;
+
+use super::{philox4x64, Philox, PhiloxState};
+
+type Rng = Philox<IntrinsicsMathsCore>;
+
+const SAMPLES: usize = 1 << 20;
+
+#[test]
+fn known_answer_vectors() {
+    // Known answer tests from the Random123 library
+    // https://github.com/DEShawResearch/random123/blob/main/tests/kat_vectors
+    assert_eq!(
+        philox4x64([0_u64; 4], [0_u64; 2]),
+        [
+            0x1655_4d9e_ca36_314c,
+            0xdb20_fe9d_672d_0fdc,
+            0xd7e7_72ce_e186_176b,
+            0x7e68_b68a_ec7b_a23b,
+        ]
+    );
+    assert_eq!(
+        philox4x64([u64::MAX; 4], [u64::MAX; 2]),
+        [
+            0x87b0_92c3_013f_e90b,
+            0x438c_3c67_be8d_0224,
+            0x9cc7_d7c6_9cd7_77b6,
+            0xa09c_aebf_594f_0ba0,
+        ]
+    );
+    assert_eq!(
+        philox4x64(
+            [
+                0x243f_6a88_85a3_08d3,
+                0x1319_8a2e_0370_7344,
+                0xa409_3822_299f_31d0,
+                0x082e_fa98_ec4e_6c89,
+            ],
+            [0x4528_21e6_38d0_1377, 0xbe54_66cf_34e9_0c6c]
+        ),
+        [
+            0xa528_f454_03e6_1d95,
+            0x38c7_2dbd_566e_9788,
+            0xa5a1_610e_72fd_18b5,
+            0x57bd_43b5_e52b_7fe6,
+        ]
+    );
+}
+
+#[test]
+fn state_roundtrip() {
+    let mut rng = Rng::seed_from_u64(42);
+
+    for _ in 0..7 {
+        let _ = rng.sample_u64();
+    }
+
+    let mut restored = Rng::from(PhiloxState::from(rng.clone()));
+
+    for _ in 0..64 {
+        assert_eq!(rng.sample_u64(), restored.sample_u64());
+    }
+}
+
+#[test]
+fn priming_is_deterministic() {
+    let mut rng = Rng::seed_from_u64(24897);
+
+    rng.prime_with(42, 24);
+    let first = sample_vec(&mut rng, 16);
+
+    let _ = sample_vec(&mut rng, 5);
+
+    rng.prime_with(42, 24);
+    let second = sample_vec(&mut rng, 16);
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn monobit_frequency() {
+    let mut rng = Rng::seed_from_u64(471_093);
+
+    let ones: u64 = (0..SAMPLES)
+        .map(|_| u64::from(rng.sample_u64().count_ones()))
+        .sum();
+
+    assert_monobit(ones, SAMPLES as u64 * 64);
+}
+
+#[test]
+fn byte_frequency() {
+    let mut rng = Rng::seed_from_u64(739_139);
+
+    let mut bins = vec![0_u64; 256];
+
+    for _ in 0..SAMPLES {
+        for byte in rng.sample_u64().to_le_bytes() {
+            bins[usize::from(byte)] += 1;
+        }
+    }
+
+    // 255 degrees of freedom, p ~ 1e-6
+    assert_chi_squared(&bins, 375.0);
+}
+
+#[test]
+fn serial_pairs() {
+    let mut rng = Rng::seed_from_u64(248_971);
+
+    let mut bins = vec![0_u64; 256];
+
+    let mut previous = rng.sample_u64() >> 60;
+
+    for _ in 0..SAMPLES {
+        let next = rng.sample_u64() >> 60;
+
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            bins[((previous << 4) | next) as usize] += 1;
+        }
+
+        previous = next;
+    }
+
+    // 255 degrees of freedom, p ~ 1e-6
+    assert_chi_squared(&bins, 375.0);
+}
+
+#[test]
+fn runs_up_and_down() {
+    let mut rng = Rng::seed_from_u64(42);
+
+    let mut runs = 1_u64;
+    let mut previous = rng.sample_u64();
+    let mut ascending = rng.sample_u64() > previous;
+
+    for _ in 0..SAMPLES {
+        let next = rng.sample_u64();
+
+        if (next > previous) != ascending {
+            runs += 1;
+            ascending = !ascending;
+        }
+
+        previous = next;
+    }
+
+    // Expected number of runs up and down: (2n - 1) / 3
+    // Variance of the number of runs up and down: (16n - 29) / 90
+    #[allow(clippy::cast_precision_loss)]
+    let n = (SAMPLES + 2) as f64;
+    #[allow(clippy::cast_precision_loss)]
+    let deviation = runs as f64 - (2.0 * n - 1.0) / 3.0;
+    let variance = (16.0 * n - 29.0) / 90.0;
+
+    assert!(
+        deviation * deviation / variance < 25.0,
+        "runs test failed with z^2 = {}",
+        deviation * deviation / variance
+    );
+}
+
+#[test]
+fn primed_stream_independence() {
+    let mut rng = Rng::seed_from_u64(31_415);
+
+    // Adjacent locations at the same time
+    let mut interleaved = Vec::with_capacity(SAMPLES);
+    for location in 0..(SAMPLES as u64 / 4) {
+        rng.prime_with(location, 0);
+        interleaved.extend(sample_vec(&mut rng, 4));
+    }
+    assert_interleaved_streams_independent(&interleaved);
+
+    // Adjacent times at the same location
+    interleaved.clear();
+    for time in 0..(SAMPLES as u64 / 4) {
+        rng.prime_with(0, time);
+        interleaved.extend(sample_vec(&mut rng, 4));
+    }
+    assert_interleaved_streams_independent(&interleaved);
+}
+
+#[test]
+fn split_stream_independence() {
+    let (mut left, mut right) = Rng::seed_from_u64(27_182).split();
+
+    let mut interleaved = Vec::with_capacity(SAMPLES);
+    for _ in 0..(SAMPLES / 2) {
+        interleaved.push(left.sample_u64());
+        interleaved.push(right.sample_u64());
+    }
+    assert_interleaved_streams_independent(&interleaved);
+
+    let mut interleaved = Vec::with_capacity(SAMPLES);
+    let mut streams = (0..64)
+        .map(|stream| Rng::seed_from_u64(27_182).split_to_stream(stream))
+        .collect::<Vec<_>>();
+    for _ in 0..(SAMPLES / streams.len()) {
+        for stream in &mut streams {
+            interleaved.push(stream.sample_u64());
+        }
+    }
+    assert_interleaved_streams_independent(&interleaved);
+}
+
+#[test]
+fn priming_avalanche() {
+    let mut rng = Rng::seed_from_u64(16_180);
+
+    let mut flipped = 0_u64;
+    let mut total = 0_u64;
+
+    for index in 0..(1_u64 << 12) {
+        for bit in 0..64 {
+            rng.prime_with(index, index);
+            let base = rng.sample_u64();
+
+            rng.prime_with(index ^ (1 << bit), index);
+            flipped += u64::from((base ^ rng.sample_u64()).count_ones());
+
+            rng.prime_with(index, index ^ (1 << bit));
+            flipped += u64::from((base ^ rng.sample_u64()).count_ones());
+
+            total += 128;
+        }
+    }
+
+    // Every flipped input bit should flip each output bit with p = 0.5
+    assert_monobit(flipped, total);
+}
+
+fn sample_vec(rng: &mut Rng, n: usize) -> Vec<u64> {
+    (0..n).map(|_| rng.sample_u64()).collect()
+}
+
+fn assert_monobit(ones: u64, bits: u64) {
+    #[allow(clippy::cast_precision_loss)]
+    let deviation = ones as f64 - (bits as f64) * 0.5;
+    #[allow(clippy::cast_precision_loss)]
+    let variance = (bits as f64) * 0.25;
+
+    assert!(
+        deviation * deviation / variance < 25.0,
+        "monobit test failed with {ones} ones in {bits} bits"
+    );
+}
+
+fn assert_chi_squared(bins: &[u64], critical: f64) {
+    #[allow(clippy::cast_precision_loss)]
+    let expected = (bins.iter().sum::<u64>() as f64) / (bins.len() as f64);
+
+    let chi_squared: f64 = bins
+        .iter()
+        .map(|observed| {
+            #[allow(clippy::cast_precision_loss)]
+            let deviation = (*observed as f64) - expected;
+            deviation * deviation / expected
+        })
+        .sum();
+
+    assert!(
+        chi_squared < critical,
+        "chi-squared test failed with {chi_squared} >= {critical}"
+    );
+}
+
+fn assert_interleaved_streams_independent(interleaved: &[u64]) {
+    // Adjacent samples from different streams should not share bits
+    let ones: u64 = interleaved
+        .windows(2)
+        .map(|pair| u64::from((pair[0] ^ pair[1]).count_ones()))
+        .sum();
+
+    assert_monobit(ones, (interleaved.len() as u64 - 1) * 64);
+
+    // Adjacent samples from different streams should not be correlated
+    let mut bins = vec![0_u64; 256];
+    for pair in interleaved.windows(2) {
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            bins[(((pair[0] >> 60) << 4) | (pair[1] >> 60)) as usize] += 1;
+        }
+    }
+
+    // 255 degrees of freedom, p ~ 1e-6
+    assert_chi_squared(&bins, 375.0);
+}
@@ -0,0 +1,597 @@
+use std::{
+    collections::VecDeque,
+    fmt,
+    fs::{File, OpenOptions},
+    io::{self, BufReader, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+use flate2::{read::DeflateDecoder, write::DeflateEncoder, Compression};
+use serde::Deserialize;
+use thiserror::Error;
+
+use necsim_core::event::PackedEvent;
+use necsim_core_bond::PositiveF64;
+
+use super::{
+    frame::{self, read_u32, FramePayloadError, FRAME_PREFIX_BYTES},
+    index::{SegmentIndex, SegmentIndexBlock},
+    EventLogHeader,
+};
+
+/// Magic bytes at the start of every versioned event log segment.
+///
+/// Legacy segments start with the bincode-encoded minimum event time, a
+/// positive `f64`. The final magic byte sets the sign bit of that `f64`, so a
+/// versioned segment can never be mistaken for a legacy one.
+pub const SEGMENT_MAGIC: [u8; 8] = *b"\xffNECSIM\xff";
+
+/// Version of the block-compressed event log segment format
+pub const SEGMENT_FORMAT_VERSION: u32 = 1;
+
+/// Maximum number of events that are compressed into one block
+pub const SEGMENT_BLOCK_EVENTS: usize = 4096;
+
+/// Maximum size of the encoded segment header, used to detect corruption
+const SEGMENT_MAX_HEADER_BYTES: u32 = 1 << 10;
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentFormat {
+    /// Raw bincode events after an unversioned header
+    Legacy,
+    /// Checksummed and compressed blocks of events after a versioned header
+    Blocked { version: u32 },
+}
+
+impl fmt::Display for SegmentFormat {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Legacy => fmt.write_str("legacy"),
+            Self::Blocked { version } => write!(fmt, "v{version}"),
+        }
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Error, Debug)]
+pub enum SegmentFormatError {
+    #[error("Failed to access the event log segment {path:?}.")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error(
+        "The event log segment {path:?} has format version {version}, but only versions up to \
+         {SEGMENT_FORMAT_VERSION} are supported."
+    )]
+    UnsupportedVersion { path: PathBuf, version: u32 },
+    #[error("The header of the event log segment {path:?} is corrupted.")]
+    CorruptedHeader { path: PathBuf },
+    #[error("Block {block} of the event log segment {path:?} is corrupted.")]
+    CorruptedBlock { path: PathBuf, block: usize },
+    #[error(
+        "The event log segment {path:?} is truncated: it ends after {read} of {expected} events."
+    )]
+    Truncated {
+        path: PathBuf,
+        read: usize,
+        expected: usize,
+    },
+}
+
+/// Writes a sorted event log segment in the latest versioned format, and
+/// returns its random-access index.
+///
+/// # Errors
+///
+/// Fails if writing to the `writer` fails.
+pub fn write_segment<W: Write>(
+    mut writer: W,
+    header: &EventLogHeader,
+    events: &[PackedEvent],
+) -> anyhow::Result<SegmentIndex> {
+    let header = bincode::serialize(header)?;
+    let header_crc =
+        frame::write_frame(&mut writer, SEGMENT_MAGIC, SEGMENT_FORMAT_VERSION, &header)?;
+
+    let mut index = SegmentIndex::new(header_crc);
+    let mut offset = (FRAME_PREFIX_BYTES + header.len()) as u64;
+
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::fast());
+
+    for block in events.chunks(SEGMENT_BLOCK_EVENTS) {
+        for event in block {
+            bincode::serialize_into(&mut encoder, event)?;
+        }
+
+        let compressed = encoder.reset(Vec::new())?;
+
+        index.push_block(offset, block);
+        offset += (3 * std::mem::size_of::<u32>() + compressed.len()) as u64;
+
+        writer.write_all(&u32::try_from(block.len())?.to_le_bytes())?;
+        writer.write_all(&u32::try_from(compressed.len())?.to_le_bytes())?;
+        writer.write_all(&crc32fast::hash(&compressed).to_le_bytes())?;
+        writer.write_all(&compressed)?;
+    }
+
+    writer.flush()?;
+
+    Ok(index)
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct SegmentReader {
+    path: PathBuf,
+    format: SegmentFormat,
+    reader: BufReader<File>,
+    header_crc: Option<u32>,
+    block: VecDeque<PackedEvent>,
+    block_index: usize,
+    // Blocks selected from the index, or `None` to read all blocks in order
+    selected_blocks: Option<VecDeque<SegmentIndexBlock>>,
+    read: usize,
+    expected: usize,
+}
+
+impl fmt::Debug for SegmentReader {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(SegmentReader))
+            .field("path", &self.path)
+            .field("format", &self.format)
+            .field("read", &self.read)
+            .field("expected", &self.expected)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SegmentReader {
+    /// Opens a versioned or legacy event log segment and reads its header.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the segment cannot be opened, has an unsupported version, or
+    /// has a corrupted header.
+    pub fn try_new(path: &Path) -> Result<(EventLogHeader, Self), SegmentFormatError> {
+        let io_error = |source| SegmentFormatError::Io {
+            path: path.to_owned(),
+            source,
+        };
+        let corrupted_header = || SegmentFormatError::CorruptedHeader {
+            path: path.to_owned(),
+        };
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(false)
+            .open(path)
+            .map_err(io_error)?;
+
+        let mut buf_reader = BufReader::new(file);
+
+        let mut magic = [0_u8; SEGMENT_MAGIC.len()];
+        buf_reader.read_exact(&mut magic).map_err(io_error)?;
+
+        let (header, format, header_crc) = if magic == SEGMENT_MAGIC {
+            let version = read_u32(&mut buf_reader).map_err(io_error)?;
+
+            if version == 0 || version > SEGMENT_FORMAT_VERSION {
+                return Err(SegmentFormatError::UnsupportedVersion {
+                    path: path.to_owned(),
+                    version,
+                });
+            }
+
+            let (header_bytes, header_crc) =
+                frame::read_frame_payload(&mut buf_reader, SEGMENT_MAX_HEADER_BYTES).map_err(
+                    |err| match err {
+                        FramePayloadError::Io(err) => io_error(err),
+                        FramePayloadError::Corrupted => corrupted_header(),
+                    },
+                )?;
+
+            let header: EventLogHeader =
+                bincode::deserialize(&header_bytes).map_err(|_| corrupted_header())?;
+
+            (header, SegmentFormat::Blocked { version }, Some(header_crc))
+        } else {
+            let header: LegacyEventLogHeader =
+                bincode::deserialize_from((&magic[..]).chain(&mut buf_reader))
+                    .map_err(|_| corrupted_header())?;
+
+            (header.into(), SegmentFormat::Legacy, None)
+        };
+
+        let expected = header.length();
+
+        Ok((
+            header,
+            Self {
+                path: path.to_owned(),
+                format,
+                reader: buf_reader,
+                header_crc,
+                block: VecDeque::new(),
+                block_index: 0,
+                selected_blocks: None,
+                read: 0,
+                expected,
+            },
+        ))
+    }
+
+    #[must_use]
+    pub fn format(&self) -> SegmentFormat {
+        self.format
+    }
+
+    /// Restricts the reader to the blocks of the segment's `index` that are
+    /// selected by `select`, and discards all already buffered events.
+    ///
+    /// Returns `false`, and leaves the reader unchanged, if the `index` does
+    /// not belong to this segment.
+    pub fn select_blocks<F: FnMut(&SegmentIndexBlock) -> bool>(
+        &mut self,
+        index: &SegmentIndex,
+        mut select: F,
+    ) -> bool {
+        if self.header_crc != Some(index.header_crc())
+            || index
+                .blocks()
+                .iter()
+                .map(SegmentIndexBlock::events)
+                .sum::<usize>()
+                != self.expected
+        {
+            return false;
+        }
+
+        self.block.clear();
+        self.selected_blocks = Some(
+            index
+                .blocks()
+                .iter()
+                .filter(|block| select(block))
+                .cloned()
+                .collect(),
+        );
+
+        true
+    }
+
+    /// Reads the next event, or `None` once all events announced by the
+    /// segment header have been read.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the segment is truncated or corrupted.
+    pub fn read_event(&mut self) -> Result<Option<PackedEvent>, SegmentFormatError> {
+        if self.read >= self.expected {
+            return Ok(None);
+        }
+
+        let event = match self.format {
+            SegmentFormat::Legacy => bincode::deserialize_from(&mut self.reader).ok(),
+            SegmentFormat::Blocked { .. } => {
+                if self.block.is_empty() {
+                    self.read_block()?;
+
+                    // All remaining blocks were skipped using the index
+                    if self.read >= self.expected {
+                        return Ok(None);
+                    }
+                }
+
+                self.block.pop_front()
+            },
+        };
+
+        let Some(event) = event else {
+            return Err(SegmentFormatError::Truncated {
+                path: self.path.clone(),
+                read: self.read,
+                expected: self.expected,
+            });
+        };
+
+        self.read += 1;
+
+        Ok(Some(event))
+    }
+
+    fn read_block(&mut self) -> Result<(), SegmentFormatError> {
+        if let Some(selected_blocks) = &mut self.selected_blocks {
+            let Some(selected) = selected_blocks.pop_front() else {
+                self.read = self.expected;
+                return Ok(());
+            };
+
+            self.reader
+                .seek(SeekFrom::Start(selected.offset()))
+                .map_err(|source| SegmentFormatError::Io {
+                    path: self.path.clone(),
+                    source,
+                })?;
+
+            self.read = selected.events_before();
+            self.block_index = selected.events_before() / SEGMENT_BLOCK_EVENTS;
+        }
+
+        let block = self.block_index;
+        self.block_index += 1;
+
+        let corrupted_block = || SegmentFormatError::CorruptedBlock {
+            path: self.path.clone(),
+            block,
+        };
+
+        // A segment that ends at a block boundary is truncated, not corrupted
+        let Ok(block_events) = read_u32(&mut self.reader) else {
+            return Ok(());
+        };
+        let (Ok(compressed_len), Ok(block_crc)) =
+            (read_u32(&mut self.reader), read_u32(&mut self.reader))
+        else {
+            return Ok(());
+        };
+
+        let remaining = self.expected - self.read;
+
+        if block_events == 0
+            || (block_events as usize) > SEGMENT_BLOCK_EVENTS.min(remaining)
+            || (compressed_len as usize)
+                > SEGMENT_BLOCK_EVENTS * 2 * std::mem::size_of::<PackedEvent>()
+        {
+            return Err(corrupted_block());
+        }
+
+        let mut compressed = vec![0_u8; compressed_len as usize];
+        if self.reader.read_exact(&mut compressed).is_err() {
+            return Ok(());
+        }
+
+        if crc32fast::hash(&compressed) != block_crc {
+            return Err(corrupted_block());
+        }
+
+        let mut decoder = DeflateDecoder::new(compressed.as_slice());
+
+        for _ in 0..block_events {
+            let event = bincode::deserialize_from(&mut decoder).map_err(|_| corrupted_block())?;
+
+            self.block.push_back(event);
+        }
+
+        Ok(())
+    }
+}
+
+/// Segment header of legacy segments, which have no simulation run
+#[derive(Deserialize)]
+struct LegacyEventLogHeader {
+    min_time: PositiveF64,
+    max_time: PositiveF64,
+
+    length: usize,
+
+    with_speciation: bool,
+    with_dispersal: bool,
+}
+
+impl From<LegacyEventLogHeader> for EventLogHeader {
+    fn from(header: LegacyEventLogHeader) -> Self {
+        Self::new(
+            header.min_time,
+            header.max_time,
+            header.length,
+            header.with_speciation,
+            header.with_dispersal,
+            None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use necsim_core::event::PackedEvent;
+
+    use super::{
+        super::testing::{events, write},
+        SegmentFormat, SegmentFormatError, SegmentReader, SEGMENT_BLOCK_EVENTS,
+        SEGMENT_FORMAT_VERSION, SEGMENT_MAGIC,
+    };
+
+    fn read_all(path: &Path) -> (SegmentFormat, Vec<PackedEvent>, Option<SegmentFormatError>) {
+        let (_header, mut reader) = SegmentReader::try_new(path).unwrap();
+
+        let mut events = Vec::new();
+
+        loop {
+            match reader.read_event() {
+                Ok(Some(event)) => events.push(event),
+                Ok(None) => return (reader.format(), events, None),
+                Err(err) => return (reader.format(), events, Some(err)),
+            }
+        }
+    }
+
+    fn header_len(bytes: &[u8]) -> usize {
+        let offset = SEGMENT_MAGIC.len() + 4;
+
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap()) as usize
+    }
+
+    fn blocks_offset(bytes: &[u8]) -> usize {
+        SEGMENT_MAGIC.len() + 3 * 4 + header_len(bytes)
+    }
+
+    #[test]
+    fn round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("segment");
+
+        let events = events(0..SEGMENT_BLOCK_EVENTS * 2 + 10);
+        write(&path, &events);
+
+        let (format, read, error) = read_all(&path);
+
+        assert_eq!(
+            format,
+            SegmentFormat::Blocked {
+                version: SEGMENT_FORMAT_VERSION
+            }
+        );
+        assert!(error.is_none());
+        assert_eq!(read, events);
+    }
+
+    #[test]
+    fn truncated_segment() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("segment");
+
+        let events = events(0..SEGMENT_BLOCK_EVENTS + 10);
+        let bytes = write(&path, &events);
+
+        // Truncate within the second block
+        fs::write(&path, &bytes[..bytes.len() - 8]).unwrap();
+
+        let (_format, read, error) = read_all(&path);
+
+        assert_eq!(read, events[..SEGMENT_BLOCK_EVENTS]);
+        assert!(matches!(
+            error,
+            Some(SegmentFormatError::Truncated { read, expected, .. })
+                if read == SEGMENT_BLOCK_EVENTS && expected == events.len()
+        ));
+
+        // Truncate at the block boundary
+        let compressed_len_offset = blocks_offset(&bytes) + 4;
+        let compressed_len = u32::from_le_bytes(
+            bytes[compressed_len_offset..compressed_len_offset + 4]
+                .try_into()
+                .unwrap(),
+        ) as usize;
+        fs::write(&path, &bytes[..compressed_len_offset + 8 + compressed_len]).unwrap();
+
+        let (_format, read, error) = read_all(&path);
+
+        assert_eq!(read, events[..SEGMENT_BLOCK_EVENTS]);
+        assert!(matches!(error, Some(SegmentFormatError::Truncated { .. })));
+    }
+
+    #[test]
+    fn flipped_block_byte() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("segment");
+
+        let events = events(0..SEGMENT_BLOCK_EVENTS + 10);
+        let mut bytes = write(&path, &events);
+
+        // Flip the last byte of the compressed second block
+        *bytes.last_mut().unwrap() ^= 0x01;
+        fs::write(&path, &bytes).unwrap();
+
+        let (_format, read, error) = read_all(&path);
+
+        assert_eq!(read, events[..SEGMENT_BLOCK_EVENTS]);
+        assert!(matches!(
+            error,
+            Some(SegmentFormatError::CorruptedBlock { block: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn flipped_header_byte() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("segment");
+
+        let mut bytes = write(&path, &events(0..10));
+
+        let header_end = blocks_offset(&bytes);
+        bytes[header_end - 1] ^= 0x01;
+        fs::write(&path, &bytes).unwrap();
+
+        assert!(matches!(
+            SegmentReader::try_new(&path),
+            Err(SegmentFormatError::CorruptedHeader { .. })
+        ));
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("segment");
+
+        let mut bytes = write(&path, &events(0..10));
+
+        bytes[SEGMENT_MAGIC.len()..SEGMENT_MAGIC.len() + 4]
+            .copy_from_slice(&(SEGMENT_FORMAT_VERSION + 1).to_le_bytes());
+        fs::write(&path, &bytes).unwrap();
+
+        assert!(matches!(
+            SegmentReader::try_new(&path),
+            Err(SegmentFormatError::UnsupportedVersion { version, .. })
+                if version == SEGMENT_FORMAT_VERSION + 1
+        ));
+    }
+
+    fn legacy_header(events: &[PackedEvent]) -> Vec<u8> {
+        bincode::serialize(&(
+            events.first().unwrap().event_time(),
+            events.last().unwrap().event_time(),
+            events.len(),
+            true,
+            false,
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn legacy_segment() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("segment");
+
+        let events = events(0..10);
+
+        let mut bytes = legacy_header(&events);
+        for event in &events {
+            bincode::serialize_into(&mut bytes, event).unwrap();
+        }
+        fs::write(&path, &bytes).unwrap();
+
+        let (format, read, error) = read_all(&path);
+
+        assert_eq!(format, SegmentFormat::Legacy);
+        assert!(error.is_none());
+        assert_eq!(read, events);
+
+        fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+
+        let (_format, read, error) = read_all(&path);
+
+        assert_eq!(read, events[..events.len() - 1]);
+        assert!(matches!(error, Some(SegmentFormatError::Truncated { .. })));
+    }
+
+    #[test]
+    fn oversized_header() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("segment");
+
+        let mut bytes = write(&path, &events(0..10));
+
+        bytes[SEGMENT_MAGIC.len() + 4..SEGMENT_MAGIC.len() + 8]
+            .copy_from_slice(&u32::MAX.to_le_bytes());
+        fs::write(&path, &bytes).unwrap();
+
+        assert!(matches!(
+            SegmentReader::try_new(&path),
+            Err(SegmentFormatError::CorruptedHeader { .. })
+        ));
+    }
+}
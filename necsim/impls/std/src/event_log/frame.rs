@@ -0,0 +1,144 @@
+use std::io::{self, Read, Write};
+
+/// Magic bytes, format version, payload length, and payload checksum
+pub(super) const FRAME_PREFIX_BYTES: usize = 8 + 3 * std::mem::size_of::<u32>();
+
+#[derive(Debug)]
+pub(super) enum FramePayloadError {
+    Io(io::Error),
+    Corrupted,
+}
+
+/// Writes the `payload`, framed by the `magic` bytes, the format `version`,
+/// and the payload's length and checksum, and returns the checksum.
+///
+/// This framing is shared by event log segments and their sidecar files.
+pub(super) fn write_frame<W: Write>(
+    writer: &mut W,
+    magic: [u8; 8],
+    version: u32,
+    payload: &[u8],
+) -> anyhow::Result<u32> {
+    let payload_crc = crc32fast::hash(payload);
+
+    writer.write_all(&magic)?;
+    writer.write_all(&version.to_le_bytes())?;
+    writer.write_all(&u32::try_from(payload.len())?.to_le_bytes())?;
+    writer.write_all(&payload_crc.to_le_bytes())?;
+    writer.write_all(payload)?;
+
+    Ok(payload_crc)
+}
+
+/// Reads the checksummed payload of a frame whose magic bytes and version
+/// have already been read, and returns it together with its checksum.
+///
+/// Payloads that are longer than `max_len` bytes are rejected as corrupted
+/// before any memory is allocated for them.
+pub(super) fn read_frame_payload<R: Read>(
+    reader: &mut R,
+    max_len: u32,
+) -> Result<(Vec<u8>, u32), FramePayloadError> {
+    let payload_len = read_u32(reader).map_err(FramePayloadError::Io)?;
+    let payload_crc = read_u32(reader).map_err(FramePayloadError::Io)?;
+
+    if payload_len > max_len {
+        return Err(FramePayloadError::Corrupted);
+    }
+
+    // Only allocate as much memory as the file actually contains
+    let mut payload = Vec::new();
+    reader
+        .take(u64::from(payload_len))
+        .read_to_end(&mut payload)
+        .map_err(FramePayloadError::Io)?;
+
+    if payload.len() != (payload_len as usize) {
+        return Err(FramePayloadError::Io(io::Error::from(
+            io::ErrorKind::UnexpectedEof,
+        )));
+    }
+
+    if crc32fast::hash(&payload) != payload_crc {
+        return Err(FramePayloadError::Corrupted);
+    }
+
+    Ok((payload, payload_crc))
+}
+
+/// Reads the payload of a frame with the expected `magic` bytes and format
+/// `version`, or `None` if the frame is different, truncated, or corrupted.
+pub(super) fn try_read_frame<R: Read>(
+    reader: &mut R,
+    magic: [u8; 8],
+    version: u32,
+    max_len: u32,
+) -> Option<Vec<u8>> {
+    let mut frame_magic = [0_u8; 8];
+    reader.read_exact(&mut frame_magic).ok()?;
+
+    if frame_magic != magic || read_u32(reader).ok()? != version {
+        return None;
+    }
+
+    read_frame_payload(reader, max_len)
+        .ok()
+        .map(|(payload, _)| payload)
+}
+
+pub(super) fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut bytes = [0_u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_u32, try_read_frame, write_frame, FRAME_PREFIX_BYTES};
+
+    const MAGIC: [u8; 8] = *b"\xffNECTST\xff";
+
+    fn frame(payload: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let crc = write_frame(&mut bytes, MAGIC, 1, payload).unwrap();
+
+        assert_eq!(crc, crc32fast::hash(payload));
+        assert_eq!(bytes.len(), FRAME_PREFIX_BYTES + payload.len());
+
+        bytes
+    }
+
+    #[test]
+    fn round_trip() {
+        let bytes = frame(b"payload");
+
+        assert_eq!(
+            try_read_frame(&mut bytes.as_slice(), MAGIC, 1, 64).as_deref(),
+            Some(&b"payload"[..])
+        );
+        assert!(try_read_frame(&mut bytes.as_slice(), *b"\xffNECOTH\xff", 1, 64).is_none());
+        assert!(try_read_frame(&mut bytes.as_slice(), MAGIC, 2, 64).is_none());
+        assert!(try_read_frame(&mut bytes.as_slice(), MAGIC, 1, 6).is_none());
+    }
+
+    #[test]
+    fn truncated_or_corrupted_payload() {
+        let mut bytes = frame(b"payload");
+
+        assert!(try_read_frame(&mut &bytes[..bytes.len() - 1], MAGIC, 1, 64).is_none());
+
+        *bytes.last_mut().unwrap() ^= 0x01;
+        assert!(try_read_frame(&mut bytes.as_slice(), MAGIC, 1, 64).is_none());
+    }
+
+    #[test]
+    fn untrusted_length_is_not_allocated() {
+        let mut bytes = frame(b"payload");
+
+        // Claim the maximum payload length, which is only read up to the end
+        bytes[8 + 4..8 + 8].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(read_u32(&mut &bytes[8 + 4..]).unwrap(), u32::MAX);
+
+        assert!(try_read_frame(&mut bytes.as_slice(), MAGIC, 1, u32::MAX).is_none());
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use necsim_core::{event::PackedEvent, lineage::GlobalLineageReference};
+use necsim_core_bond::PositiveF64;
+
+use super::frame;
+
+/// Magic bytes at the start of every sidecar event log segment index
+pub const SEGMENT_INDEX_MAGIC: [u8; 8] = *b"\xffNECIDX\xff";
+
+/// Version of the sidecar event log segment index format
+pub const SEGMENT_INDEX_FORMAT_VERSION: u32 = 1;
+
+/// File extension of the sidecar index, which is stored next to its segment
+pub const SEGMENT_INDEX_EXTENSION: &str = "index";
+
+/// Maximum size of the encoded index, used to detect corruption
+const SEGMENT_INDEX_MAX_BYTES: u32 = 1 << 30;
+
+/// Random-access index of a versioned event log segment, which maps the time
+/// and lineage reference ranges of every block of events to its byte offset
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentIndex {
+    /// Checksum of the segment header, used to detect stale indices
+    header_crc: u32,
+    blocks: Vec<SegmentIndexBlock>,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentIndexBlock {
+    offset: u64,
+    events_before: usize,
+    events: usize,
+
+    min_time: PositiveF64,
+    max_time: PositiveF64,
+
+    min_lineage: GlobalLineageReference,
+    max_lineage: GlobalLineageReference,
+}
+
+impl SegmentIndex {
+    #[must_use]
+    pub(super) fn new(header_crc: u32) -> Self {
+        Self {
+            header_crc,
+            blocks: Vec::new(),
+        }
+    }
+
+    /// Adds the next block of sorted `events`, which starts `offset` bytes
+    /// into the segment
+    pub(super) fn push_block(&mut self, offset: u64, events: &[PackedEvent]) {
+        let events_before = self
+            .blocks
+            .last()
+            .map_or(0, |block| block.events_before + block.events);
+
+        let (Some(first), Some(last)) = (events.first(), events.last()) else {
+            return;
+        };
+
+        let mut min_lineage = first.global_lineage_reference();
+        let mut max_lineage = first.global_lineage_reference();
+
+        for event in events {
+            min_lineage = min_lineage.min(event.global_lineage_reference());
+            max_lineage = max_lineage.max(event.global_lineage_reference());
+        }
+
+        self.blocks.push(SegmentIndexBlock {
+            offset,
+            events_before,
+            events: events.len(),
+            min_time: first.event_time(),
+            max_time: last.event_time(),
+            min_lineage: min_lineage.clone(),
+            max_lineage: max_lineage.clone(),
+        });
+    }
+
+    #[must_use]
+    pub fn header_crc(&self) -> u32 {
+        self.header_crc
+    }
+
+    #[must_use]
+    pub fn blocks(&self) -> &[SegmentIndexBlock] {
+        &self.blocks
+    }
+
+    /// Returns the path of the sidecar index of the segment at `segment`
+    #[must_use]
+    pub fn path_of(segment: &Path) -> PathBuf {
+        let mut path = segment.as_os_str().to_owned();
+        path.push(".");
+        path.push(SEGMENT_INDEX_EXTENSION);
+        PathBuf::from(path)
+    }
+
+    /// Checks if the file at `path` is a sidecar index instead of a segment
+    #[must_use]
+    pub fn is_index_path(path: &Path) -> bool {
+        path.extension()
+            .map_or(false, |extension| extension == SEGMENT_INDEX_EXTENSION)
+    }
+
+    /// Writes the sidecar index of the segment at `segment`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the index file cannot be written.
+    pub fn write(&self, segment: &Path) -> anyhow::Result<()> {
+        let index = bincode::serialize(self)?;
+
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(Self::path_of(segment))?;
+        let mut writer = BufWriter::new(file);
+
+        frame::write_frame(
+            &mut writer,
+            SEGMENT_INDEX_MAGIC,
+            SEGMENT_INDEX_FORMAT_VERSION,
+            &index,
+        )?;
+
+        writer.into_inner()?;
+
+        Ok(())
+    }
+
+    /// Reads the sidecar index of the segment at `segment`, if it exists and
+    /// is intact. A missing or unreadable index only disables seeking.
+    #[must_use]
+    pub fn try_read(segment: &Path) -> Option<Self> {
+        let file = File::open(Self::path_of(segment)).ok()?;
+        let index = frame::try_read_frame(
+            &mut BufReader::new(file),
+            SEGMENT_INDEX_MAGIC,
+            SEGMENT_INDEX_FORMAT_VERSION,
+            SEGMENT_INDEX_MAX_BYTES,
+        )?;
+
+        bincode::deserialize(&index).ok()
+    }
+}
+
+impl SegmentIndexBlock {
+    #[must_use]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    #[must_use]
+    pub fn events_before(&self) -> usize {
+        self.events_before
+    }
+
+    #[must_use]
+    pub fn events(&self) -> usize {
+        self.events
+    }
+
+    #[must_use]
+    pub fn min_time(&self) -> PositiveF64 {
+        self.min_time
+    }
+
+    #[must_use]
+    pub fn max_time(&self) -> PositiveF64 {
+        self.max_time
+    }
+
+    #[must_use]
+    pub fn min_lineage(&self) -> &GlobalLineageReference {
+        &self.min_lineage
+    }
+
+    #[must_use]
+    pub fn max_lineage(&self) -> &GlobalLineageReference {
+        &self.max_lineage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, num::NonZeroUsize, path::Path};
+
+    use necsim_core::event::PackedEvent;
+
+    use super::{
+        super::{
+            format::SEGMENT_BLOCK_EVENTS,
+            replay::{filter::EventLogFilter, segment::SortedSegment, EventLogReplay},
+            testing,
+        },
+        SegmentIndex,
+    };
+
+    fn replay(directory: &Path, filter: &str) -> Vec<PackedEvent> {
+        EventLogReplay::try_from_globs(
+            [directory.join("*").to_str().unwrap()],
+            NonZeroUsize::new(100).unwrap(),
+            ron::from_str(filter).unwrap(),
+        )
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap()
+    }
+
+    #[test]
+    fn indexed_replay_matches_full_replay() {
+        let indexed = tempfile::tempdir().unwrap();
+        let scanned = tempfile::tempdir().unwrap();
+
+        let events = testing::events(0..(SEGMENT_BLOCK_EVENTS * 3 + 10));
+
+        testing::write_indexed(&indexed.path().join("segment"), &events);
+        testing::write(&scanned.path().join("segment"), &events);
+
+        let index = SegmentIndex::try_read(&indexed.path().join("segment")).unwrap();
+        assert_eq!(index.blocks().len(), 4);
+        assert!(SegmentIndex::try_read(&scanned.path().join("segment")).is_none());
+
+        for filter in [
+            "()",
+            "(after: Some(5000.0))",
+            "(before: Some(100.0))",
+            "(after: Some(4000.0), before: Some(9000.0))",
+            "(lineages: Some([5, 8200, 12290]))",
+            "(after: Some(10.0), lineages: Some([5, 8200]))",
+        ] {
+            let parsed: EventLogFilter = ron::from_str(filter).unwrap();
+            let expected: Vec<_> = events
+                .iter()
+                .filter(|event| parsed.contains(event))
+                .cloned()
+                .collect();
+
+            assert_eq!(replay(indexed.path(), filter), expected, "{filter}");
+            assert_eq!(replay(scanned.path(), filter), expected, "{filter}");
+        }
+    }
+
+    #[test]
+    fn stale_index_is_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("segment");
+
+        testing::write_indexed(&path, &testing::events(0..(SEGMENT_BLOCK_EVENTS * 2)));
+
+        let filter: EventLogFilter = ron::from_str("(lineages: Some([5]))").unwrap();
+
+        let mut segment = SortedSegment::try_new(&path, NonZeroUsize::new(1).unwrap()).unwrap();
+        assert!(segment.seek(&filter));
+
+        // Overwrite the segment, but leave its now stale index behind
+        let events = testing::events(1..(SEGMENT_BLOCK_EVENTS * 2));
+        testing::write(&path, &events);
+
+        let mut segment = SortedSegment::try_new(&path, NonZeroUsize::new(1).unwrap()).unwrap();
+        assert!(!segment.seek(&filter));
+
+        assert_eq!(
+            replay(dir.path(), "(lineages: Some([5]))"),
+            testing::events(5..6)
+        );
+        assert_eq!(replay(dir.path(), "()"), events);
+
+        // An index that is no longer intact is ignored as well
+        let index = fs::read(SegmentIndex::path_of(&path)).unwrap();
+        fs::write(SegmentIndex::path_of(&path), &index[..index.len() - 1]).unwrap();
+        assert!(SegmentIndex::try_read(&path).is_none());
+    }
+}
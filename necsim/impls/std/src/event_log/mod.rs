@@ -1,11 +1,19 @@
-use std::{cmp::Ordering, fmt};
+use std::{cmp::Ordering, convert::TryFrom, fmt, str::FromStr};
 
 use necsim_core_bond::PositiveF64;
 use serde::{Deserialize, Serialize};
 
+mod frame;
+
+pub mod format;
+pub mod index;
+pub mod provenance;
 pub mod recorder;
 pub mod replay;
 
+#[cfg(test)]
+mod testing;
+
 #[derive(Serialize, Deserialize, PartialEq)]
 #[allow(clippy::module_name_repetitions)]
 pub struct EventLogHeader {
@@ -16,6 +24,8 @@ pub struct EventLogHeader {
 
     with_speciation: bool,
     with_dispersal: bool,
+
+    run: Option<EventLogRunId>,
 }
 
 impl fmt::Debug for EventLogHeader {
@@ -24,6 +34,7 @@ impl fmt::Debug for EventLogHeader {
             .field("min_time", &self.min_time)
             .field("max_time", &self.max_time)
             .field("length", &self.length)
+            .field("run", &self.run)
             .finish_non_exhaustive()
     }
 }
@@ -36,6 +47,7 @@ impl EventLogHeader {
         length: usize,
         with_speciation: bool,
         with_dispersal: bool,
+        run: Option<EventLogRunId>,
     ) -> Self {
         Self {
             min_time,
@@ -43,6 +55,7 @@ impl EventLogHeader {
             length,
             with_speciation,
             with_dispersal,
+            run,
         }
     }
 
@@ -70,6 +83,16 @@ impl EventLogHeader {
     pub fn with_dispersal(&self) -> bool {
         self.with_dispersal
     }
+
+    /// Identifier of the simulation run that recorded the segment, which is
+    /// `None` for segments that were recorded without provenance
+    ///
+    /// The provenance of the run is stored in a sidecar file, see
+    /// [`EventLogProvenance::try_read`].
+    #[must_use]
+    pub fn run(&self) -> Option<EventLogRunId> {
+        self.run
+    }
 }
 
 impl Eq for EventLogHeader {}
@@ -85,3 +108,83 @@ impl PartialOrd for EventLogHeader {
         }
     }
 }
+
+/// Provenance of the simulation run that recorded an event log segment
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct EventLogProvenance {
+    run: EventLogRunId,
+    config: String,
+}
+
+impl EventLogProvenance {
+    #[must_use]
+    pub fn new(run: EventLogRunId, config: String) -> Self {
+        Self { run, config }
+    }
+
+    #[must_use]
+    pub fn run(&self) -> EventLogRunId {
+        self.run
+    }
+
+    /// Normalised configuration of the simulation run
+    #[must_use]
+    pub fn config(&self) -> &str {
+        &self.config
+    }
+}
+
+/// Identifier that is shared by all partitions and all paused and resumed
+/// slices of one simulation run
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+#[allow(clippy::module_name_repetitions)]
+pub struct EventLogRunId(u64);
+
+impl From<u64> for EventLogRunId {
+    fn from(run: u64) -> Self {
+        Self(run)
+    }
+}
+
+impl fmt::Display for EventLogRunId {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{:016x}", self.0)
+    }
+}
+
+impl fmt::Debug for EventLogRunId {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}({self})", stringify!(EventLogRunId))
+    }
+}
+
+impl FromStr for EventLogRunId {
+    type Err = String;
+
+    fn from_str(run: &str) -> Result<Self, Self::Err> {
+        match u64::from_str_radix(run, 16) {
+            Ok(run_id) if run.len() == 16 && run.bytes().all(|b| b.is_ascii_hexdigit()) => {
+                Ok(Self(run_id))
+            },
+            _ => Err(format!(
+                "{run:?} is not a valid run identifier, which has 16 hexadecimal digits."
+            )),
+        }
+    }
+}
+
+impl From<EventLogRunId> for String {
+    fn from(run: EventLogRunId) -> Self {
+        run.to_string()
+    }
+}
+
+impl TryFrom<String> for EventLogRunId {
+    type Error = String;
+
+    fn try_from(run: String) -> Result<Self, Self::Error> {
+        run.parse()
+    }
+}
@@ -0,0 +1,84 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
+};
+
+use super::{frame, EventLogProvenance, EventLogRunId};
+
+/// Magic bytes at the start of every sidecar event log provenance
+pub const PROVENANCE_MAGIC: [u8; 8] = *b"\xffNECPRV\xff";
+
+/// Version of the sidecar event log provenance format
+pub const PROVENANCE_FORMAT_VERSION: u32 = 1;
+
+/// File extension of the sidecar provenance, which is stored once per
+/// simulation run in every event log directory that contains its segments
+pub const PROVENANCE_EXTENSION: &str = "provenance";
+
+/// Maximum size of the encoded provenance, used to detect corruption
+///
+/// The provenance contains the normalised simulation config, which can list
+/// all lineages of a resumed simulation.
+const PROVENANCE_MAX_BYTES: u32 = 1 << 30;
+
+impl EventLogProvenance {
+    /// Returns the path of the sidecar provenance of the simulation `run` in
+    /// the event log `directory`
+    #[must_use]
+    pub fn path_of(directory: &Path, run: EventLogRunId) -> PathBuf {
+        directory.join(format!("{run}.{PROVENANCE_EXTENSION}"))
+    }
+
+    /// Checks if the file at `path` is a sidecar provenance instead of a
+    /// segment
+    #[must_use]
+    pub fn is_provenance_path(path: &Path) -> bool {
+        path.extension()
+            .map_or(false, |extension| extension == PROVENANCE_EXTENSION)
+    }
+
+    /// Writes the sidecar provenance into the event log `directory`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the provenance file cannot be written.
+    pub fn write(&self, directory: &Path) -> anyhow::Result<()> {
+        let provenance = bincode::serialize(self)?;
+
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(Self::path_of(directory, self.run))?;
+        let mut writer = BufWriter::new(file);
+
+        frame::write_frame(
+            &mut writer,
+            PROVENANCE_MAGIC,
+            PROVENANCE_FORMAT_VERSION,
+            &provenance,
+        )?;
+
+        writer.into_inner()?;
+
+        Ok(())
+    }
+
+    /// Reads the sidecar provenance of the simulation `run` from the event
+    /// log `directory`, if it exists and is intact.
+    #[must_use]
+    pub fn try_read(directory: &Path, run: EventLogRunId) -> Option<Self> {
+        let file = File::open(Self::path_of(directory, run)).ok()?;
+        let provenance = frame::try_read_frame(
+            &mut BufReader::new(file),
+            PROVENANCE_MAGIC,
+            PROVENANCE_FORMAT_VERSION,
+            PROVENANCE_MAX_BYTES,
+        )?;
+
+        let provenance: Self = bincode::deserialize(&provenance).ok()?;
+
+        (provenance.run == run).then_some(provenance)
+    }
+}
@@ -21,6 +21,7 @@ use std::{
     io::BufWriter,
     num::NonZeroUsize,
     path::{Path, PathBuf},
+    sync::{Arc, OnceLock},
 };
 
 use anyhow::{Error, Result};
@@ -28,7 +29,7 @@ use serde::{Deserialize, Serialize, Serializer};
 
 use necsim_core::event::{DispersalEvent, PackedEvent, SpeciationEvent};
 
-use super::EventLogHeader;
+use super::{format, EventLogHeader, EventLogProvenance, EventLogRunId};
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Deserialize)]
@@ -41,23 +42,27 @@ pub struct EventLogRecorder {
 
     record_speciation: bool,
     record_dispersal: bool,
+
+    run: Option<EventLogRunId>,
+    provenance: Arc<OnceLock<EventLogProvenance>>,
+    // Whether the provenance sidecar has been written into the directory
+    provenance_written: bool,
 }
 
 impl TryFrom<EventLogRecorderRaw> for EventLogRecorder {
     type Error = Error;
 
     fn try_from(raw: EventLogRecorderRaw) -> Result<Self, Self::Error> {
-        Self::try_new(&raw.directory, raw.capacity)
+        let mut recorder = Self::try_new(&raw.directory, raw.capacity)?;
+        recorder.run = raw.run;
+
+        Ok(recorder)
     }
 }
 
 impl Serialize for EventLogRecorder {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        EventLogRecorderRaw {
-            directory: self.directory.clone(),
-            capacity: self.segment_capacity,
-        }
-        .serialize(serializer)
+        self.provenance().serialize(serializer)
     }
 }
 
@@ -97,6 +102,10 @@ impl EventLogRecorder {
 
             record_speciation: false,
             record_dispersal: false,
+
+            run: None,
+            provenance: Arc::new(OnceLock::new()),
+            provenance_written: false,
         })
     }
 
@@ -125,6 +134,7 @@ impl EventLogRecorder {
         }
 
         self.directory = path.to_owned();
+        self.provenance_written = false;
 
         Ok(self)
     }
@@ -151,6 +161,19 @@ impl EventLogRecorder {
         &self.directory
     }
 
+    /// Returns a handle to set the provenance that is stored alongside the
+    /// segments, which remains usable after the recorder has been moved into
+    /// a partition.
+    #[must_use]
+    pub fn provenance(&self) -> EventLogProvenanceHandle {
+        EventLogProvenanceHandle {
+            directory: self.directory.clone(),
+            capacity: self.segment_capacity,
+            run: self.run,
+            provenance: self.provenance.clone(),
+        }
+    }
+
     pub fn set_event_filter(&mut self, record_speciation: bool, record_dispersal: bool) {
         self.record_speciation = record_speciation;
         self.record_dispersal = record_dispersal;
@@ -177,6 +200,14 @@ impl EventLogRecorder {
     }
 
     fn sort_and_write_segment(&mut self) -> Result<()> {
+        let provenance = self.provenance.get();
+
+        // The provenance is only stored once per directory, not per segment
+        if let (Some(provenance), false) = (provenance, self.provenance_written) {
+            provenance.write(&self.directory)?;
+            self.provenance_written = true;
+        }
+
         self.buffer.sort_unstable();
 
         let segment_path = self.directory.join(format!("{}", self.segment_index));
@@ -186,26 +217,25 @@ impl EventLogRecorder {
             .create(true)
             .truncate(true)
             .write(true)
-            .open(segment_path)?;
+            .open(&segment_path)?;
         let mut buf_writer = BufWriter::new(segment_file);
 
-        bincode::serialize_into(
-            &mut buf_writer,
-            &EventLogHeader::new(
-                self.buffer[0].event_time(),
-                self.buffer[self.buffer.len() - 1].event_time(),
-                self.buffer.len(),
-                self.record_speciation,
-                self.record_dispersal,
-            ),
-        )?;
-
-        for event in self.buffer.drain(0..) {
-            bincode::serialize_into(&mut buf_writer, &event)?;
-        }
+        let header = EventLogHeader::new(
+            self.buffer[0].event_time(),
+            self.buffer[self.buffer.len() - 1].event_time(),
+            self.buffer.len(),
+            self.record_speciation,
+            self.record_dispersal,
+            provenance.map(EventLogProvenance::run),
+        );
+
+        let index = format::write_segment(&mut buf_writer, &header, &self.buffer)?;
+        self.buffer.clear();
 
         buf_writer.into_inner()?;
 
+        index.write(&segment_path)?;
+
         Ok(())
     }
 }
@@ -229,6 +259,76 @@ impl fmt::Debug for EventLogRecorder {
     }
 }
 
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone)]
+pub struct EventLogProvenanceHandle {
+    directory: PathBuf,
+    capacity: NonZeroUsize,
+    run: Option<EventLogRunId>,
+    provenance: Arc<OnceLock<EventLogProvenance>>,
+}
+
+impl Serialize for EventLogProvenanceHandle {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        EventLogRecorderRaw {
+            directory: self.directory.clone(),
+            capacity: self.capacity,
+            run: self.run(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl EventLogProvenanceHandle {
+    /// Returns the identifier of the simulation run, which is only known
+    /// before the provenance has been set if the run is being resumed.
+    #[must_use]
+    pub fn run(&self) -> Option<EventLogRunId> {
+        self.provenance
+            .get()
+            .map(EventLogProvenance::run)
+            .or(self.run)
+    }
+
+    /// Returns a copy of this handle that serialises with the identifier of
+    /// the simulation `run`, e.g. before the provenance has been set.
+    #[must_use]
+    pub fn with_run(&self, run: EventLogRunId) -> Self {
+        Self {
+            run: Some(run),
+            ..self.clone()
+        }
+    }
+
+    /// # Errors
+    ///
+    /// Fails if the provenance has already been set, or if it belongs to a
+    /// different simulation run than the one being resumed.
+    pub fn set(&self, provenance: EventLogProvenance) -> Result<()> {
+        if let Some(run) = self.run {
+            anyhow::ensure!(
+                run == provenance.run(),
+                "The event log provenance of run {} cannot be set for the resumed run {}.",
+                provenance.run(),
+                run,
+            );
+        }
+
+        self.provenance
+            .set(provenance)
+            .map_err(|_| anyhow::anyhow!("The event log provenance has already been set."))
+    }
+}
+
+impl fmt::Debug for EventLogProvenanceHandle {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(EventLogProvenanceHandle))
+            .field("directory", &self.directory)
+            .field("run", &self.run())
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(rename = "EventLog")]
 #[serde(deny_unknown_fields)]
@@ -236,6 +336,8 @@ struct EventLogRecorderRaw {
     directory: PathBuf,
     #[serde(default = "default_event_log_recorder_segment_capacity")]
     capacity: NonZeroUsize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    run: Option<EventLogRunId>,
 }
 
 fn default_event_log_recorder_segment_capacity() -> NonZeroUsize {
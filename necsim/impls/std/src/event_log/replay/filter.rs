@@ -0,0 +1,242 @@
+use std::{collections::BTreeSet, convert::TryFrom};
+
+use serde::{Deserialize, Serialize};
+
+use necsim_core::{
+    event::PackedEvent, landscape::LandscapeExtent, lineage::GlobalLineageReference,
+};
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+use crate::event_log::{index::SegmentIndexBlock, EventLogHeader};
+
+/// Restricts an event log replay to the events that happened inside a time
+/// window, that originated inside a rectangle of locations, and that belong
+/// to a set of lineages
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(try_from = "EventLogFilterRaw", into = "EventLogFilterRaw")]
+pub struct EventLogFilter {
+    after: Option<NonNegativeF64>,
+    before: Option<PositiveF64>,
+    origin: Option<LandscapeExtent>,
+    lineages: Option<BTreeSet<GlobalLineageReference>>,
+}
+
+impl TryFrom<EventLogFilterRaw> for EventLogFilter {
+    type Error = String;
+
+    fn try_from(raw: EventLogFilterRaw) -> Result<Self, Self::Error> {
+        if let (Some(after), Some(before)) = (raw.after, raw.before) {
+            if after >= before {
+                return Err(format!(
+                    "The event log filter time window after {after} and before {before} is empty."
+                ));
+            }
+        }
+
+        Ok(Self {
+            after: raw.after,
+            before: raw.before,
+            origin: raw.origin,
+            lineages: raw.lineages,
+        })
+    }
+}
+
+impl From<EventLogFilter> for EventLogFilterRaw {
+    fn from(filter: EventLogFilter) -> Self {
+        Self {
+            after: filter.after,
+            before: filter.before,
+            origin: filter.origin,
+            lineages: filter.lineages,
+        }
+    }
+}
+
+impl EventLogFilter {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.after.is_none()
+            && self.before.is_none()
+            && self.origin.is_none()
+            && self.lineages.is_none()
+    }
+
+    /// Checks if the time range of a segment overlaps with the time window,
+    /// segments outside the window do not need to be read at all
+    #[must_use]
+    pub fn overlaps_segment(&self, header: &EventLogHeader) -> bool {
+        self.after.map_or(true, |after| header.max_time() > after)
+            && self
+                .before
+                .map_or(true, |before| header.min_time() < before)
+    }
+
+    /// Checks if a block of a segment's index may contain events that pass
+    /// the filter, blocks that cannot are skipped without being read
+    #[must_use]
+    pub fn overlaps_block(&self, block: &SegmentIndexBlock) -> bool {
+        self.after.map_or(true, |after| block.max_time() > after)
+            && self.before.map_or(true, |before| block.min_time() < before)
+            && self.lineages.as_ref().map_or(true, |lineages| {
+                lineages
+                    .range(block.min_lineage()..=block.max_lineage())
+                    .next()
+                    .is_some()
+            })
+    }
+
+    /// Checks if an event is at or past the end of the time window, after
+    /// which no later event can pass the filter
+    #[must_use]
+    pub fn is_exhausted_by(&self, event: &PackedEvent) -> bool {
+        self.before
+            .map_or(false, |before| event.event_time() >= before)
+    }
+
+    #[must_use]
+    pub fn contains(&self, event: &PackedEvent) -> bool {
+        self.after.map_or(true, |after| event.event_time() > after)
+            && self
+                .before
+                .map_or(true, |before| event.event_time() < before)
+            && self
+                .origin
+                .as_ref()
+                .map_or(true, |origin| origin.contains(event.origin().location()))
+            && self.lineages.as_ref().map_or(true, |lineages| {
+                lineages.contains(event.global_lineage_reference())
+            })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Filter")]
+#[serde(deny_unknown_fields)]
+struct EventLogFilterRaw {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    after: Option<NonNegativeF64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    before: Option<PositiveF64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    origin: Option<LandscapeExtent>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    lineages: Option<BTreeSet<GlobalLineageReference>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeSet, convert::TryFrom};
+
+    use necsim_core::{
+        event::{PackedEvent, SpeciationEvent},
+        landscape::{IndexedLocation, LandscapeExtent, Location},
+        lineage::GlobalLineageReference,
+    };
+    use necsim_core_bond::{NonNegativeF64, OffByOneU32, PositiveF64};
+
+    use super::{EventLogFilter, EventLogFilterRaw};
+
+    fn event(lineage: u64, time: f64, x: u32) -> PackedEvent {
+        PackedEvent::from(SpeciationEvent {
+            global_lineage_reference: unsafe { GlobalLineageReference::from_inner(lineage) },
+            prior_time: NonNegativeF64::zero(),
+            event_time: PositiveF64::new(time).unwrap(),
+            origin: IndexedLocation::new(Location::new(x, 0), 0),
+        })
+    }
+
+    fn filter(raw: EventLogFilterRaw) -> EventLogFilter {
+        EventLogFilter::try_from(raw).unwrap()
+    }
+
+    fn no_filter() -> EventLogFilterRaw {
+        EventLogFilterRaw {
+            after: None,
+            before: None,
+            origin: None,
+            lineages: None,
+        }
+    }
+
+    #[test]
+    fn empty_filter_contains_everything() {
+        let filter = filter(no_filter());
+
+        assert!(filter.is_empty());
+        assert!(filter.contains(&event(0, 1.0, 0)));
+        assert!(!filter.is_exhausted_by(&event(0, f64::MAX, 0)));
+    }
+
+    #[test]
+    fn after_is_exclusive() {
+        let filter = filter(EventLogFilterRaw {
+            after: Some(NonNegativeF64::new(2.0).unwrap()),
+            ..no_filter()
+        });
+
+        assert!(!filter.contains(&event(0, 1.0, 0)));
+        assert!(!filter.contains(&event(0, 2.0, 0)));
+        assert!(filter.contains(&event(0, 3.0, 0)));
+        assert!(!filter.is_exhausted_by(&event(0, 3.0, 0)));
+    }
+
+    #[test]
+    fn before_is_exclusive_and_exhausts() {
+        let filter = filter(EventLogFilterRaw {
+            before: Some(PositiveF64::new(2.0).unwrap()),
+            ..no_filter()
+        });
+
+        assert!(filter.contains(&event(0, 1.0, 0)));
+        assert!(!filter.contains(&event(0, 2.0, 0)));
+        assert!(!filter.is_exhausted_by(&event(0, 1.0, 0)));
+        assert!(filter.is_exhausted_by(&event(0, 2.0, 0)));
+    }
+
+    #[test]
+    fn empty_time_window_is_rejected() {
+        assert!(EventLogFilter::try_from(EventLogFilterRaw {
+            after: Some(NonNegativeF64::new(2.0).unwrap()),
+            before: Some(PositiveF64::new(2.0).unwrap()),
+            ..no_filter()
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn origin_selects_locations() {
+        let filter = filter(EventLogFilterRaw {
+            origin: Some(LandscapeExtent::new(
+                Location::new(1, 0),
+                OffByOneU32::new(2).unwrap(),
+                OffByOneU32::new(1).unwrap(),
+            )),
+            ..no_filter()
+        });
+
+        assert!(!filter.contains(&event(0, 1.0, 0)));
+        assert!(filter.contains(&event(0, 1.0, 1)));
+        assert!(filter.contains(&event(0, 1.0, 2)));
+        assert!(!filter.contains(&event(0, 1.0, 3)));
+    }
+
+    #[test]
+    fn lineages_select_events() {
+        let filter = filter(EventLogFilterRaw {
+            lineages: Some(
+                [1, 3]
+                    .into_iter()
+                    .map(|lineage| unsafe { GlobalLineageReference::from_inner(lineage) })
+                    .collect::<BTreeSet<_>>(),
+            ),
+            ..no_filter()
+        });
+
+        assert!(!filter.contains(&event(0, 1.0, 0)));
+        assert!(filter.contains(&event(1, 1.0, 0)));
+        assert!(!filter.contains(&event(2, 1.0, 0)));
+        assert!(filter.contains(&event(3, 1.0, 0)));
+    }
+}
@@ -3,6 +3,8 @@ use std::{num::NonZeroUsize, path::PathBuf};
 use glob::MatchOptions;
 use serde::{Deserialize, Deserializer};
 
+use crate::event_log::{index::SegmentIndex, EventLogProvenance};
+
 use super::segment::SortedSegment;
 
 #[allow(clippy::module_name_repetitions)]
@@ -17,48 +19,60 @@ impl<'de> Deserialize<'de> for GlobbedSortedSegments {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let pattern = String::deserialize(deserializer)?;
 
-        let mut paths = Vec::new();
-
-        for path in glob::glob_with(
-            &pattern,
-            MatchOptions {
-                case_sensitive: true,
-                require_literal_separator: true,
-                require_literal_leading_dot: false,
-            },
-        )
-        .map_err(serde::de::Error::custom)?
-        {
-            paths.push(path.map_err(serde::de::Error::custom)?);
-        }
+        Self::try_new(&pattern).map_err(serde::de::Error::custom)
+    }
+}
 
-        let mut segments = Vec::with_capacity(paths.len().min(1));
-
-        if paths.is_empty() {
-            segments.push(
-                SortedSegment::try_new(&PathBuf::from(pattern), SEGMENT_INIT_CAPACITY)
-                    .map_err(serde::de::Error::custom)?,
-            );
-        } else if paths.len() == 1 {
-            segments.push(
-                SortedSegment::try_new(&paths[0], SEGMENT_INIT_CAPACITY)
-                    .map_err(serde::de::Error::custom)?,
-            );
-        } else {
-            for path in paths {
-                if path.is_file() || !path.exists() {
-                    segments.push(
-                        SortedSegment::try_new(&path, SEGMENT_INIT_CAPACITY)
-                            .map_err(serde::de::Error::custom)?,
-                    );
-                }
-            }
-        }
+impl GlobbedSortedSegments {
+    /// # Errors
+    ///
+    /// Fails if the glob `pattern` is invalid or if any of the matched paths
+    /// cannot be read as an event log segment
+    pub fn try_new(pattern: &str) -> anyhow::Result<Self> {
+        let segments = glob_segment_paths(pattern)?
+            .iter()
+            .map(|path| SortedSegment::try_new(path, SEGMENT_INIT_CAPACITY))
+            .collect::<anyhow::Result<_>>()?;
 
         Ok(Self { segments })
     }
 }
 
+/// Expands the glob `pattern` into the paths of all event log segments that
+/// it matches, skipping directories and sidecar files. A `pattern` that
+/// matches nothing is returned as a literal path.
+///
+/// # Errors
+///
+/// Fails if the glob `pattern` is invalid or if a matched path is unreadable
+pub fn glob_segment_paths(pattern: &str) -> anyhow::Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+
+    for path in glob::glob_with(
+        pattern,
+        MatchOptions {
+            case_sensitive: true,
+            require_literal_separator: true,
+            require_literal_leading_dot: false,
+        },
+    )? {
+        let path = path?;
+
+        // Sidecar indices and provenances are read together with their segments
+        if !SegmentIndex::is_index_path(&path) && !EventLogProvenance::is_provenance_path(&path) {
+            paths.push(path);
+        }
+    }
+
+    if paths.is_empty() {
+        paths.push(PathBuf::from(pattern));
+    } else if paths.len() > 1 {
+        paths.retain(|path| path.is_file() || !path.exists());
+    }
+
+    Ok(paths)
+}
+
 impl IntoIterator for GlobbedSortedSegments {
     type IntoIter = std::vec::IntoIter<Self::Item>;
     type Item = SortedSegment;
@@ -6,10 +6,16 @@ use serde::{Deserialize, Serialize, Serializer};
 
 use necsim_core::event::PackedEvent;
 
+use super::{format::SegmentFormatError, EventLogProvenance, EventLogRunId};
+
+pub mod filter;
 mod globbed;
 pub mod segment;
 mod sorted_segments;
 
+pub use globbed::glob_segment_paths;
+
+use filter::EventLogFilter;
 use globbed::GlobbedSortedSegments;
 use segment::SortedSegment;
 use sorted_segments::SortedSortedSegments;
@@ -19,9 +25,14 @@ use sorted_segments::SortedSortedSegments;
 #[serde(try_from = "EventLogReplayRaw")]
 pub struct EventLogReplay {
     frontier: BinaryHeap<SortedSortedSegments>,
+    filter: EventLogFilter,
+    // Error that is reported after the event that was read before it
+    error: Option<SegmentFormatError>,
 
     with_speciation: bool,
     with_dispersal: bool,
+
+    runs: Vec<Option<EventLogProvenance>>,
 }
 
 impl Serialize for EventLogReplay {
@@ -30,22 +41,27 @@ impl Serialize for EventLogReplay {
         struct EventLog<'r> {
             segments: Vec<&'r Path>,
             capacity: NonZeroUsize,
+            #[serde(skip_serializing_if = "EventLogFilter::is_empty")]
+            filter: &'r EventLogFilter,
         }
 
         let mut segments = Vec::new();
         let mut capacity = NonZeroUsize::new(1).unwrap();
 
-        for segs in &self.frontier {
-            for seg in segs.segments() {
-                segments.push(seg.path());
+        for seg in self.segments() {
+            segments.push(seg.path());
 
-                capacity = capacity.max(seg.capacity());
-            }
+            capacity = capacity.max(seg.capacity());
         }
 
         segments.sort_unstable();
 
-        EventLog { segments, capacity }.serialize(serializer)
+        EventLog {
+            segments,
+            capacity,
+            filter: &self.filter,
+        }
+        .serialize(serializer)
     }
 }
 
@@ -53,40 +69,61 @@ impl TryFrom<EventLogReplayRaw> for EventLogReplay {
     type Error = anyhow::Error;
 
     fn try_from(raw: EventLogReplayRaw) -> Result<Self, Self::Error> {
-        let capacity = raw.capacity;
-
-        raw.segments
-            .into_iter()
-            .flatten()
-            .map(|mut segment| {
-                segment.set_capacity(capacity);
-                segment
-            })
-            .collect()
+        Self::try_from_segments(
+            raw.segments.into_iter().flatten().collect(),
+            raw.capacity,
+            raw.filter,
+        )
     }
 }
 
 impl EventLogReplay {
-    #[must_use]
-    pub fn length(&self) -> usize {
-        self.frontier.iter().map(SortedSortedSegments::length).sum()
-    }
+    /// Opens all event log segments that match any of the glob `patterns`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if any pattern is invalid, if any matched segment cannot be
+    /// read, or if the segments are inconsistent.
+    pub fn try_from_globs<'a, I: IntoIterator<Item = &'a str>>(
+        patterns: I,
+        capacity: NonZeroUsize,
+        filter: EventLogFilter,
+    ) -> anyhow::Result<Self> {
+        let mut segments = Vec::new();
 
-    #[must_use]
-    pub fn with_speciation(&self) -> bool {
-        self.with_speciation
-    }
+        for pattern in patterns {
+            segments.extend(GlobbedSortedSegments::try_new(pattern)?);
+        }
 
-    #[must_use]
-    pub fn with_dispersal(&self) -> bool {
-        self.with_dispersal
+        Self::try_from_segments(segments, capacity, filter)
     }
-}
 
-impl FromIterator<SortedSegment> for anyhow::Result<EventLogReplay> {
-    fn from_iter<T: IntoIterator<Item = SortedSegment>>(iter: T) -> Self {
-        let mut segments: Vec<SortedSegment> = iter.into_iter().collect();
+    fn try_from_segments(
+        segments: Vec<SortedSegment>,
+        capacity: NonZeroUsize,
+        filter: EventLogFilter,
+    ) -> anyhow::Result<Self> {
+        let segments = segments
+            .into_iter()
+            .map(|mut segment| {
+                segment.set_capacity(capacity);
+                segment
+            })
+            .collect();
 
+        Self::try_from_filtered_segments(segments, filter)
+    }
+
+    /// Checks that the `segments` are consistent and groups those that may
+    /// contain events which pass the `filter` into sorted runs of segments.
+    ///
+    /// Segments that lie outside of the filter's time window are skipped
+    /// after only their header has been read. If no segment overlaps with the
+    /// time window, the replay is empty.
+    fn try_from_filtered_segments(
+        mut segments: Vec<SortedSegment>,
+        filter: EventLogFilter,
+    ) -> anyhow::Result<Self> {
         if segments.is_empty() {
             anyhow::bail!("The EventLogReplay requires at least one event log segment.")
         }
@@ -94,7 +131,24 @@ impl FromIterator<SortedSegment> for anyhow::Result<EventLogReplay> {
         let mut with_speciation = None;
         let mut with_dispersal = None;
 
+        let mut runs: Vec<(Option<EventLogRunId>, Option<EventLogProvenance>)> = Vec::new();
+
         for segment in &segments {
+            let run = segment.header().run();
+
+            let run_index = runs
+                .iter()
+                .position(|(other, _)| *other == run)
+                .unwrap_or_else(|| {
+                    runs.push((run, None));
+                    runs.len() - 1
+                });
+
+            // Only look for the sidecar provenance until it has been found
+            if run.is_some() && runs[run_index].1.is_none() {
+                runs[run_index].1 = segment.provenance();
+            }
+
             if let Some(with_speciation) = with_speciation {
                 anyhow::ensure!(
                     with_speciation == segment.header().with_speciation(),
@@ -114,6 +168,13 @@ impl FromIterator<SortedSegment> for anyhow::Result<EventLogReplay> {
             }
         }
 
+        // Skip all segments which lie outside of the filter's time window
+        segments.retain(|segment| filter.overlaps_segment(segment.header()));
+
+        for segment in &mut segments {
+            segment.seek(&filter);
+        }
+
         let mut grouped_segments: Vec<Vec<SortedSegment>> = Vec::new();
         let mut current_group: Vec<SortedSegment> = Vec::new();
 
@@ -158,25 +219,93 @@ impl FromIterator<SortedSegment> for anyhow::Result<EventLogReplay> {
             frontier.push(SortedSortedSegments::new(group));
         }
 
-        Ok(EventLogReplay {
+        Ok(Self {
             frontier,
+            filter,
+            error: None,
             with_speciation: with_speciation.unwrap(),
             with_dispersal: with_dispersal.unwrap(),
+            runs: runs.into_iter().map(|(_, provenance)| provenance).collect(),
         })
     }
+
+    /// Returns the number of events in all replayed segments, which is an
+    /// upper bound on the number of replayed events if they are filtered.
+    #[must_use]
+    pub fn length(&self) -> usize {
+        self.frontier.iter().map(SortedSortedSegments::length).sum()
+    }
+
+    /// Returns all segments which have not yet been fully replayed
+    pub fn segments(&self) -> impl Iterator<Item = &SortedSegment> {
+        self.frontier
+            .iter()
+            .flat_map(SortedSortedSegments::segments)
+    }
+
+    #[must_use]
+    pub fn with_speciation(&self) -> bool {
+        self.with_speciation
+    }
+
+    #[must_use]
+    pub fn with_dispersal(&self) -> bool {
+        self.with_dispersal
+    }
+
+    /// Returns the provenance of every distinct simulation run whose segments
+    /// are replayed, where `None` stands for segments without provenance or
+    /// whose sidecar provenance is missing.
+    #[must_use]
+    pub fn runs(&self) -> &[Option<EventLogProvenance>] {
+        &self.runs
+    }
+}
+
+impl FromIterator<SortedSegment> for anyhow::Result<EventLogReplay> {
+    fn from_iter<T: IntoIterator<Item = SortedSegment>>(iter: T) -> Self {
+        EventLogReplay::try_from_filtered_segments(
+            iter.into_iter().collect(),
+            EventLogFilter::default(),
+        )
+    }
 }
 
 impl Iterator for EventLogReplay {
-    type Item = PackedEvent;
+    type Item = Result<PackedEvent, SegmentFormatError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let mut next_segment = self.frontier.pop()?;
+        if let Some(error) = self.error.take() {
+            return Some(Err(error));
+        }
+
+        loop {
+            let mut next_segment = self.frontier.pop()?;
+
+            let next_event = next_segment.next();
+            self.error = next_segment.take_error();
+
+            self.frontier.push(next_segment);
+
+            let Some(next_event) = next_event else {
+                return self.error.take().map(Err);
+            };
+
+            if self.filter.is_exhausted_by(&next_event) {
+                // All remaining events are outside the filter's time window
+                self.frontier.clear();
 
-        let next_event = next_segment.next();
+                return self.error.take().map(Err);
+            }
 
-        self.frontier.push(next_segment);
+            if self.filter.contains(&next_event) {
+                return Some(Ok(next_event));
+            }
 
-        next_event
+            if let Some(error) = self.error.take() {
+                return Some(Err(error));
+            }
+        }
     }
 }
 
@@ -187,8 +316,206 @@ struct EventLogReplayRaw {
     segments: Vec<GlobbedSortedSegments>,
     #[serde(default = "default_event_log_replay_segment_capacity")]
     capacity: NonZeroUsize,
+    #[serde(default)]
+    filter: EventLogFilter,
 }
 
 fn default_event_log_replay_segment_capacity() -> NonZeroUsize {
     NonZeroUsize::new(100_000_usize).unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, num::NonZeroUsize};
+
+    use necsim_core::event::{PackedEvent, TypedEvent};
+
+    use super::{
+        super::{
+            format::SEGMENT_BLOCK_EVENTS, recorder::EventLogRecorder, testing, EventLogProvenance,
+            EventLogRunId,
+        },
+        filter::EventLogFilter,
+        EventLogReplay,
+    };
+
+    #[test]
+    fn provenance_is_stored_once_per_directory() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let provenance = EventLogProvenance::new(EventLogRunId::from(42), String::from("()"));
+
+        let mut recorder =
+            EventLogRecorder::try_new(dir.path(), NonZeroUsize::new(10).unwrap()).unwrap();
+        recorder.provenance().set(provenance.clone()).unwrap();
+
+        for event in testing::events(0..25) {
+            if let TypedEvent::Speciation(event) = TypedEvent::from(event) {
+                recorder.record_speciation(&event);
+            }
+        }
+
+        std::mem::drop(recorder);
+
+        let sidecars = fs::read_dir(dir.path())
+            .unwrap()
+            .filter(|entry| EventLogProvenance::is_provenance_path(&entry.as_ref().unwrap().path()))
+            .count();
+        assert_eq!(sidecars, 1);
+
+        let replay = EventLogReplay::try_from_globs(
+            [dir.path().join("*").to_str().unwrap()],
+            NonZeroUsize::new(10).unwrap(),
+            EventLogFilter::default(),
+        )
+        .unwrap();
+
+        assert_eq!(replay.segments().count(), 3);
+        assert_eq!(replay.runs(), [Some(provenance)]);
+        assert_eq!(
+            replay.collect::<Result<Vec<PackedEvent>, _>>().unwrap(),
+            testing::events(0..25)
+        );
+    }
+
+    fn filter(filter: &str) -> EventLogFilter {
+        ron::from_str(filter).unwrap()
+    }
+
+    #[test]
+    fn filtered_replay_spans_segments() {
+        let dir = tempfile::tempdir().unwrap();
+
+        testing::write(&dir.path().join("a"), &testing::events(0..10));
+        testing::write(&dir.path().join("b"), &testing::events(10..20));
+        testing::write(&dir.path().join("c"), &testing::events(20..30));
+
+        let replay = |filter| {
+            EventLogReplay::try_from_globs(
+                [dir.path().join("*").to_str().unwrap()],
+                NonZeroUsize::new(4).unwrap(),
+                filter,
+            )
+            .unwrap()
+        };
+
+        // Events of lineage `i` happen at time `i + 1`
+        let filtered = replay(self::filter("(after: Some(5.0), before: Some(25.0))"));
+        assert_eq!(filtered.segments().count(), 3);
+        assert_eq!(
+            filtered.collect::<Result<Vec<_>, _>>().unwrap(),
+            testing::events(5..24)
+        );
+
+        let filtered = replay(self::filter("(after: Some(15.0))"));
+        assert_eq!(filtered.segments().count(), 2);
+        assert_eq!(
+            filtered.collect::<Result<Vec<_>, _>>().unwrap(),
+            testing::events(15..30)
+        );
+
+        let filtered = replay(self::filter("(before: Some(3.0))"));
+        assert_eq!(filtered.segments().count(), 1);
+        assert_eq!(
+            filtered.collect::<Result<Vec<_>, _>>().unwrap(),
+            testing::events(0..2)
+        );
+
+        let filtered = replay(self::filter("(lineages: Some([3, 17, 29]))"));
+        assert_eq!(
+            filtered.collect::<Result<Vec<_>, _>>().unwrap(),
+            [3, 17, 29]
+                .into_iter()
+                .flat_map(|i| testing::events(i..(i + 1)))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn non_overlapping_time_window_replays_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+
+        testing::write(&dir.path().join("a"), &testing::events(0..10));
+
+        let replay = EventLogReplay::try_from_globs(
+            [dir.path().join("*").to_str().unwrap()],
+            NonZeroUsize::new(4).unwrap(),
+            filter("(after: Some(20.0), before: Some(30.0))"),
+        )
+        .unwrap();
+
+        assert!(replay.with_speciation());
+        assert_eq!(replay.runs(), [None]);
+        assert_eq!(replay.segments().count(), 0);
+        assert_eq!(replay.count(), 0);
+    }
+
+    #[test]
+    fn skipped_segments_are_not_read() {
+        let dir = tempfile::tempdir().unwrap();
+
+        testing::write(&dir.path().join("a"), &testing::events(0..10));
+
+        // Corrupt the events of the second segment, but not its header
+        let path = dir.path().join("b");
+        let mut bytes = testing::write(&path, &testing::events(10..20));
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        fs::write(&path, &bytes).unwrap();
+
+        let replay = |filter| {
+            EventLogReplay::try_from_globs(
+                [dir.path().join("*").to_str().unwrap()],
+                NonZeroUsize::new(4).unwrap(),
+                filter,
+            )
+            .unwrap()
+        };
+
+        assert!(replay(EventLogFilter::default()).any(|event| event.is_err()));
+
+        let replay = replay(filter("(before: Some(10.5))"));
+
+        assert_eq!(
+            replay.collect::<Result<Vec<_>, _>>().unwrap(),
+            testing::events(0..10)
+        );
+    }
+
+    #[test]
+    fn events_before_an_error_are_not_lost() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("segment");
+
+        let events = testing::events(0..SEGMENT_BLOCK_EVENTS + 10);
+        let bytes = testing::write(&path, &events);
+
+        // Truncate within the second block
+        fs::write(&path, &bytes[..bytes.len() - 8]).unwrap();
+
+        for capacity in [1, 10, SEGMENT_BLOCK_EVENTS] {
+            let replay = EventLogReplay::try_from_globs(
+                [path.to_str().unwrap()],
+                NonZeroUsize::new(capacity).unwrap(),
+                EventLogFilter::default(),
+            )
+            .unwrap();
+
+            let mut replayed = Vec::new();
+            let mut errors = 0;
+
+            for event in replay {
+                match event {
+                    Ok(event) => {
+                        assert_eq!(errors, 0, "no events may follow an error");
+                        replayed.push(event);
+                    },
+                    Err(_) => errors += 1,
+                }
+            }
+
+            assert_eq!(errors, 1);
+            assert_eq!(replayed, events[..SEGMENT_BLOCK_EVENTS]);
+        }
+    }
+}
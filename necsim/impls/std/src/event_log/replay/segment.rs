@@ -2,8 +2,6 @@ use std::{
     cmp::{Ord, Ordering},
     collections::VecDeque,
     fmt,
-    fs::{File, OpenOptions},
-    io::BufReader,
     num::NonZeroUsize,
     path::{Path, PathBuf},
 };
@@ -12,15 +10,22 @@ use anyhow::Result;
 
 use necsim_core::event::PackedEvent;
 
-use crate::event_log::EventLogHeader;
+use crate::event_log::{
+    format::{SegmentFormatError, SegmentReader},
+    index::SegmentIndex,
+    EventLogHeader, EventLogProvenance,
+};
+
+use super::filter::EventLogFilter;
 
 #[allow(clippy::module_name_repetitions)]
 pub struct SortedSegment {
     path: PathBuf,
     header: EventLogHeader,
-    reader: BufReader<File>,
+    reader: SegmentReader,
     buffer: VecDeque<PackedEvent>,
     capacity: NonZeroUsize,
+    error: Option<SegmentFormatError>,
 }
 
 impl fmt::Debug for SortedSegment {
@@ -33,36 +38,51 @@ impl fmt::Debug for SortedSegment {
 }
 
 impl SortedSegment {
+    /// Opens the segment at `path` and only reads its header, its events are
+    /// not read until they are first iterated over.
+    ///
     /// # Errors
     ///
     /// Fails if the `path` cannot be read as an event log segment
     pub fn try_new(path: &Path, capacity: NonZeroUsize) -> Result<Self> {
-        let file = OpenOptions::new().read(true).write(false).open(path)?;
-
-        let mut buf_reader = BufReader::new(file);
-
-        let header: EventLogHeader = bincode::deserialize_from(&mut buf_reader)?;
-
-        let mut buffer = VecDeque::with_capacity(header.length.min(capacity.get()));
-
-        if let Ok(event) = bincode::deserialize_from(&mut buf_reader) {
-            buffer.push_back(event);
-        }
+        let (header, segment_reader) = SegmentReader::try_new(path)?;
 
         Ok(Self {
             path: path.to_owned(),
             header,
-            reader: buf_reader,
-            buffer,
+            reader: segment_reader,
+            buffer: VecDeque::new(),
             capacity,
+            error: None,
         })
     }
 
-    pub fn set_capacity(&mut self, capacity: NonZeroUsize) {
-        if let Some(additional) = capacity.get().checked_sub(self.capacity.get()) {
-            self.buffer.reserve(additional);
+    /// Uses the segment's sidecar index, if it exists, to skip all blocks of
+    /// events that cannot pass the `filter`.
+    ///
+    /// Returns whether the index was used.
+    pub fn seek(&mut self, filter: &EventLogFilter) -> bool {
+        if filter.is_empty() {
+            return false;
+        }
+
+        let Some(index) = SegmentIndex::try_read(&self.path) else {
+            return false;
+        };
+
+        if !self
+            .reader
+            .select_blocks(&index, |block| filter.overlaps_block(block))
+        {
+            return false;
         }
 
+        self.buffer.clear();
+
+        true
+    }
+
+    pub fn set_capacity(&mut self, capacity: NonZeroUsize) {
         self.capacity = capacity;
     }
 
@@ -85,25 +105,49 @@ impl SortedSegment {
     pub fn path(&self) -> &Path {
         &self.path
     }
+
+    /// Reads the provenance of the simulation run that recorded this segment
+    /// from the sidecar file in the segment's directory.
+    #[must_use]
+    pub fn provenance(&self) -> Option<EventLogProvenance> {
+        EventLogProvenance::try_read(self.path.parent()?, self.header.run()?)
+    }
+
+    /// Takes the error that stopped the iteration over this segment early, if
+    /// the segment turned out to be truncated or corrupted.
+    ///
+    /// The error is only returned once all events that were read before it
+    /// have been taken from the segment.
+    pub fn take_error(&mut self) -> Option<SegmentFormatError> {
+        if self.buffer.is_empty() {
+            self.error.take()
+        } else {
+            None
+        }
+    }
 }
 
 impl Iterator for SortedSegment {
     type Item = PackedEvent;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let next_event = self.buffer.pop_front();
+        if self.buffer.is_empty() && self.error.is_none() {
+            self.buffer
+                .reserve(self.capacity.get().min(self.header.length()));
 
-        if next_event.is_some() && self.buffer.is_empty() {
             for _ in 0..self.capacity.get() {
-                if let Ok(event) = bincode::deserialize_from(&mut self.reader) {
-                    self.buffer.push_back(event);
-                } else {
-                    break;
+                match self.reader.read_event() {
+                    Ok(Some(event)) => self.buffer.push_back(event),
+                    Ok(None) => break,
+                    Err(err) => {
+                        self.error = Some(err);
+                        break;
+                    },
                 }
             }
         }
 
-        next_event
+        self.buffer.pop_front()
     }
 }
 
@@ -5,12 +5,17 @@ use std::{
 
 use necsim_core::event::PackedEvent;
 
+use crate::event_log::format::SegmentFormatError;
+
 use super::segment::SortedSegment;
 
 #[allow(clippy::module_name_repetitions)]
 pub struct SortedSortedSegments {
     segments: Vec<SortedSegment>,
     next: Option<PackedEvent>,
+    // Error that occurred right after reading the `next` event
+    next_error: Option<SegmentFormatError>,
+    error: Option<SegmentFormatError>,
 }
 
 impl fmt::Debug for SortedSortedSegments {
@@ -44,6 +49,8 @@ impl SortedSortedSegments {
         let mut this = Self {
             segments,
             next: None,
+            next_error: None,
+            error: None,
         };
 
         this.next();
@@ -58,12 +65,23 @@ impl SortedSortedSegments {
     pub fn segments(&self) -> &[SortedSegment] {
         &self.segments
     }
+
+    /// Takes the error that occurred right after the most recently returned
+    /// event, which has thus not been lost.
+    pub fn take_error(&mut self) -> Option<SegmentFormatError> {
+        self.error.take()
+    }
 }
 
 impl Iterator for SortedSortedSegments {
     type Item = PackedEvent;
 
     fn next(&mut self) -> Option<Self::Item> {
+        // The error is only reported once the event before it is returned
+        if let Some(error) = self.next_error.take() {
+            self.error = self.error.take().or(Some(error));
+        }
+
         let next = std::mem::replace(
             &mut self.next,
             loop {
@@ -72,10 +90,14 @@ impl Iterator for SortedSortedSegments {
                 };
 
                 if let Some(next_event) = next_segment.next() {
+                    self.next_error = next_segment.take_error();
+
                     break Some(next_event);
                 }
 
-                self.segments.pop();
+                if let Some(mut segment) = self.segments.pop() {
+                    self.error = self.error.take().or_else(|| segment.take_error());
+                }
             },
         );
 
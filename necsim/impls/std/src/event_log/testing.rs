@@ -0,0 +1,55 @@
+use std::{fs, ops::Range, path::Path};
+
+use necsim_core::{
+    event::{PackedEvent, SpeciationEvent},
+    landscape::{IndexedLocation, Location},
+    lineage::GlobalLineageReference,
+};
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+use super::{format::write_segment, EventLogHeader};
+
+/// Speciation events of lineage `i` at time `i + 1` for every `i` in `range`
+pub(super) fn events(range: Range<usize>) -> Vec<PackedEvent> {
+    range
+        .map(|i| {
+            #[allow(clippy::cast_precision_loss)]
+            let time = (i + 1) as f64;
+
+            PackedEvent::from(SpeciationEvent {
+                global_lineage_reference: unsafe { GlobalLineageReference::from_inner(i as u64) },
+                prior_time: NonNegativeF64::new(time - 1.0).unwrap(),
+                event_time: PositiveF64::new(time).unwrap(),
+                origin: IndexedLocation::new(Location::new(0, 0), 0),
+            })
+        })
+        .collect()
+}
+
+/// Header of a sorted segment of `events`
+pub(super) fn header(events: &[PackedEvent]) -> EventLogHeader {
+    EventLogHeader::new(
+        events.first().unwrap().event_time(),
+        events.last().unwrap().event_time(),
+        events.len(),
+        true,
+        false,
+        None,
+    )
+}
+
+/// Writes a segment of sorted `events` to `path` and returns its bytes
+pub(super) fn write(path: &Path, events: &[PackedEvent]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    write_segment(&mut bytes, &header(events), events).unwrap();
+    fs::write(path, &bytes).unwrap();
+    bytes
+}
+
+/// Writes a segment of sorted `events` and its sidecar index to `path`
+pub(super) fn write_indexed(path: &Path, events: &[PackedEvent]) {
+    let mut bytes = Vec::new();
+    let index = write_segment(&mut bytes, &header(events), events).unwrap();
+    fs::write(path, &bytes).unwrap();
+    index.write(path).unwrap();
+}
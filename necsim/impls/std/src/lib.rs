@@ -6,3 +6,4 @@ extern crate contracts;
 pub mod cogs;
 pub mod event_log;
 pub mod lineage_file;
+pub mod parallelisation;
@@ -0,0 +1,493 @@
+use std::{
+    collections::VecDeque,
+    num::{NonZeroU64, NonZeroUsize, Wrapping},
+    ops::ControlFlow,
+    sync::{mpsc, Mutex, PoisonError},
+};
+
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+use necsim_core::{
+    cogs::{
+        DispersalSampler, Habitat, MathsCore, PrimeableRng, SpeciationProbability, TurnoverRate,
+    },
+    event::TypedEvent,
+    lineage::Lineage,
+    reporter::{boolean::Boolean, Reporter},
+    simulation::Simulation,
+};
+
+use necsim_partitioning_core::LocalPartition;
+
+use necsim_impls_no_std::{
+    cache::DirectMappedCache,
+    cogs::{
+        active_lineage_sampler::singular::SingularActiveLineageSampler,
+        coalescence_sampler::independent::IndependentCoalescenceSampler,
+        emigration_exit::never::NeverEmigrationExit,
+        event_sampler::{
+            independent::IndependentEventSampler,
+            tracking::{MinSpeciationTrackingEventSampler, SpeciationSample},
+        },
+        immigration_entry::never::NeverImmigrationEntry,
+        lineage_store::independent::IndependentLineageStore,
+    },
+    parallelisation::{
+        independent::{
+            monolithic::reporter::{
+                WaterLevelReporterConstructor, WaterLevelReporterProxy, WaterLevelReporterStrategy,
+            },
+            DedupCache, EventSlice,
+        },
+        Status,
+    },
+};
+
+mod reporter;
+mod shared;
+
+#[cfg(test)]
+mod test;
+
+use reporter::EventBufferReporter;
+use shared::SharedSimulation;
+
+type IndependentSimulation<M, H, G, D, T, N, A> = Simulation<
+    M,
+    H,
+    G,
+    IndependentLineageStore<M, H>,
+    NeverEmigrationExit,
+    D,
+    IndependentCoalescenceSampler<M, H>,
+    T,
+    N,
+    IndependentEventSampler<M, H, G, NeverEmigrationExit, D, T, N>,
+    NeverImmigrationEntry,
+    A,
+>;
+
+/// # Panics
+///
+/// Panics if any of the worker threads panicked.
+#[allow(
+    clippy::type_complexity,
+    clippy::too_many_lines,
+    clippy::too_many_arguments
+)]
+pub fn simulate<
+    'p,
+    M: MathsCore + Send,
+    H: Habitat<M> + Send + Sync,
+    G: PrimeableRng<M> + Send,
+    D: DispersalSampler<M, H, G> + Send + Sync,
+    T: TurnoverRate<M, H> + Send + Sync,
+    N: SpeciationProbability<M, H> + Send + Sync,
+    A: SingularActiveLineageSampler<
+            M,
+            H,
+            G,
+            IndependentLineageStore<M, H>,
+            NeverEmigrationExit,
+            D,
+            IndependentCoalescenceSampler<M, H>,
+            T,
+            N,
+            IndependentEventSampler<M, H, G, NeverEmigrationExit, D, T, N>,
+            NeverImmigrationEntry,
+        > + Send,
+    R: Reporter,
+    P: LocalPartition<'p, R>,
+    L: IntoIterator<Item = Lineage>,
+>(
+    simulation: &mut IndependentSimulation<M, H, G, D, T, N, A>,
+    lineages: L,
+    dedup_cache: DedupCache,
+    step_slice: NonZeroU64,
+    event_slice: EventSlice,
+    workers: NonZeroUsize,
+    pause_before: Option<NonNegativeF64>,
+    local_partition: &mut P,
+) -> (
+    Status,
+    NonNegativeF64,
+    u64,
+    impl IntoIterator<Item = Lineage>,
+) {
+    let mut lineages = lineages.into_iter().collect::<Vec<_>>();
+
+    // The workers are cloned from the simulation, which must thus be empty
+    if let Some(active_lineage) = simulation
+        .active_lineage_sampler_mut()
+        .replace_active_lineage(None)
+    {
+        lineages.push(active_lineage);
+    }
+    let _ = simulation.event_sampler_mut().replace_min_speciation(None);
+
+    // Ensure that the progress bar starts with the expected target
+    local_partition.report_progress_sync(lineages.len() as u64);
+
+    let event_slice = event_slice.capacity(lineages.len());
+
+    // All workers share one deduplication cache, such that a lineage which
+    //  has coalesced with a lineage of another worker is only simulated once
+    let min_spec_samples = Mutex::new(dedup_cache.construct(lineages.len()));
+
+    // Each worker owns a disjoint subset of the lineages for the entire run
+    let mut worker_lineages = (0..workers.get())
+        .map(|_| Vec::with_capacity(lineages.len() / workers.get() + 1))
+        .collect::<Vec<_>>();
+    for (i, lineage) in lineages.into_iter().enumerate() {
+        worker_lineages[i % workers.get()].push(lineage);
+    }
+
+    // The workers only copy the mutable state of the simulation, and share its
+    //  read-only habitat, dispersal sampler, turnover rate, and speciation
+    //  probability
+    let rng = simulation.rng_mut().backup();
+    let simulation = &*simulation;
+    let mut workers: Vec<Worker<M, H, G, D, T, N, A, R>> = worker_lineages
+        .into_iter()
+        .map(|lineages| {
+            Worker::new(
+                SharedSimulation::new(simulation, &rng),
+                lineages,
+                event_slice,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut proxy = <WaterLevelReporterStrategy as WaterLevelReporterConstructor<
+        P::IsLive,
+        R,
+        P,
+    >>::WaterLevelReporter::new(event_slice.get(), local_partition);
+
+    let mut total_steps = 0_u64;
+    #[allow(clippy::or_fun_call)]
+    let mut max_time = workers
+        .iter()
+        .flat_map(|worker| worker.slow_lineages.iter())
+        .map(|(lineage, _)| lineage.last_event_time)
+        .max()
+        .unwrap_or(NonNegativeF64::zero());
+
+    #[allow(clippy::or_fun_call)]
+    let mut level_time = workers
+        .iter()
+        .flat_map(|worker| worker.slow_lineages.iter())
+        .map(|(lineage, _)| lineage.last_event_time)
+        .min()
+        .unwrap_or(NonNegativeF64::zero());
+
+    std::thread::scope(|scope| {
+        // Each worker is simulated on its own thread of the pool, which is
+        //  kept alive across all water-level iterations
+        let pool = (0..workers.len())
+            .map(|_| {
+                let (task_sender, task_receiver) =
+                    mpsc::channel::<(&mut Worker<M, H, G, D, T, N, A, R>, NonNegativeF64)>();
+                let (done_sender, done_receiver) = mpsc::channel();
+                let min_spec_samples = &min_spec_samples;
+
+                scope.spawn(move || {
+                    for (worker, level_time) in task_receiver {
+                        worker.simulate_below_water_level(level_time, step_slice, min_spec_samples);
+
+                        if done_sender.send(worker).is_err() {
+                            break;
+                        }
+                    }
+                });
+
+                (task_sender, done_receiver)
+            })
+            .collect::<Vec<_>>();
+
+        let mut workers = workers.iter_mut().collect::<Vec<_>>();
+
+        while workers
+            .iter()
+            .any(|worker| !worker.slow_lineages.is_empty())
+            && pause_before.map_or(true, |pause_before| level_time < pause_before)
+        {
+            // Calculate a new water-level time which all individuals should reach
+            let total_event_rate: NonNegativeF64 =
+                workers.iter().map(|worker| worker.total_event_rate()).sum();
+
+            level_time += NonNegativeF64::from(event_slice.get()) / total_event_rate;
+
+            if let Some(pause_before) = pause_before {
+                level_time = level_time.min(pause_before);
+            }
+
+            // [Report all events below the water level] + Advance the water level
+            proxy.advance_water_level(level_time);
+
+            // Simulate all slow lineages until they have finished or exceeded the new
+            //  water level, with each worker running on its own thread of the pool
+            for ((task_sender, _), worker) in pool.iter().zip(workers.drain(..)) {
+                task_sender
+                    .send((worker, level_time))
+                    .expect("threaded worker must be alive");
+            }
+
+            // Collect the workers in order, such that their events are merged
+            //  deterministically
+            workers.extend(pool.iter().map(|(_, done_receiver)| {
+                done_receiver
+                    .recv()
+                    .expect("threaded worker must not have panicked")
+            }));
+
+            // Merge the events from all workers, which the proxy reports in order
+            for worker in &mut workers {
+                total_steps += worker.steps;
+                max_time = max_time.max(worker.max_time);
+                worker.steps = 0;
+
+                for event in worker.reporter.drain() {
+                    match event.into() {
+                        TypedEvent::Speciation(event) => {
+                            proxy.report_speciation(&event.into());
+                        },
+                        TypedEvent::Dispersal(event) => {
+                            proxy.report_dispersal(&event.into());
+                        },
+                    }
+                }
+
+                // Fast lineages are now slow again
+                std::mem::swap(&mut worker.slow_lineages, &mut worker.fast_lineages);
+            }
+
+            proxy.local_partition().get_reporter().report_progress(
+                &workers
+                    .iter()
+                    .map(|worker| Wrapping(worker.slow_lineages.len() as u64))
+                    .sum::<Wrapping<u64>>()
+                    .0
+                    .into(),
+            );
+        }
+
+        // Dropping the task senders shuts down the pool
+        std::mem::drop(pool);
+    });
+
+    // [Report all remaining events]
+    proxy.finalise();
+
+    let lineages = workers
+        .into_iter()
+        .flat_map(|worker| worker.slow_lineages)
+        .map(|(lineage, _)| lineage)
+        .collect::<Vec<_>>();
+
+    local_partition.report_progress_sync(lineages.len() as u64);
+
+    let status = Status::paused(local_partition.reduce_vote_continue(!lineages.is_empty()));
+    let (global_time, global_steps) =
+        local_partition.reduce_global_time_steps(max_time, total_steps);
+
+    (status, global_time, global_steps, lineages)
+}
+
+struct Worker<
+    's,
+    M: MathsCore,
+    H: Habitat<M> + Sync,
+    G: PrimeableRng<M>,
+    D: DispersalSampler<M, H, G> + Sync,
+    T: TurnoverRate<M, H> + Sync,
+    N: SpeciationProbability<M, H> + Sync,
+    A: SingularActiveLineageSampler<
+        M,
+        H,
+        G,
+        IndependentLineageStore<M, H>,
+        NeverEmigrationExit,
+        D,
+        IndependentCoalescenceSampler<M, H>,
+        T,
+        N,
+        IndependentEventSampler<M, H, G, NeverEmigrationExit, D, T, N>,
+        NeverImmigrationEntry,
+    >,
+    R: Reporter,
+> {
+    simulation: SharedSimulation<'s, M, H, G, D, T, N, A>,
+    slow_lineages: VecDeque<(Lineage, NonNegativeF64)>,
+    fast_lineages: VecDeque<(Lineage, NonNegativeF64)>,
+    reporter: EventBufferReporter<R>,
+    steps: u64,
+    max_time: NonNegativeF64,
+}
+
+impl<
+        's,
+        M: MathsCore,
+        H: Habitat<M> + Sync,
+        G: PrimeableRng<M>,
+        D: DispersalSampler<M, H, G> + Sync,
+        T: TurnoverRate<M, H> + Sync,
+        N: SpeciationProbability<M, H> + Sync,
+        A: SingularActiveLineageSampler<
+            M,
+            H,
+            G,
+            IndependentLineageStore<M, H>,
+            NeverEmigrationExit,
+            D,
+            IndependentCoalescenceSampler<M, H>,
+            T,
+            N,
+            IndependentEventSampler<M, H, G, NeverEmigrationExit, D, T, N>,
+            NeverImmigrationEntry,
+        >,
+        R: Reporter,
+    > Worker<'s, M, H, G, D, T, N, A, R>
+{
+    fn new(
+        simulation: SharedSimulation<'s, M, H, G, D, T, N, A>,
+        lineages: Vec<Lineage>,
+        event_slice: NonZeroUsize,
+    ) -> Self {
+        let slow_lineages = lineages
+            .into_iter()
+            .map(|lineage| {
+                // We only need a strict lower bound here,
+                //  i.e. that the next event >= pessimistic_next_event_time
+                let pessimistic_next_event_time = lineage.last_event_time;
+
+                (lineage, pessimistic_next_event_time)
+            })
+            .collect::<VecDeque<_>>();
+
+        Self {
+            simulation,
+            slow_lineages,
+            fast_lineages: VecDeque::new(),
+            reporter: EventBufferReporter::with_capacity(event_slice.get()),
+            steps: 0,
+            max_time: NonNegativeF64::zero(),
+        }
+    }
+
+    fn total_event_rate(&self) -> NonNegativeF64 {
+        let simulation = &self.simulation;
+
+        if R::ReportDispersal::VALUE {
+            // Full event rate lambda with speciation
+            self.slow_lineages
+                .iter()
+                .map(|(lineage, _)| {
+                    simulation.turnover_rate().get_turnover_rate_at_location(
+                        lineage.indexed_location.location(),
+                        simulation.habitat(),
+                    )
+                })
+                .sum()
+        } else if R::ReportSpeciation::VALUE {
+            // Only speciation event rate lambda * nu
+            self.slow_lineages
+                .iter()
+                .map(|(lineage, _)| {
+                    let location = lineage.indexed_location.location();
+
+                    simulation
+                        .turnover_rate()
+                        .get_turnover_rate_at_location(location, simulation.habitat())
+                        * simulation
+                            .speciation_probability()
+                            .get_speciation_probability_at_location(location, simulation.habitat())
+                })
+                .sum()
+        } else {
+            // No events produced -> no restriction
+            NonNegativeF64::zero()
+        }
+    }
+
+    fn simulate_below_water_level(
+        &mut self,
+        level_time: NonNegativeF64,
+        step_slice: NonZeroU64,
+        min_spec_samples: &Mutex<DirectMappedCache<SpeciationSample>>,
+    ) {
+        let mut previous_next_event_time: Option<PositiveF64> = None;
+
+        while !self.slow_lineages.is_empty()
+            || self
+                .simulation
+                .active_lineage_sampler()
+                .number_active_lineages()
+                > 0
+        {
+            let next_slow_lineage = loop {
+                match self.slow_lineages.pop_front() {
+                    None => break None,
+                    Some((slow_lineage, next_event)) if next_event < level_time => {
+                        break Some(slow_lineage)
+                    },
+                    Some((fast_lineage, next_event)) => {
+                        self.fast_lineages.push_back((fast_lineage, next_event));
+                    },
+                }
+            };
+
+            let previous_task = self
+                .simulation
+                .active_lineage_sampler_mut()
+                .replace_active_lineage(next_slow_lineage);
+
+            let previous_speciation_sample = self
+                .simulation
+                .event_sampler_mut()
+                .replace_min_speciation(None);
+
+            if let (Some(previous_task), Some(previous_next_event_time)) =
+                (previous_task, previous_next_event_time)
+            {
+                // The shared cache is only locked once per task switch
+                let duplicate_individual =
+                    previous_speciation_sample.map_or(false, |spec_sample| {
+                        !min_spec_samples
+                            .lock()
+                            .unwrap_or_else(PoisonError::into_inner)
+                            .insert(spec_sample)
+                    });
+
+                if !duplicate_individual {
+                    // Reclassify lineages as either slow (still below water) or fast
+                    if previous_next_event_time < level_time {
+                        self.slow_lineages
+                            .push_back((previous_task, previous_next_event_time.into()));
+                    } else {
+                        self.fast_lineages
+                            .push_back((previous_task, previous_next_event_time.into()));
+                    }
+                }
+            }
+
+            previous_next_event_time = None;
+
+            let (new_time, new_steps) = self.simulation.simulate_incremental_early_stop(
+                |_, steps, next_event_time| {
+                    previous_next_event_time = Some(next_event_time);
+
+                    if steps >= step_slice.get() || next_event_time >= level_time {
+                        ControlFlow::Break(())
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                },
+                &mut self.reporter,
+            );
+
+            self.steps += new_steps;
+            self.max_time = self.max_time.max(new_time);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::{fmt, marker::PhantomData};
+
+use necsim_core::{event::PackedEvent, impl_report, reporter::Reporter};
+
+#[allow(clippy::module_name_repetitions)]
+pub struct EventBufferReporter<R: Reporter> {
+    events: Vec<PackedEvent>,
+    _marker: PhantomData<fn() -> R>,
+}
+
+impl<R: Reporter> fmt::Debug for EventBufferReporter<R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        struct EventBufferLen(usize);
+
+        impl fmt::Debug for EventBufferLen {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "Vec<PackedEvent; {}>", self.0)
+            }
+        }
+
+        fmt.debug_struct(stringify!(EventBufferReporter))
+            .field("events", &EventBufferLen(self.events.len()))
+            .finish()
+    }
+}
+
+impl<R: Reporter> Reporter for EventBufferReporter<R> {
+    impl_report!(speciation(&mut self, speciation: MaybeUsed<R::ReportSpeciation>) {
+        self.events.push(speciation.clone().into());
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: MaybeUsed<R::ReportDispersal>) {
+        self.events.push(dispersal.clone().into());
+    });
+
+    impl_report!(progress(&mut self, _progress: Ignored) {});
+}
+
+impl<R: Reporter> EventBufferReporter<R> {
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            events: Vec::with_capacity(capacity),
+            _marker: PhantomData::<fn() -> R>,
+        }
+    }
+
+    pub fn drain(&mut self) -> std::vec::Drain<PackedEvent> {
+        self.events.drain(..)
+    }
+}
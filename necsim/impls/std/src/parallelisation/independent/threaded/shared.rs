@@ -0,0 +1,205 @@
+use std::{
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+};
+
+use necsim_core::{
+    cogs::{
+        Backup, DispersalSampler, Habitat, MathsCore, PrimeableRng, SpeciationProbability,
+        TurnoverRate,
+    },
+    simulation::SimulationBuilder,
+};
+
+use necsim_impls_no_std::cogs::{
+    active_lineage_sampler::singular::SingularActiveLineageSampler,
+    coalescence_sampler::independent::IndependentCoalescenceSampler,
+    emigration_exit::never::NeverEmigrationExit,
+    event_sampler::independent::IndependentEventSampler,
+    immigration_entry::never::NeverImmigrationEntry,
+    lineage_store::independent::IndependentLineageStore,
+};
+
+use super::IndependentSimulation;
+
+/// A worker's copy of a simulation, which only clones the mutable per-worker
+/// state, i.e. the rng, lineage store, and samplers. The read-only habitat,
+/// dispersal sampler, turnover rate, and speciation probability are shared
+/// with the original simulation, which must outlive the copy.
+pub struct SharedSimulation<
+    's,
+    M: MathsCore,
+    H: Habitat<M> + Sync,
+    G: PrimeableRng<M>,
+    D: DispersalSampler<M, H, G> + Sync,
+    T: TurnoverRate<M, H> + Sync,
+    N: SpeciationProbability<M, H> + Sync,
+    A: SingularActiveLineageSampler<
+        M,
+        H,
+        G,
+        IndependentLineageStore<M, H>,
+        NeverEmigrationExit,
+        D,
+        IndependentCoalescenceSampler<M, H>,
+        T,
+        N,
+        IndependentEventSampler<M, H, G, NeverEmigrationExit, D, T, N>,
+        NeverImmigrationEntry,
+    >,
+> {
+    simulation: ManuallyDrop<IndependentSimulation<M, H, G, D, T, N, A>>,
+    marker: PhantomData<&'s ()>,
+}
+
+impl<
+        's,
+        M: MathsCore,
+        H: Habitat<M> + Sync,
+        G: PrimeableRng<M>,
+        D: DispersalSampler<M, H, G> + Sync,
+        T: TurnoverRate<M, H> + Sync,
+        N: SpeciationProbability<M, H> + Sync,
+        A: SingularActiveLineageSampler<
+            M,
+            H,
+            G,
+            IndependentLineageStore<M, H>,
+            NeverEmigrationExit,
+            D,
+            IndependentCoalescenceSampler<M, H>,
+            T,
+            N,
+            IndependentEventSampler<M, H, G, NeverEmigrationExit, D, T, N>,
+            NeverImmigrationEntry,
+        >,
+    > SharedSimulation<'s, M, H, G, D, T, N, A>
+{
+    pub fn new(simulation: &'s IndependentSimulation<M, H, G, D, T, N, A>, rng: &G) -> Self {
+        // Safety:
+        // - the simulation only provides immutable access to its habitat, dispersal
+        //   sampler, turnover rate, and speciation probability, so their bitwise copies
+        //   are only ever read, which is thread-safe as they are `Sync`
+        // - the copies are never dropped, so they are only freed once by the original
+        //   simulation, which the lifetime 's keeps alive
+        let simulation = unsafe {
+            SimulationBuilder {
+                maths: PhantomData::<M>,
+                habitat: std::ptr::read(simulation.habitat()),
+                lineage_store: simulation.lineage_store().backup_unchecked(),
+                dispersal_sampler: std::ptr::read(simulation.dispersal_sampler()),
+                coalescence_sampler: simulation.coalescence_sampler().backup_unchecked(),
+                turnover_rate: std::ptr::read(simulation.turnover_rate()),
+                speciation_probability: std::ptr::read(simulation.speciation_probability()),
+                emigration_exit: NeverEmigrationExit::default(),
+                event_sampler: simulation.event_sampler().backup_unchecked(),
+                active_lineage_sampler: simulation.active_lineage_sampler().backup_unchecked(),
+                rng: rng.backup_unchecked(),
+                immigration_entry: NeverImmigrationEntry::default(),
+            }
+        }
+        .build();
+
+        Self {
+            simulation: ManuallyDrop::new(simulation),
+            marker: PhantomData::<&'s ()>,
+        }
+    }
+}
+
+impl<
+        M: MathsCore,
+        H: Habitat<M> + Sync,
+        G: PrimeableRng<M>,
+        D: DispersalSampler<M, H, G> + Sync,
+        T: TurnoverRate<M, H> + Sync,
+        N: SpeciationProbability<M, H> + Sync,
+        A: SingularActiveLineageSampler<
+            M,
+            H,
+            G,
+            IndependentLineageStore<M, H>,
+            NeverEmigrationExit,
+            D,
+            IndependentCoalescenceSampler<M, H>,
+            T,
+            N,
+            IndependentEventSampler<M, H, G, NeverEmigrationExit, D, T, N>,
+            NeverImmigrationEntry,
+        >,
+    > Deref for SharedSimulation<'_, M, H, G, D, T, N, A>
+{
+    type Target = IndependentSimulation<M, H, G, D, T, N, A>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.simulation
+    }
+}
+
+impl<
+        M: MathsCore,
+        H: Habitat<M> + Sync,
+        G: PrimeableRng<M>,
+        D: DispersalSampler<M, H, G> + Sync,
+        T: TurnoverRate<M, H> + Sync,
+        N: SpeciationProbability<M, H> + Sync,
+        A: SingularActiveLineageSampler<
+            M,
+            H,
+            G,
+            IndependentLineageStore<M, H>,
+            NeverEmigrationExit,
+            D,
+            IndependentCoalescenceSampler<M, H>,
+            T,
+            N,
+            IndependentEventSampler<M, H, G, NeverEmigrationExit, D, T, N>,
+            NeverImmigrationEntry,
+        >,
+    > DerefMut for SharedSimulation<'_, M, H, G, D, T, N, A>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.simulation
+    }
+}
+
+impl<
+        M: MathsCore,
+        H: Habitat<M> + Sync,
+        G: PrimeableRng<M>,
+        D: DispersalSampler<M, H, G> + Sync,
+        T: TurnoverRate<M, H> + Sync,
+        N: SpeciationProbability<M, H> + Sync,
+        A: SingularActiveLineageSampler<
+            M,
+            H,
+            G,
+            IndependentLineageStore<M, H>,
+            NeverEmigrationExit,
+            D,
+            IndependentCoalescenceSampler<M, H>,
+            T,
+            N,
+            IndependentEventSampler<M, H, G, NeverEmigrationExit, D, T, N>,
+            NeverImmigrationEntry,
+        >,
+    > Drop for SharedSimulation<'_, M, H, G, D, T, N, A>
+{
+    fn drop(&mut self) {
+        // Safety: the simulation is not accessed again after it has been taken
+        let SimulationBuilder {
+            habitat,
+            dispersal_sampler,
+            turnover_rate,
+            speciation_probability,
+            ..
+        } = unsafe { ManuallyDrop::take(&mut self.simulation) }.into_builder();
+
+        // The shared components are owned by the original simulation
+        std::mem::forget(habitat);
+        std::mem::forget(dispersal_sampler);
+        std::mem::forget(turnover_rate);
+        std::mem::forget(speciation_probability);
+    }
+}
@@ -0,0 +1,246 @@
+use std::{
+    collections::BTreeSet,
+    marker::PhantomData,
+    num::{NonZeroU32, NonZeroU64, NonZeroUsize},
+};
+
+use necsim_core::{
+    cogs::SeedableRng,
+    event::{DispersalEvent, SpeciationEvent},
+    impl_report,
+    landscape::IndexedLocation,
+    lineage::MigratingLineage,
+    reporter::{boolean::True, Reporter},
+    simulation::SimulationBuilder,
+};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, OffByOneU32, PositiveF64};
+
+use necsim_impls_no_std::{
+    cogs::{
+        active_lineage_sampler::independent::{
+            event_time_sampler::exp::ExpEventTimeSampler, IndependentActiveLineageSampler,
+        },
+        coalescence_sampler::independent::IndependentCoalescenceSampler,
+        dispersal_sampler::non_spatial::NonSpatialDispersalSampler,
+        emigration_exit::never::NeverEmigrationExit,
+        event_sampler::independent::IndependentEventSampler,
+        habitat::non_spatial::NonSpatialHabitat,
+        immigration_entry::never::NeverImmigrationEntry,
+        maths::intrinsics::IntrinsicsMathsCore,
+        origin_sampler::{non_spatial::NonSpatialOriginSampler, pre_sampler::OriginPreSampler},
+        rng::wyhash::WyHash,
+        speciation_probability::uniform::UniformSpeciationProbability,
+        turnover_rate::uniform::UniformTurnoverRate,
+    },
+    parallelisation::{
+        independent::{monolithic, AbsoluteCapacity, DedupCache, EventSlice, RelativeCapacity},
+        Status,
+    },
+};
+use necsim_partitioning_core::{partition::Partition, LocalPartition, MigrationMode};
+
+type M = IntrinsicsMathsCore;
+type H = NonSpatialHabitat<M>;
+type G = WyHash<M>;
+type D = NonSpatialDispersalSampler<M, G>;
+type A = IndependentActiveLineageSampler<
+    M,
+    H,
+    G,
+    NeverEmigrationExit,
+    D,
+    UniformTurnoverRate,
+    UniformSpeciationProbability,
+    ExpEventTimeSampler,
+>;
+
+// Events without their lineage reference, which depends on which of several
+//  coalesced lineages is deduplicated first
+type EventKey = (IndexedLocation, Option<IndexedLocation>, u64, u64);
+
+#[derive(Debug, Default)]
+struct EventCollector {
+    events: BTreeSet<EventKey>,
+    speciations: usize,
+}
+
+impl Reporter for EventCollector {
+    impl_report!(speciation(&mut self, speciation: Used) {
+        let SpeciationEvent { origin, prior_time, event_time, .. } = speciation;
+
+        self.events.insert((
+            origin.clone(), None, prior_time.get().to_bits(), event_time.get().to_bits(),
+        ));
+        self.speciations += 1;
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        let DispersalEvent { origin, target, prior_time, event_time, .. } = dispersal;
+
+        self.events.insert((
+            origin.clone(),
+            Some(target.clone()),
+            prior_time.get().to_bits(),
+            event_time.get().to_bits(),
+        ));
+    });
+
+    impl_report!(progress(&mut self, _progress: Ignored) {});
+}
+
+#[derive(Debug, Default)]
+struct TestLocalPartition {
+    reporter: EventCollector,
+}
+
+#[contract_trait]
+impl<'p> LocalPartition<'p, EventCollector> for TestLocalPartition {
+    type ImmigrantIterator<'a> = std::iter::Empty<MigratingLineage> where 'p: 'a;
+    type IsLive = True;
+    type Reporter = EventCollector;
+
+    fn get_reporter(&mut self) -> &mut Self::Reporter {
+        &mut self.reporter
+    }
+
+    fn is_root(&self) -> bool {
+        true
+    }
+
+    fn get_partition(&self) -> Partition {
+        Partition::monolithic()
+    }
+
+    fn migrate_individuals<'a, E: Iterator<Item = (u32, MigratingLineage)>>(
+        &'a mut self,
+        _emigrants: &mut E,
+        _emigration_mode: MigrationMode,
+        _immigration_mode: MigrationMode,
+    ) -> Self::ImmigrantIterator<'a>
+    where
+        'p: 'a,
+    {
+        std::iter::empty()
+    }
+
+    fn reduce_vote_continue(&self, local_continue: bool) -> bool {
+        local_continue
+    }
+
+    fn reduce_vote_min_time(&self, local_time: PositiveF64) -> Result<PositiveF64, PositiveF64> {
+        Ok(local_time)
+    }
+
+    fn wait_for_termination(&mut self) -> bool {
+        false
+    }
+
+    fn reduce_global_time_steps(
+        &self,
+        local_time: NonNegativeF64,
+        local_steps: u64,
+    ) -> (NonNegativeF64, u64) {
+        (local_time, local_steps)
+    }
+
+    fn report_progress_sync(&mut self, _remaining: u64) {}
+
+    fn finalise_reporting(self) {}
+}
+
+fn simulate(workers: Option<NonZeroUsize>, seed: u64) -> (Status, NonNegativeF64, EventCollector) {
+    let habitat = H::new(
+        (OffByOneU32::new(16).unwrap(), OffByOneU32::new(16).unwrap()),
+        NonZeroU32::new(2).unwrap(),
+    );
+
+    let (lineage_store, active_lineage_sampler, lineages) = A::init_with_store_and_lineages(
+        NonSpatialOriginSampler::new(OriginPreSampler::all(), &habitat),
+        ExpEventTimeSampler::new(PositiveF64::new(1.0).unwrap()),
+    );
+
+    let mut simulation = SimulationBuilder {
+        maths: PhantomData::<M>,
+        habitat,
+        lineage_store,
+        dispersal_sampler: D::default(),
+        coalescence_sampler: IndependentCoalescenceSampler::default(),
+        turnover_rate: UniformTurnoverRate::default(),
+        speciation_probability: UniformSpeciationProbability::new(
+            ClosedUnitF64::new(0.05).unwrap(),
+        ),
+        emigration_exit: NeverEmigrationExit::default(),
+        event_sampler: IndependentEventSampler::default(),
+        active_lineage_sampler,
+        rng: G::seed_from_u64(seed),
+        immigration_entry: NeverImmigrationEntry::default(),
+    }
+    .build();
+
+    let mut local_partition = TestLocalPartition::default();
+
+    let dedup_cache = DedupCache::Relative(RelativeCapacity {
+        factor: PositiveF64::new(2.0).unwrap(),
+    });
+    let step_slice = NonZeroU64::new(10).unwrap();
+    let event_slice = EventSlice::Absolute(AbsoluteCapacity {
+        capacity: NonZeroUsize::new(100).unwrap(),
+    });
+
+    let (status, time) = match workers {
+        Some(workers) => {
+            let (status, time, _steps, _lineages) = super::simulate(
+                &mut simulation,
+                lineages,
+                dedup_cache,
+                step_slice,
+                event_slice,
+                workers,
+                None,
+                &mut local_partition,
+            );
+
+            (status, time)
+        },
+        None => {
+            let (status, time, _steps, _lineages) = monolithic::simulate(
+                &mut simulation,
+                lineages,
+                dedup_cache,
+                step_slice,
+                event_slice,
+                None,
+                &mut local_partition,
+            );
+
+            (status, time)
+        },
+    };
+
+    (status, time, local_partition.reporter)
+}
+
+#[test]
+fn test_threaded_matches_monolithic() {
+    for seed in [42, 1337] {
+        let (monolithic_status, monolithic_time, monolithic_events) = simulate(None, seed);
+
+        assert!(matches!(monolithic_status, Status::Done));
+        assert!(monolithic_events.speciations > 0);
+
+        for workers in [1, 2, 3, 8] {
+            let (threaded_status, threaded_time, threaded_events) =
+                simulate(NonZeroUsize::new(workers), seed);
+
+            assert!(matches!(threaded_status, Status::Done));
+            assert_eq!(
+                threaded_time, monolithic_time,
+                "seed={seed} workers={workers}"
+            );
+            assert_eq!(
+                threaded_events.events, monolithic_events.events,
+                "seed={seed} workers={workers}"
+            );
+        }
+    }
+}
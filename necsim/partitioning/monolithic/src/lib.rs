@@ -6,7 +6,7 @@ extern crate contracts;
 use std::fmt;
 
 use anyhow::Context;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use necsim_core::{
     impl_report,
@@ -43,8 +43,36 @@ impl Serialize for MonolithicPartitioning {
 }
 
 impl<'de> Deserialize<'de> for MonolithicPartitioning {
-    fn deserialize<D: Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
-        Ok(Self::default())
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_unit(MonolithicPartitioningVisitor)
+    }
+}
+
+/// Accepts `()` in RON, `null` in JSON or YAML, or an empty table in TOML,
+/// which has no unit value.
+struct MonolithicPartitioningVisitor;
+
+impl<'de> de::Visitor<'de> for MonolithicPartitioningVisitor {
+    type Value = MonolithicPartitioning;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("unit or an empty table")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(MonolithicPartitioning::default())
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(MonolithicPartitioning::default())
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        if map.next_key::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_type(de::Unexpected::Map, &self));
+        }
+
+        Ok(MonolithicPartitioning::default())
     }
 }
 
@@ -1,11 +1,10 @@
 use std::fmt;
 
-use necsim_core::{event::SpeciationEvent, impl_finalise, impl_report, reporter::Reporter};
+use necsim_core::{impl_finalise, impl_report, reporter::Reporter};
 
 #[allow(clippy::module_name_repetitions)]
+#[derive(Default)]
 pub struct BiodiversityReporter {
-    last_event: Option<SpeciationEvent>,
-
     biodiversity: usize,
 }
 
@@ -13,7 +12,7 @@ impl fmt::Debug for BiodiversityReporter {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         fmt.debug_struct(stringify!(BiodiversityReporter))
             .field("biodiversity", &self.biodiversity)
-            .finish_non_exhaustive()
+            .finish()
     }
 }
 
@@ -24,19 +23,15 @@ impl serde::Serialize for BiodiversityReporter {
 }
 
 impl<'de> serde::Deserialize<'de> for BiodiversityReporter {
-    fn deserialize<D: serde::Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::unit::deserialize_unit(deserializer)?;
+
         Ok(Self::default())
     }
 }
 
 impl Reporter for BiodiversityReporter {
-    impl_report!(speciation(&mut self, speciation: Used) {
-        if Some(speciation) == self.last_event.as_ref() {
-            return;
-        }
-
-        self.last_event = Some(speciation.clone());
-
+    impl_report!(speciation(&mut self, _speciation: Used) {
         self.biodiversity += 1;
     });
 
@@ -53,13 +48,3 @@ impl Reporter for BiodiversityReporter {
         }
     });
 }
-
-impl Default for BiodiversityReporter {
-    #[debug_ensures(ret.biodiversity == 0, "biodiversity initialised to 0")]
-    fn default() -> Self {
-        Self {
-            last_event: None,
-            biodiversity: 0,
-        }
-    }
-}
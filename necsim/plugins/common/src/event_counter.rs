@@ -4,7 +4,7 @@ use necsim_core::{
     event::{DispersalEvent, SpeciationEvent},
     impl_finalise, impl_report,
     lineage::LineageInteraction,
-    reporter::Reporter,
+    reporter::{RecentEvents, Reporter},
 };
 use necsim_core_bond::NonNegativeF64;
 
@@ -12,8 +12,8 @@ use necsim_core_bond::NonNegativeF64;
 #[derive(Default)]
 pub struct EventCounterReporter {
     last_parent_prior_time: Option<NonNegativeF64>,
-    last_speciation_event: Option<SpeciationEvent>,
-    last_dispersal_event: Option<DispersalEvent>,
+    speciation_events: RecentEvents<SpeciationEvent>,
+    dispersal_events: RecentEvents<DispersalEvent>,
 
     raw_total: usize,
     speciation: usize,
@@ -49,7 +49,9 @@ impl serde::Serialize for EventCounterReporter {
 }
 
 impl<'de> serde::Deserialize<'de> for EventCounterReporter {
-    fn deserialize<D: serde::Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::unit::deserialize_unit(deserializer)?;
+
         Ok(Self::default())
     }
 }
@@ -58,7 +60,7 @@ impl Reporter for EventCounterReporter {
     impl_report!(speciation(&mut self, speciation: Used) {
         self.raw_total += 1;
 
-        if Some(speciation) == self.last_speciation_event.as_ref() {
+        if self.speciation_events.is_duplicate(speciation) {
             if Some(speciation.prior_time) != self.last_parent_prior_time {
                 self.late_coalescence += 1;
             }
@@ -66,7 +68,6 @@ impl Reporter for EventCounterReporter {
 
             return;
         }
-        self.last_speciation_event = Some(speciation.clone());
         self.last_parent_prior_time = Some(speciation.prior_time);
 
         self.speciation += 1;
@@ -75,7 +76,7 @@ impl Reporter for EventCounterReporter {
     impl_report!(dispersal(&mut self, dispersal: Used) {
         self.raw_total += 1;
 
-        if Some(dispersal) == self.last_dispersal_event.as_ref() {
+        if self.dispersal_events.is_duplicate(dispersal) {
             if Some(dispersal.prior_time) != self.last_parent_prior_time {
                 self.late_coalescence += 1;
             }
@@ -83,7 +84,6 @@ impl Reporter for EventCounterReporter {
 
             return;
         }
-        self.last_dispersal_event = Some(dispersal.clone());
         self.last_parent_prior_time = Some(dispersal.prior_time);
 
         let self_dispersal = dispersal.origin == dispersal.target;
@@ -111,7 +111,7 @@ impl Reporter for EventCounterReporter {
     impl_report!(progress(&mut self, _progress: Ignored) {});
 
     impl_finalise!((self) {
-        if self.last_speciation_event.is_none() && self.last_dispersal_event.is_none() {
+        if self.raw_total == 0 {
             return;
         }
 
@@ -37,7 +37,9 @@ impl serde::Serialize for ExecutionTimeReporter {
 }
 
 impl<'de> serde::Deserialize<'de> for ExecutionTimeReporter {
-    fn deserialize<D: serde::Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::unit::deserialize_unit(deserializer)?;
+
         Ok(Self::default())
     }
 }
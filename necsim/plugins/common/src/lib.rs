@@ -1,4 +1,5 @@
 #![deny(clippy::pedantic)]
+#![cfg_attr(feature = "builtin", feature(linkage))]
 
 #[macro_use]
 extern crate contracts;
@@ -12,8 +13,10 @@ pub mod execution_time;
 pub mod progress;
 pub mod verbose;
 
+mod unit;
+
 necsim_plugins_core::export_plugin!(
-    Biodiversity => biodiversity::BiodiversityReporter,
+    #[deduplicate] Biodiversity => biodiversity::BiodiversityReporter,
     Progress => progress::ProgressReporter,
     Execution => execution_time::ExecutionTimeReporter,
     Counter => event_counter::EventCounterReporter,
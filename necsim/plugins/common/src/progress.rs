@@ -12,6 +12,8 @@ use std::{
 
 use necsim_core::{impl_report, reporter::Reporter};
 
+use necsim_plugins_core::milestone::MILESTONE_TARGET;
+
 struct ProgressUpdater {
     thread: JoinHandle<()>,
     sender: Sender<()>,
@@ -50,7 +52,9 @@ impl serde::Serialize for ProgressReporter {
 }
 
 impl<'de> serde::Deserialize<'de> for ProgressReporter {
-    fn deserialize<D: serde::Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::unit::deserialize_unit(deserializer)?;
+
         Ok(Self::default())
     }
 }
@@ -72,10 +76,7 @@ impl Reporter for ProgressReporter {
         if last_remaining > 0 && *remaining == 0 {
             let total = self.last_total.load(Ordering::Acquire);
 
-            display_progress(total, self.last_remaining.load(Ordering::Acquire).min(total));
-
-            // Flush stdout to update the progress bar
-            std::mem::drop(io::stdout().flush());
+            report_progress(total, self.last_remaining.load(Ordering::Acquire).min(total));
         }
     });
 
@@ -90,6 +91,8 @@ impl Reporter for ProgressReporter {
         let (sender, receiver) = mpsc::channel();
 
         let thread = thread::spawn(move || {
+            let mut last_progress = (0, 0);
+
             loop {
                 thread::sleep(Duration::from_millis(100));
 
@@ -99,12 +102,13 @@ impl Reporter for ProgressReporter {
                 }
 
                 let total = total.load(Ordering::Acquire);
+                let progress = (total, remaining.load(Ordering::Acquire).min(total));
 
-                if total > 0 {
-                    display_progress(total, remaining.load(Ordering::Acquire).min(total));
+                // Only report the progress if it has changed
+                if total > 0 && progress != last_progress {
+                    report_progress(progress.0, progress.1);
 
-                    // Flush stdout to update the progress bar
-                    std::mem::drop(io::stdout().flush());
+                    last_progress = progress;
                 }
             }
         });
@@ -138,6 +142,30 @@ impl Default for ProgressReporter {
     }
 }
 
+/// Progress milestone, which is serialised as
+/// `{"milestone":"progress","remaining":...,"total":...}`
+#[derive(serde::Serialize)]
+#[serde(tag = "milestone", rename = "progress")]
+struct ProgressMilestone {
+    remaining: u64,
+    total: u64,
+}
+
+fn report_progress(total: u64, remaining: u64) {
+    // The host's logger opts into receiving the progress as a JSON milestone,
+    //  e.g. rustcoalescence with `--log-format json`
+    if log_enabled!(target: MILESTONE_TARGET, log::Level::Info) {
+        if let Ok(milestone) = serde_json::to_string(&ProgressMilestone { remaining, total }) {
+            info!(target: MILESTONE_TARGET, "{milestone}");
+        }
+    } else {
+        display_progress(total, remaining);
+
+        // Flush stdout to update the progress bar
+        std::mem::drop(io::stdout().flush());
+    }
+}
+
 fn display_progress(total: u64, remaining: u64) {
     const UPDATE_PRECISION: usize = 50;
 
@@ -162,3 +190,20 @@ fn display_progress(total: u64, remaining: u64) {
     }
     print!("] {total:<13}");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ProgressMilestone;
+
+    #[test]
+    fn progress_milestone_is_tagged() {
+        assert_eq!(
+            serde_json::to_string(&ProgressMilestone {
+                remaining: 3,
+                total: 5
+            })
+            .unwrap(),
+            r#"{"milestone":"progress","remaining":3,"total":5}"#
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt;
+
+use serde::{de, Deserializer};
+
+/// Deserialises a reporter without arguments from `()` in RON, `null` in JSON
+/// or YAML, or an empty table in TOML, which has no unit value.
+///
+/// # Errors
+///
+/// Returns an error if the reporter is given any arguments.
+pub(crate) fn deserialize_unit<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(), D::Error> {
+    deserializer.deserialize_unit(UnitVisitor)
+}
+
+struct UnitVisitor;
+
+impl<'de> de::Visitor<'de> for UnitVisitor {
+    type Value = ();
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("unit or an empty table")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(())
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(())
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        if map.next_key::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_type(de::Unexpected::Map, &self));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::{value, IntoDeserializer};
+
+    use super::deserialize_unit;
+
+    #[test]
+    fn unit_and_empty_maps_are_accepted() {
+        deserialize_unit::<value::UnitDeserializer<value::Error>>(().into_deserializer()).unwrap();
+
+        let empty: Vec<(String, u32)> = Vec::new();
+        deserialize_unit(value::MapDeserializer::<_, value::Error>::new(
+            empty.into_iter(),
+        ))
+        .unwrap();
+    }
+
+    #[test]
+    fn arguments_are_rejected() {
+        let arguments = vec![(String::from("interval"), 1_u32)];
+        assert!(
+            deserialize_unit(value::MapDeserializer::<_, value::Error>::new(
+                arguments.into_iter()
+            ))
+            .is_err()
+        );
+        assert!(deserialize_unit::<value::U32Deserializer<value::Error>>(
+            42_u32.into_deserializer()
+        )
+        .is_err());
+    }
+}
@@ -19,7 +19,9 @@ impl serde::Serialize for VerboseReporter {
 }
 
 impl<'de> serde::Deserialize<'de> for VerboseReporter {
-    fn deserialize<D: serde::Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::unit::deserialize_unit(deserializer)?;
+
         Ok(Self::default())
     }
 }
@@ -0,0 +1,100 @@
+/*
+ * Stable C ABI for necsim reporter plugins.
+ *
+ * A plugin library exports a single `NECSIM_FFI_REPORTER_PLUGIN_DECLARATION`
+ * symbol, which lists all reporters that it provides. Unlike Rust-native
+ * plugins, C-ABI plugins do not need to be rebuilt for every rustcoalescence
+ * release, only when `NECSIM_FFI_REPORTER_PLUGIN_ABI_VERSION` changes.
+ *
+ * A reporter is selected in the simulation configuration by its name, and
+ * receives its arguments as a single string, e.g.
+ *
+ *   Plugin(library: "libmy_plugin.so", reporters: [MyReporter("{\"interval\": 10}")])
+ *
+ * The argument string is passed through verbatim, so the reporter may parse
+ * it as JSON, RON, or any other format.
+ *
+ * The declarations must match `necsim_plugins_core::ffi`.
+ */
+
+#ifndef NECSIM_REPORTER_PLUGIN_H
+#define NECSIM_REPORTER_PLUGIN_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define NECSIM_FFI_REPORTER_PLUGIN_ABI_VERSION 1
+
+typedef struct necsim_indexed_location {
+    uint32_t x;
+    uint32_t y;
+    uint32_t index;
+} necsim_indexed_location;
+
+typedef struct necsim_speciation_event {
+    uint64_t global_lineage_reference;
+    double prior_time;
+    double event_time;
+    necsim_indexed_location origin;
+} necsim_speciation_event;
+
+typedef enum necsim_lineage_interaction {
+    NECSIM_LINEAGE_INTERACTION_NONE = 0,
+    NECSIM_LINEAGE_INTERACTION_MAYBE = 1,
+    NECSIM_LINEAGE_INTERACTION_COALESCENCE = 2,
+} necsim_lineage_interaction;
+
+typedef struct necsim_dispersal_event {
+    uint64_t global_lineage_reference;
+    double prior_time;
+    double event_time;
+    necsim_indexed_location origin;
+    necsim_indexed_location target;
+    uint32_t interaction; /* necsim_lineage_interaction */
+    /* lineage reference of the parent iff interaction is COALESCENCE */
+    uint64_t parent;
+} necsim_dispersal_event;
+
+/*
+ * Functions that can fail write a NUL-terminated error message into the
+ * provided `error` buffer of `error_capacity` bytes.
+ */
+typedef struct necsim_reporter_declaration {
+    /* NUL-terminated UTF-8 name of the reporter */
+    const char *name;
+
+    /* creates a new reporter, returns its opaque state or NULL on error */
+    void *(*init)(const char *arguments, size_t arguments_len, char *error,
+                  size_t error_capacity);
+
+    /* optional, called right before the simulation starts, false on error */
+    bool (*initialise)(void *state, char *error, size_t error_capacity);
+
+    /* optional, NULL if the event type is not reported */
+    void (*report_speciation)(void *state, const necsim_speciation_event *event);
+    void (*report_dispersal)(void *state, const necsim_dispersal_event *event);
+    void (*report_progress)(void *state, uint64_t remaining);
+
+    /* finalises the reporter after a successful simulation, frees the state */
+    void (*finalise)(void *state);
+    /* frees the state of a reporter that is discarded without finalisation */
+    void (*drop)(void *state);
+} necsim_reporter_declaration;
+
+typedef struct necsim_reporter_plugin_declaration {
+    /* must be NECSIM_FFI_REPORTER_PLUGIN_ABI_VERSION */
+    uint32_t abi_version;
+    const necsim_reporter_declaration *reporters;
+    size_t num_reporters;
+} necsim_reporter_plugin_declaration;
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* NECSIM_REPORTER_PLUGIN_H */
@@ -0,0 +1,251 @@
+use std::fmt;
+
+use serde::{de, Deserialize, Deserializer};
+
+use crate::export::ReporterPluginFilter;
+
+/// Description of a reporter that is exported by a plugin library
+#[derive(Debug, Clone)]
+#[allow(clippy::module_name_repetitions)]
+pub struct ReporterPluginDescription {
+    pub name: &'static str,
+    pub arguments: ArgumentsShape,
+    pub filter: ReporterPluginFilter,
+}
+
+impl fmt::Display for ReporterPluginDescription {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.name)?;
+
+        match &self.arguments {
+            ArgumentsShape::Unit | ArgumentsShape::UnitStruct { .. } => fmt.write_str("()"),
+            ArgumentsShape::Struct { fields, .. } => write_fields(fmt, fields),
+            ArgumentsShape::TupleStruct { len, .. } | ArgumentsShape::Tuple { len } => {
+                write_elements(fmt, *len)
+            },
+            arguments => write!(fmt, "({arguments})"),
+        }
+    }
+}
+
+/// Top-level structure of the arguments that a reporter is deserialised from
+#[derive(Debug, Clone)]
+pub enum ArgumentsShape {
+    Any,
+    Primitive(&'static str),
+    Optional(Box<ArgumentsShape>),
+    Unit,
+    UnitStruct {
+        name: &'static str,
+    },
+    NewtypeStruct {
+        name: &'static str,
+    },
+    Seq,
+    Tuple {
+        len: usize,
+    },
+    TupleStruct {
+        name: &'static str,
+        len: usize,
+    },
+    Map,
+    Struct {
+        name: &'static str,
+        fields: &'static [&'static str],
+    },
+    Enum {
+        name: &'static str,
+        variants: &'static [&'static str],
+    },
+}
+
+impl fmt::Display for ArgumentsShape {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Any => fmt.write_str("_"),
+            Self::Primitive(primitive) => fmt.write_str(primitive),
+            Self::Optional(inner) => write!(fmt, "Option<{inner}>"),
+            Self::Unit => fmt.write_str("()"),
+            Self::UnitStruct { name } => fmt.write_str(name),
+            Self::NewtypeStruct { name } => write!(fmt, "{name}(_)"),
+            Self::Seq => fmt.write_str("[_]"),
+            Self::Tuple { len } => write_elements(fmt, *len),
+            Self::TupleStruct { name, len } => {
+                fmt.write_str(name)?;
+                write_elements(fmt, *len)
+            },
+            Self::Map => fmt.write_str("{_: _}"),
+            Self::Struct { name, fields } => {
+                fmt.write_str(name)?;
+                write_fields(fmt, fields)
+            },
+            Self::Enum { variants, .. } => fmt.write_str(&variants.join(" | ")),
+        }
+    }
+}
+
+impl ArgumentsShape {
+    /// Traces the top-level structure that `T` requests from a deserializer,
+    /// without deserialising any value
+    #[must_use]
+    pub fn of<'de, T: Deserialize<'de>>() -> Self {
+        match T::deserialize(ShapeTracer) {
+            // `T` can be deserialised without consuming any arguments
+            Ok(_) => Self::Unit,
+            Err(ShapeTraced(shape)) => shape,
+        }
+    }
+}
+
+fn write_fields(fmt: &mut fmt::Formatter, fields: &[&str]) -> fmt::Result {
+    fmt.write_str("(")?;
+
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            fmt.write_str(", ")?;
+        }
+
+        write!(fmt, "{field}: _")?;
+    }
+
+    fmt.write_str(")")
+}
+
+fn write_elements(fmt: &mut fmt::Formatter, len: usize) -> fmt::Result {
+    fmt.write_str("(")?;
+
+    for i in 0..len {
+        if i > 0 {
+            fmt.write_str(", ")?;
+        }
+
+        fmt.write_str("_")?;
+    }
+
+    fmt.write_str(")")
+}
+
+/// Deserializer that aborts on the first request with the requested shape
+struct ShapeTracer;
+
+#[derive(Debug)]
+struct ShapeTraced(ArgumentsShape);
+
+impl fmt::Display for ShapeTraced {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "traced arguments shape {}", self.0)
+    }
+}
+
+impl std::error::Error for ShapeTraced {}
+
+impl de::Error for ShapeTraced {
+    fn custom<T: fmt::Display>(_msg: T) -> Self {
+        Self(ArgumentsShape::Any)
+    }
+}
+
+macro_rules! trace_primitive {
+    ($($method:ident => $primitive:literal),*) => {
+        $(fn $method<V: de::Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+            Err(ShapeTraced(ArgumentsShape::Primitive($primitive)))
+        })*
+    };
+}
+
+impl<'de> Deserializer<'de> for ShapeTracer {
+    type Error = ShapeTraced;
+
+    trace_primitive! {
+        deserialize_bool => "bool",
+        deserialize_i8 => "i8", deserialize_i16 => "i16",
+        deserialize_i32 => "i32", deserialize_i64 => "i64", deserialize_i128 => "i128",
+        deserialize_u8 => "u8", deserialize_u16 => "u16",
+        deserialize_u32 => "u32", deserialize_u64 => "u64", deserialize_u128 => "u128",
+        deserialize_f32 => "f32", deserialize_f64 => "f64",
+        deserialize_char => "char", deserialize_str => "str", deserialize_string => "str",
+        deserialize_bytes => "bytes", deserialize_byte_buf => "bytes",
+        deserialize_identifier => "identifier"
+    }
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(ShapeTraced(ArgumentsShape::Any))
+    }
+
+    fn deserialize_ignored_any<V: de::Visitor<'de>>(
+        self,
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(ShapeTraced(ArgumentsShape::Any))
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor
+            .visit_some(self)
+            .map_err(|ShapeTraced(inner)| ShapeTraced(ArgumentsShape::Optional(Box::new(inner))))
+    }
+
+    fn deserialize_unit<V: de::Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(ShapeTraced(ArgumentsShape::Unit))
+    }
+
+    fn deserialize_unit_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(ShapeTraced(ArgumentsShape::UnitStruct { name }))
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(ShapeTraced(ArgumentsShape::NewtypeStruct { name }))
+    }
+
+    fn deserialize_seq<V: de::Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(ShapeTraced(ArgumentsShape::Seq))
+    }
+
+    fn deserialize_tuple<V: de::Visitor<'de>>(
+        self,
+        len: usize,
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(ShapeTraced(ArgumentsShape::Tuple { len }))
+    }
+
+    fn deserialize_tuple_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        len: usize,
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(ShapeTraced(ArgumentsShape::TupleStruct { name, len }))
+    }
+
+    fn deserialize_map<V: de::Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(ShapeTraced(ArgumentsShape::Map))
+    }
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(ShapeTraced(ArgumentsShape::Struct { name, fields }))
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(ShapeTraced(ArgumentsShape::Enum { name, variants }))
+    }
+}
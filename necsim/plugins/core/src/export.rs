@@ -1,14 +1,28 @@
-use std::mem::ManuallyDrop;
+use std::{fmt, mem::ManuallyDrop};
 
 use necsim_core::reporter::{
     boolean::{Boolean, True},
-    Reporter,
+    DeduplicatingReporter, Reporter,
 };
 
+use crate::describe::ReporterPluginDescription;
+
 pub trait SerializeableReporter: Reporter + erased_serde::Serialize {
     fn reporter_name(&self) -> &'static str;
 }
 
+impl<R: SerializeableReporter + serde::Serialize> SerializeableReporter
+    for DeduplicatingReporter<R>
+{
+    fn reporter_name(&self) -> &'static str {
+        self.inner().reporter_name()
+    }
+}
+
+// The version fields must remain first, so that they can be checked before
+//  the remaining fields of a plugin's declaration are read. New fields must be
+//  appended at the end, and require a new plugin system version.
+#[repr(C)]
 pub struct ReporterPluginDeclaration {
     pub rustc_version: &'static str,
     pub core_version: &'static str,
@@ -28,9 +42,12 @@ pub struct ReporterPluginDeclaration {
 
     #[allow(improper_ctypes_definitions)]
     pub drop: unsafe extern "C" fn(ManuallyDrop<UnsafeReporterPlugin>),
+
+    #[allow(improper_ctypes_definitions)]
+    pub describe: unsafe extern "C" fn() -> Vec<ReporterPluginDescription>,
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug)]
 #[allow(dead_code)]
 pub struct ReporterPluginFilter {
     pub(crate) report_speciation: bool,
@@ -47,6 +64,40 @@ impl ReporterPluginFilter {
             report_progress: R::ReportProgress::VALUE,
         }
     }
+
+    #[must_use]
+    pub fn report_speciation(&self) -> bool {
+        self.report_speciation
+    }
+
+    #[must_use]
+    pub fn report_dispersal(&self) -> bool {
+        self.report_dispersal
+    }
+
+    #[must_use]
+    pub fn report_progress(&self) -> bool {
+        self.report_progress
+    }
+}
+
+impl fmt::Display for ReporterPluginFilter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let events = [
+            (self.report_speciation, "speciation"),
+            (self.report_dispersal, "dispersal"),
+            (self.report_progress, "progress"),
+        ]
+        .into_iter()
+        .filter_map(|(report, event)| report.then_some(event))
+        .collect::<Vec<_>>();
+
+        if events.is_empty() {
+            fmt.write_str("no events")
+        } else {
+            fmt.write_str(&events.join(", "))
+        }
+    }
 }
 
 pub type DynReporterPlugin = dyn SerializeableReporter<
@@ -78,10 +129,15 @@ impl<R: SerializeableReporter> From<R> for UnsafeReporterPlugin {
     }
 }
 
+/// Declares the reporters that are exported by this plugin.
+///
+/// Reporters which are marked with `#[deduplicate]` are wrapped in a
+/// [`DeduplicatingReporter`], such that they only observe the first of
+/// several duplicate events.
 #[macro_export]
 #[allow(clippy::module_name_repetitions)]
 macro_rules! export_plugin {
-    ($($name:ident => $plugin:ty),+$(,)?) => {
+    ($($(#[$wrapper:ident])? $name:ident => $plugin:ty),+$(,)?) => {
         #[doc(hidden)]
         extern "C" fn __necsim_reporter_plugin_init(
             log: &'static dyn $crate::log::Log,
@@ -103,7 +159,7 @@ macro_rules! export_plugin {
             #[derive($crate::serde::Deserialize)]
             #[serde(crate = "::necsim_plugins_core::serde")]
             enum Reporters {
-                $($name($plugin)),*
+                $($name($crate::__reporter_plugin_type!($($wrapper)? $plugin))),*
             }
 
             $crate::erased_serde::deserialize::<Reporters>(deserializer).map(|reporter| {
@@ -113,6 +169,20 @@ macro_rules! export_plugin {
             }).map(::std::mem::ManuallyDrop::new)
         }
 
+        #[doc(hidden)]
+        extern "C" fn __necsim_reporter_plugin_describe(
+        ) -> Vec<$crate::describe::ReporterPluginDescription> {
+            vec![$($crate::describe::ReporterPluginDescription {
+                name: stringify!($name),
+                arguments: $crate::describe::ArgumentsShape::of::<
+                    $crate::__reporter_plugin_type!($($wrapper)? $plugin)
+                >(),
+                filter: $crate::export::ReporterPluginFilter::from_reporter::<
+                    $crate::__reporter_plugin_type!($($wrapper)? $plugin)
+                >(),
+            }),*]
+        }
+
         $(impl $crate::export::SerializeableReporter for $plugin {
             fn reporter_name(&self) -> &'static str {
                 stringify!($name)
@@ -131,8 +201,13 @@ macro_rules! export_plugin {
             ::std::mem::drop(::std::mem::ManuallyDrop::into_inner(plugin))
         }
 
+        // The declaration is always exported, even if the plugin is also built
+        //  as a built-in plugin, e.g. when features are unified across a
+        //  workspace. Several statically linked plugins would then define the
+        //  same symbol, so it is weak to let them be linked together.
         #[doc(hidden)]
         #[no_mangle]
+        #[cfg_attr(feature = "builtin", linkage = "weak")]
         pub static NECSIM_REPORTER_PLUGIN_DECLARATION: $crate::export::ReporterPluginDeclaration =
             $crate::export::ReporterPluginDeclaration {
                 rustc_version: $crate::RUSTC_VERSION,
@@ -142,7 +217,34 @@ macro_rules! export_plugin {
                 deserialise: __necsim_reporter_plugin_deserialise,
                 library_path: __necsim_reporter_plugin_library_path,
                 drop: __necsim_reporter_plugin_drop,
+                describe: __necsim_reporter_plugin_describe,
             };
+
+        /// Declaration of the reporters in this plugin, which can be linked
+        /// into the host as built-in reporters
+        #[cfg(feature = "builtin")]
+        pub static BUILTIN_REPORTER_PLUGIN_DECLARATION: $crate::export::ReporterPluginDeclaration =
+            $crate::export::ReporterPluginDeclaration {
+                rustc_version: $crate::RUSTC_VERSION,
+                core_version: $crate::CORE_VERSION,
+
+                init: __necsim_reporter_plugin_init,
+                deserialise: __necsim_reporter_plugin_deserialise,
+                library_path: __necsim_reporter_plugin_library_path,
+                drop: __necsim_reporter_plugin_drop,
+                describe: __necsim_reporter_plugin_describe,
+            };
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __reporter_plugin_type {
+    (deduplicate $plugin:ty) => {
+        $crate::necsim_core::reporter::DeduplicatingReporter<$plugin>
+    };
+    ($plugin:ty) => {
+        $plugin
     };
 }
 
@@ -0,0 +1,194 @@
+//! Stable C ABI for reporter plugins.
+//!
+//! Rust-native plugins, which are declared with [`export_plugin!`], exchange
+//! Rust trait objects with the host and must therefore be compiled with the
+//! exact same `rustc` and plugin system versions. Plugins that are written in
+//! C or C++, or that are built with a different toolchain, can instead export
+//! a [`FfiReporterPluginDeclaration`] under the
+//! `NECSIM_FFI_REPORTER_PLUGIN_DECLARATION` symbol. Only the
+//! [`NECSIM_FFI_REPORTER_PLUGIN_ABI_VERSION`] has to match the host.
+//!
+//! The matching C declarations can be found in the
+//! `include/necsim_reporter_plugin.h` header of this crate.
+//!
+//! [`export_plugin!`]: crate::export_plugin
+
+#![allow(clippy::module_name_repetitions)]
+
+use std::{
+    ffi::{c_char, c_void},
+    fmt,
+};
+
+use necsim_core::{
+    event::{DispersalEvent, SpeciationEvent},
+    landscape::IndexedLocation,
+    lineage::{GlobalLineageReference, LineageInteraction},
+};
+
+/// Version of the C reporter plugin ABI, which is incremented on every
+/// incompatible change to the declarations in this module
+pub const NECSIM_FFI_REPORTER_PLUGIN_ABI_VERSION: u32 = 1;
+
+/// Symbol under which a C-ABI plugin library exports its declaration
+pub const NECSIM_FFI_REPORTER_PLUGIN_DECLARATION_SYMBOL: &[u8] =
+    b"NECSIM_FFI_REPORTER_PLUGIN_DECLARATION\0";
+
+/// Declaration of all reporters that are exported by a C-ABI plugin library
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FfiReporterPluginDeclaration {
+    /// Must be [`NECSIM_FFI_REPORTER_PLUGIN_ABI_VERSION`]
+    pub abi_version: u32,
+    /// Array of `num_reporters` reporter declarations
+    pub reporters: *const FfiReporterDeclaration,
+    pub num_reporters: usize,
+}
+
+// Safety: the declaration is immutable and only points to static data
+unsafe impl Sync for FfiReporterPluginDeclaration {}
+
+/// Declaration of a single reporter in a C-ABI plugin library.
+///
+/// The reporter is selected by its `name` in the simulation configuration,
+/// e.g. `Name("arguments")`. The argument string is passed to `init`
+/// verbatim, so the reporter is free to parse it as JSON, RON, or any other
+/// format.
+///
+/// Functions that can fail write a NUL-terminated error message into the
+/// provided `error` buffer of `error_capacity` bytes.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FfiReporterDeclaration {
+    /// NUL-terminated UTF-8 name of the reporter
+    pub name: *const c_char,
+
+    /// Creates a new reporter from its NUL-terminated argument string of
+    /// `arguments_len` bytes. Returns the reporter's opaque state, or NULL
+    /// if the arguments are invalid.
+    pub init: unsafe extern "C" fn(
+        arguments: *const c_char,
+        arguments_len: usize,
+        error: *mut c_char,
+        error_capacity: usize,
+    ) -> *mut c_void,
+
+    /// Optionally commits to initialisation steps with side effects right
+    /// before the simulation starts. Returns `false` if initialisation
+    /// failed.
+    pub initialise: Option<
+        unsafe extern "C" fn(state: *mut c_void, error: *mut c_char, error_capacity: usize) -> bool,
+    >,
+
+    /// Reports a speciation event, or NULL if speciation is not reported
+    pub report_speciation:
+        Option<unsafe extern "C" fn(state: *mut c_void, event: *const FfiSpeciationEvent)>,
+    /// Reports a dispersal event, or NULL if dispersal is not reported
+    pub report_dispersal:
+        Option<unsafe extern "C" fn(state: *mut c_void, event: *const FfiDispersalEvent)>,
+    /// Reports the simulation progress, or NULL if progress is not reported
+    pub report_progress: Option<unsafe extern "C" fn(state: *mut c_void, remaining: u64)>,
+
+    /// Finalises a reporter after a successful simulation and frees its state
+    pub finalise: unsafe extern "C" fn(state: *mut c_void),
+    /// Frees the state of a reporter that is discarded without finalisation
+    pub drop: unsafe extern "C" fn(state: *mut c_void),
+}
+
+// Safety: the declaration is immutable and only points to static data
+unsafe impl Sync for FfiReporterDeclaration {}
+
+impl fmt::Debug for FfiReporterDeclaration {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(FfiReporterDeclaration))
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FfiIndexedLocation {
+    pub x: u32,
+    pub y: u32,
+    pub index: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FfiSpeciationEvent {
+    pub global_lineage_reference: u64,
+    pub prior_time: f64,
+    pub event_time: f64,
+    pub origin: FfiIndexedLocation,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FfiDispersalEvent {
+    pub global_lineage_reference: u64,
+    pub prior_time: f64,
+    pub event_time: f64,
+    pub origin: FfiIndexedLocation,
+    pub target: FfiIndexedLocation,
+    pub interaction: FfiLineageInteraction,
+    /// Lineage reference of the parent iff `interaction` is `Coalescence`
+    pub parent: u64,
+}
+
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FfiLineageInteraction {
+    None = 0,
+    Maybe = 1,
+    Coalescence = 2,
+}
+
+impl From<&IndexedLocation> for FfiIndexedLocation {
+    fn from(location: &IndexedLocation) -> Self {
+        Self {
+            x: location.location().x(),
+            y: location.location().y(),
+            index: location.index(),
+        }
+    }
+}
+
+impl From<&SpeciationEvent> for FfiSpeciationEvent {
+    fn from(event: &SpeciationEvent) -> Self {
+        Self {
+            global_lineage_reference: lineage_reference_to_u64(&event.global_lineage_reference),
+            prior_time: event.prior_time.get(),
+            event_time: event.event_time.get(),
+            origin: FfiIndexedLocation::from(&event.origin),
+        }
+    }
+}
+
+impl From<&DispersalEvent> for FfiDispersalEvent {
+    fn from(event: &DispersalEvent) -> Self {
+        let (interaction, parent) = match &event.interaction {
+            LineageInteraction::None => (FfiLineageInteraction::None, 0),
+            LineageInteraction::Maybe => (FfiLineageInteraction::Maybe, 0),
+            LineageInteraction::Coalescence(parent) => (
+                FfiLineageInteraction::Coalescence,
+                lineage_reference_to_u64(parent),
+            ),
+        };
+
+        Self {
+            global_lineage_reference: lineage_reference_to_u64(&event.global_lineage_reference),
+            prior_time: event.prior_time.get(),
+            event_time: event.event_time.get(),
+            origin: FfiIndexedLocation::from(&event.origin),
+            target: FfiIndexedLocation::from(&event.target),
+            interaction,
+            parent,
+        }
+    }
+}
+
+fn lineage_reference_to_u64(reference: &GlobalLineageReference) -> u64 {
+    // Safety: the raw lineage reference is only used as an opaque identifier
+    unsafe { reference.clone().into_inner() }
+}
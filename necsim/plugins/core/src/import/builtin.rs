@@ -0,0 +1,250 @@
+use std::{fmt, marker::PhantomData, mem::ManuallyDrop, rc::Rc};
+
+use serde::{
+    de::{
+        self, DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer, SeqAccess,
+        VariantAccess, Visitor,
+    },
+    forward_to_deserialize_any, Deserialize,
+};
+
+use crate::{
+    describe::ReporterPluginDescription,
+    export::ReporterPluginDeclaration,
+    import::{
+        serde::{PluginLibrary, ReporterName, ReporterPluginLibraryVisitor},
+        AnyReporterPluginVec, ReporterPlugin,
+    },
+};
+
+/// Registry of the reporter plugins which are statically linked into the
+/// host, such that they can be used without loading a dynamic library.
+///
+/// The registry deserialises a list of reporter plugins, each of which is
+/// either loaded from a dynamic library, i.e.
+/// `Plugin(library: "path", reporters: [...])`, or one of the built-in
+/// reporters, i.e. `Builtin(Reporter(...), ...)`.
+#[derive(Clone, Copy)]
+#[allow(clippy::module_name_repetitions)]
+pub struct BuiltinReporterPlugins {
+    declarations: &'static [&'static ReporterPluginDeclaration],
+}
+
+impl BuiltinReporterPlugins {
+    #[must_use]
+    pub const fn new(declarations: &'static [&'static ReporterPluginDeclaration]) -> Self {
+        Self { declarations }
+    }
+
+    #[must_use]
+    pub fn describe(&self) -> Vec<ReporterPluginDescription> {
+        self.declarations
+            .iter()
+            .flat_map(|declaration| unsafe { (declaration.describe)() })
+            .collect()
+    }
+
+    fn find(&self, name: &str) -> Option<&'static ReporterPluginDeclaration> {
+        self.declarations.iter().copied().find(|declaration| {
+            unsafe { (declaration.describe)() }
+                .iter()
+                .any(|reporter| reporter.name == name)
+        })
+    }
+}
+
+impl fmt::Debug for BuiltinReporterPlugins {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_list()
+            .entries(self.describe().iter().map(|reporter| reporter.name))
+            .finish()
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for BuiltinReporterPlugins {
+    type Value = AnyReporterPluginVec;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        struct ReporterPluginSourcesVisitor(BuiltinReporterPlugins);
+
+        impl<'de> Visitor<'de> for ReporterPluginSourcesVisitor {
+            type Value = AnyReporterPluginVec;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "an array of reporter plugins")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut reporters = Vec::new();
+
+                while let Some(source) = seq.next_element_seed(ReporterPluginSource(self.0))? {
+                    reporters.extend(source);
+                }
+
+                Ok(reporters.into_iter().collect())
+            }
+        }
+
+        deserializer.deserialize_seq(ReporterPluginSourcesVisitor(self))
+    }
+}
+
+// Helper enum to deserialise the source of reporter plugins
+#[derive(Deserialize)]
+enum ReporterPluginSourceVariant {
+    Plugin,
+    Builtin,
+}
+
+// Helper struct to deserialise either a dynamic plugin library or a list of
+//  built-in reporter plugins
+struct ReporterPluginSource(BuiltinReporterPlugins);
+
+impl<'de> DeserializeSeed<'de> for ReporterPluginSource {
+    type Value = Vec<ReporterPlugin>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_enum("ReporterPluginSource", &["Plugin", "Builtin"], self)
+    }
+}
+
+impl<'de> Visitor<'de> for ReporterPluginSource {
+    type Value = Vec<ReporterPlugin>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a Plugin(...) library or Builtin(...) reporters")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        match data.variant()? {
+            (ReporterPluginSourceVariant::Plugin, variant) => Ok(variant
+                .struct_variant(
+                    &["library", "name", "reporters"],
+                    ReporterPluginLibraryVisitor,
+                )?
+                .into_iter()
+                .collect()),
+            (ReporterPluginSourceVariant::Builtin, variant) => {
+                variant.tuple_variant(0, BuiltinReporterPluginsVisitor(self.0))
+            },
+        }
+    }
+}
+
+// Helper struct to deserialise a list of built-in ReporterPlugins
+struct BuiltinReporterPluginsVisitor(BuiltinReporterPlugins);
+
+impl<'de> Visitor<'de> for BuiltinReporterPluginsVisitor {
+    type Value = Vec<ReporterPlugin>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a list of built-in reporters")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut reporters = Vec::new();
+
+        while let Some(reporter) = seq.next_element_seed(BuiltinReporterPlugin(self.0))? {
+            reporters.push(reporter);
+        }
+
+        Ok(reporters)
+    }
+}
+
+// Helper struct to deserialise a single built-in ReporterPlugin
+struct BuiltinReporterPlugin(BuiltinReporterPlugins);
+
+impl<'de> DeserializeSeed<'de> for BuiltinReporterPlugin {
+    type Value = ReporterPlugin;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_enum("Reporters", &[], self)
+    }
+}
+
+impl<'de> Visitor<'de> for BuiltinReporterPlugin {
+    type Value = ReporterPlugin;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a built-in reporter")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (ReporterName(name), variant) = data.variant()?;
+
+        let Some(declaration) = self.0.find(&name) else {
+            let names = self
+                .0
+                .describe()
+                .iter()
+                .map(|reporter| reporter.name)
+                .collect::<Vec<_>>();
+
+            return Err(if names.is_empty() {
+                de::Error::custom(format_args!(
+                    "unknown built-in reporter `{name}`, no built-in reporters are available"
+                ))
+            } else {
+                de::Error::custom(format_args!(
+                    "unknown built-in reporter `{name}`, expected one of `{}`",
+                    names.join("`, `")
+                ))
+            });
+        };
+
+        // Replay the already consumed reporter name to the plugin
+        let deserializer = NamedVariantDeserializer {
+            name: &name,
+            variant,
+            marker: PhantomData::<&'de ()>,
+        };
+
+        match unsafe {
+            (declaration.deserialise)(&mut <dyn erased_serde::Deserializer>::erase(deserializer))
+        } {
+            Ok(reporter) => Ok(ReporterPlugin {
+                library: Rc::new(PluginLibrary::builtin(declaration)),
+                filter: reporter.filter,
+                reporter: ManuallyDrop::new(ManuallyDrop::into_inner(reporter).reporter),
+                finalised: false,
+            }),
+            Err(err) => Err(de::Error::custom(err)),
+        }
+    }
+}
+
+// Helper deserializer for an enum whose variant name has already been read
+struct NamedVariantDeserializer<'a, 'de, A: VariantAccess<'de>> {
+    name: &'a str,
+    variant: A,
+    marker: PhantomData<&'de ()>,
+}
+
+impl<'a, 'de, A: VariantAccess<'de>> Deserializer<'de> for NamedVariantDeserializer<'a, 'de, A> {
+    type Error = A::Error;
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(self)
+    }
+}
+
+impl<'a, 'de, A: VariantAccess<'de>> EnumAccess<'de> for NamedVariantDeserializer<'a, 'de, A> {
+    type Error = A::Error;
+    type Variant = A;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let name = seed.deserialize(self.name.into_deserializer())?;
+
+        Ok((name, self.variant))
+    }
+}
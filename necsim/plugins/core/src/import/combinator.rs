@@ -6,7 +6,11 @@ use std::{
     rc::Rc,
 };
 
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{
+    de::DeserializeSeed,
+    ser::{SerializeStructVariant, SerializeTupleVariant},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 
 use necsim_core::{
     impl_finalise, impl_report,
@@ -16,9 +20,10 @@ use necsim_core::{
     },
 };
 
-use crate::{export::Reporters, import::ReporterPlugin};
-
-use super::ReporterPluginLibrary;
+use crate::{
+    export::Reporters,
+    import::{serde::PluginDeclaration, BuiltinReporterPlugins, ReporterPlugin},
+};
 
 pub struct ReporterPluginVec<
     ReportSpeciation: Boolean,
@@ -264,53 +269,123 @@ impl AnyReporterPluginVec {
             }),
         }
     }
+
+    /// Returns the paths of the dynamic libraries from which the reporter
+    /// plugins were loaded, without the statically linked built-in plugins
+    #[must_use]
+    pub fn libraries(&self) -> Vec<&Path> {
+        let plugins = match_any_reporter_plugin_vec! { self => |vec| { &*vec.plugins } };
+
+        let mut libraries = Vec::new();
+
+        for reporter_plugin in plugins {
+            if let PluginDeclaration::Native(_) | PluginDeclaration::Ffi(_) =
+                reporter_plugin.library.declaration
+            {
+                let library = &*reporter_plugin.library.path;
+
+                if !libraries.contains(&library) {
+                    libraries.push(library);
+                }
+            }
+        }
+
+        libraries
+    }
 }
 
 impl<'de> Deserialize<'de> for AnyReporterPluginVec {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let plugins = Vec::<ReporterPluginLibrary>::deserialize(deserializer)?;
-
-        Ok(plugins.into_iter().flatten().collect())
+        BuiltinReporterPlugins::new(&[]).deserialize(deserializer)
     }
 }
 
 impl Serialize for AnyReporterPluginVec {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        #[derive(Serialize)]
-        struct Plugin<'r> {
-            library: &'r Path,
-            reporters: Vec<Reporters<'r>>,
+        enum ReporterPluginSource<'r> {
+            Plugin {
+                library: &'r Path,
+                reporters: Vec<Reporters<'r>>,
+            },
+            Builtin(Vec<Reporters<'r>>),
+        }
+
+        impl<'r> ReporterPluginSource<'r> {
+            fn new(library: Option<&'r Path>, reporters: Vec<Reporters<'r>>) -> Self {
+                match library {
+                    Some(library) => Self::Plugin { library, reporters },
+                    None => Self::Builtin(reporters),
+                }
+            }
+        }
+
+        impl<'r> Serialize for ReporterPluginSource<'r> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match self {
+                    Self::Plugin { library, reporters } => {
+                        let mut plugin = serializer.serialize_struct_variant(
+                            "ReporterPluginSource",
+                            0,
+                            "Plugin",
+                            2,
+                        )?;
+                        plugin.serialize_field("library", library)?;
+                        plugin.serialize_field("reporters", reporters)?;
+                        plugin.end()
+                    },
+                    Self::Builtin(reporters) => {
+                        let mut builtin = serializer.serialize_tuple_variant(
+                            "ReporterPluginSource",
+                            1,
+                            "Builtin",
+                            reporters.len(),
+                        )?;
+                        for reporter in reporters {
+                            builtin.serialize_field(reporter)?;
+                        }
+                        builtin.end()
+                    },
+                }
+            }
         }
 
         let plugins = match_any_reporter_plugin_vec! { self => |vec| { &*vec.plugins } };
 
+        // Built-in reporters are grouped together, i.e. have no library path
         let mut previous_library = None;
         let mut previous_reporters = Vec::new();
 
-        let mut plugin_libraries = Vec::new();
+        let mut plugin_sources = Vec::new();
 
         for reporter_plugin in plugins {
+            let library = match reporter_plugin.library.declaration {
+                PluginDeclaration::Builtin(_) => None,
+                PluginDeclaration::Native(_) | PluginDeclaration::Ffi(_) => {
+                    Some(&*reporter_plugin.library.path)
+                },
+            };
+
             if let Some(previous_library) = previous_library {
-                if previous_library != reporter_plugin.library.path {
-                    plugin_libraries.push(Plugin {
-                        library: previous_library,
-                        reporters: std::mem::take(&mut previous_reporters),
-                    });
+                if previous_library != library {
+                    plugin_sources.push(ReporterPluginSource::new(
+                        previous_library,
+                        std::mem::take(&mut previous_reporters),
+                    ));
                 }
             }
 
-            previous_library = Some(&reporter_plugin.library.path);
+            previous_library = Some(library);
 
             previous_reporters.push(Reporters::DynReporter(&**reporter_plugin.reporter));
         }
 
         if let Some(previous_library) = previous_library {
-            plugin_libraries.push(Plugin {
-                library: previous_library,
-                reporters: previous_reporters,
-            });
+            plugin_sources.push(ReporterPluginSource::new(
+                previous_library,
+                previous_reporters,
+            ));
         }
 
-        plugin_libraries.serialize(serializer)
+        plugin_sources.serialize(serializer)
     }
 }
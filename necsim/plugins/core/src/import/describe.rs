@@ -0,0 +1,59 @@
+use std::{
+    convert::TryFrom,
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    describe::{ArgumentsShape, ReporterPluginDescription},
+    import::{ffi::FfiReporter, serde::PluginLibrary},
+};
+
+/// Description of all reporters that are exported by a plugin library, which
+/// is kept loaded for as long as the description is alive
+#[allow(clippy::module_name_repetitions)]
+pub struct ReporterPluginLibraryDescription {
+    // The reporter descriptions must be dropped before their library
+    reporters: Vec<ReporterPluginDescription>,
+    library: PluginLibrary,
+}
+
+impl ReporterPluginLibraryDescription {
+    /// # Errors
+    ///
+    /// Returns an error if the plugin library cannot be loaded, is
+    /// incompatible with this version of the plugin system, or declares a
+    /// C-ABI reporter without a valid name.
+    pub fn try_load(library_path: PathBuf) -> io::Result<Self> {
+        let library = PluginLibrary::try_from(library_path)?;
+
+        let reporters = match library.declaration.native() {
+            Some(declaration) => unsafe { (declaration.describe)() },
+            None => library
+                .declaration
+                .ffi_reporters()
+                .iter()
+                .map(|declaration| {
+                    Ok(ReporterPluginDescription {
+                        name: unsafe { FfiReporter::name_of(declaration) }
+                            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?,
+                        arguments: ArgumentsShape::of::<String>(),
+                        filter: FfiReporter::filter_of(declaration),
+                    })
+                })
+                .collect::<io::Result<_>>()?,
+        };
+
+        Ok(Self { reporters, library })
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.library.path
+    }
+
+    #[must_use]
+    pub fn reporters(&self) -> &[ReporterPluginDescription] {
+        &self.reporters
+    }
+}
@@ -0,0 +1,382 @@
+use std::{
+    ffi::{c_char, c_void, CStr, CString},
+    fmt,
+};
+
+use necsim_core::{impl_finalise, impl_report, reporter::Reporter};
+
+use crate::{
+    export::{ReporterPluginFilter, SerializeableReporter},
+    ffi::{FfiDispersalEvent, FfiReporterDeclaration, FfiSpeciationEvent},
+};
+
+/// Capacity of the error message buffers that are passed to C-ABI reporters
+const ERROR_CAPACITY: usize = 1024;
+
+/// Host-side wrapper around a reporter from a C-ABI plugin library, which
+/// must be kept loaded for as long as the reporter is alive
+pub(crate) struct FfiReporter {
+    name: &'static str,
+    arguments: String,
+
+    declaration: FfiReporterDeclaration,
+    state: *mut c_void,
+}
+
+impl FfiReporter {
+    /// # Safety
+    ///
+    /// The `declaration` must come from a loaded C-ABI plugin library which
+    /// outlives the reporter.
+    pub(crate) unsafe fn try_new(
+        declaration: FfiReporterDeclaration,
+        arguments: String,
+    ) -> Result<Self, String> {
+        let name = Self::name_of(&declaration)?;
+
+        let c_arguments = CString::new(arguments.as_str()).map_err(|_| {
+            format!("The arguments of the C-ABI reporter {name} must not contain NUL bytes.")
+        })?;
+
+        let state = with_error_buffer(|error, error_capacity| {
+            let state =
+                (declaration.init)(c_arguments.as_ptr(), arguments.len(), error, error_capacity);
+
+            (!state.is_null()).then_some(state)
+        })
+        .map_err(|err| format!("The C-ABI reporter {name} failed to initialise: {err}"))?;
+
+        Ok(Self {
+            name,
+            arguments,
+            declaration,
+            state,
+        })
+    }
+
+    /// # Safety
+    ///
+    /// The `declaration` must come from a loaded C-ABI plugin library.
+    pub(crate) unsafe fn name_of(
+        declaration: &FfiReporterDeclaration,
+    ) -> Result<&'static str, String> {
+        if declaration.name.is_null() {
+            return Err(String::from("A C-ABI reporter has no name."));
+        }
+
+        CStr::from_ptr(declaration.name)
+            .to_str()
+            .map_err(|_| String::from("The name of a C-ABI reporter is not valid UTF-8."))
+    }
+
+    #[must_use]
+    pub(crate) fn filter_of(declaration: &FfiReporterDeclaration) -> ReporterPluginFilter {
+        ReporterPluginFilter {
+            report_speciation: declaration.report_speciation.is_some(),
+            report_dispersal: declaration.report_dispersal.is_some(),
+            report_progress: declaration.report_progress.is_some(),
+        }
+    }
+}
+
+impl Drop for FfiReporter {
+    fn drop(&mut self) {
+        if !self.state.is_null() {
+            unsafe { (self.declaration.drop)(self.state) };
+        }
+    }
+}
+
+impl fmt::Debug for FfiReporter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(FfiReporter))
+            .field("name", &self.name)
+            .field("arguments", &self.arguments)
+            .finish_non_exhaustive()
+    }
+}
+
+impl serde::Serialize for FfiReporter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.arguments)
+    }
+}
+
+impl SerializeableReporter for FfiReporter {
+    fn reporter_name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl Reporter for FfiReporter {
+    impl_report!(speciation(&mut self, speciation: Used) {
+        if let Some(report_speciation) = self.declaration.report_speciation {
+            let event = FfiSpeciationEvent::from(speciation);
+
+            unsafe { report_speciation(self.state, &event) };
+        }
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        if let Some(report_dispersal) = self.declaration.report_dispersal {
+            let event = FfiDispersalEvent::from(dispersal);
+
+            unsafe { report_dispersal(self.state, &event) };
+        }
+    });
+
+    impl_report!(progress(&mut self, remaining: Used) {
+        if let Some(report_progress) = self.declaration.report_progress {
+            unsafe { report_progress(self.state, *remaining) };
+        }
+    });
+
+    impl_finalise!((mut self) {
+        let state = std::mem::replace(&mut self.state, std::ptr::null_mut());
+
+        unsafe { (self.declaration.finalise)(state) };
+    });
+
+    fn initialise(&mut self) -> Result<(), String> {
+        let Some(initialise) = self.declaration.initialise else {
+            return Ok(());
+        };
+
+        with_error_buffer(|error, error_capacity| unsafe {
+            initialise(self.state, error, error_capacity).then_some(())
+        })
+    }
+}
+
+/// Calls `inner` with an error message buffer, which is read if `inner`
+/// reports a failure by returning `None`
+fn with_error_buffer<T, F: FnOnce(*mut c_char, usize) -> Option<T>>(inner: F) -> Result<T, String> {
+    let mut error = vec![0_u8; ERROR_CAPACITY];
+
+    if let Some(result) = inner(error.as_mut_ptr().cast(), error.len()) {
+        return Ok(result);
+    }
+
+    // Guard against C-ABI reporters which do not NUL-terminate the message
+    if let Some(last) = error.last_mut() {
+        *last = 0;
+    }
+
+    let message = CStr::from_bytes_until_nul(&error).map_or_else(
+        |_| String::new(),
+        |message| message.to_string_lossy().into_owned(),
+    );
+
+    if message.is_empty() {
+        Err(String::from("unknown error"))
+    } else {
+        Err(message)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+        process::Command,
+    };
+
+    use necsim_core::{
+        event::{DispersalEvent, SpeciationEvent},
+        landscape::{IndexedLocation, Location},
+        lineage::{GlobalLineageReference, LineageInteraction},
+    };
+    use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+    use crate::import::{ReporterPlugin, ReporterPluginLibrary};
+
+    const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+    /// Compiles the C test plugin against the C-ABI header
+    fn compile_plugin(directory: &Path) -> PathBuf {
+        let library = directory.join("libffi_plugin.so");
+
+        let status = Command::new(std::env::var("CC").unwrap_or_else(|_| String::from("cc")))
+            .args(["-std=c99", "-Wall", "-Wextra", "-Werror", "-pedantic"])
+            .args(["-shared", "-fPIC", "-I"])
+            .arg(Path::new(MANIFEST_DIR).join("include"))
+            .arg(Path::new(MANIFEST_DIR).join("tests").join("ffi_plugin.c"))
+            .arg("-o")
+            .arg(&library)
+            .status()
+            .expect("a C compiler is required to test C-ABI plugins");
+        assert!(status.success(), "the C test plugin failed to compile");
+
+        library
+    }
+
+    fn load(
+        library: &Path,
+        reporters: &str,
+    ) -> Result<Vec<ReporterPlugin>, ron::error::SpannedError> {
+        ron::from_str::<ReporterPluginLibrary>(&format!(
+            "Plugin(library: {library:?}, reporters: [{reporters}])"
+        ))
+        .map(|library| library.into_iter().collect())
+    }
+
+    fn speciation(lineage: u64, time: f64) -> SpeciationEvent {
+        SpeciationEvent {
+            global_lineage_reference: unsafe { GlobalLineageReference::from_inner(lineage) },
+            prior_time: NonNegativeF64::zero(),
+            event_time: PositiveF64::new(time).unwrap(),
+            origin: IndexedLocation::new(Location::new(1, 2), 3),
+        }
+    }
+
+    fn coalescence(lineage: u64, parent: u64, time: f64) -> DispersalEvent {
+        DispersalEvent {
+            global_lineage_reference: unsafe { GlobalLineageReference::from_inner(lineage) },
+            prior_time: NonNegativeF64::zero(),
+            event_time: PositiveF64::new(time).unwrap(),
+            origin: IndexedLocation::new(Location::new(1, 2), 3),
+            target: IndexedLocation::new(Location::new(4, 5), 6),
+            interaction: LineageInteraction::Coalescence(unsafe {
+                GlobalLineageReference::from_inner(parent)
+            }),
+        }
+    }
+
+    #[test]
+    fn header_compiles_as_cpp() {
+        let status = Command::new(std::env::var("CXX").unwrap_or_else(|_| String::from("c++")))
+            .args(["-fsyntax-only", "-Wall", "-Wextra", "-Werror", "-x", "c++"])
+            .arg(
+                Path::new(MANIFEST_DIR)
+                    .join("include")
+                    .join("necsim_reporter_plugin.h"),
+            )
+            .status()
+            .expect("a C++ compiler is required to test the C-ABI header");
+        assert!(
+            status.success(),
+            "the C-ABI header failed to compile as C++"
+        );
+    }
+
+    #[test]
+    fn c_plugin_reports_and_finalises() {
+        let dir = tempfile::tempdir().unwrap();
+        let library = compile_plugin(dir.path());
+        let output = dir.path().join("counter.txt");
+
+        let mut plugins = load(&library, &format!("Counter({output:?})")).unwrap();
+        assert_eq!(plugins.len(), 1);
+        let mut plugin = plugins.pop().unwrap();
+
+        assert!(plugin.filter.report_speciation);
+        assert!(plugin.filter.report_dispersal);
+        assert!(plugin.filter.report_progress);
+        assert_eq!(plugin.reporter.reporter_name(), "Counter");
+
+        plugin.reporter.initialise().unwrap();
+        plugin
+            .reporter
+            .report_speciation((&speciation(1, 1.5)).into());
+        plugin
+            .reporter
+            .report_dispersal((&coalescence(2, 42, 2.5)).into());
+        plugin
+            .reporter
+            .report_speciation((&speciation(3, 3.5)).into());
+        plugin.reporter.report_progress((&0).into());
+
+        assert!(!output.exists());
+        plugin.finalise();
+
+        assert_eq!(
+            fs::read_to_string(&output).unwrap(),
+            "finalised speciation=2 dispersal=1 coalescence=1 parent=42 time=3.5 remaining=0\n"
+        );
+    }
+
+    #[test]
+    fn c_plugin_is_dropped_without_finalisation() {
+        let dir = tempfile::tempdir().unwrap();
+        let library = compile_plugin(dir.path());
+        let output = dir.path().join("counter.txt");
+
+        let plugins = load(&library, &format!("Counter({output:?})")).unwrap();
+        std::mem::drop(plugins);
+
+        assert_eq!(
+            fs::read_to_string(&output).unwrap(),
+            "dropped speciation=0 dispersal=0 coalescence=0 parent=0 time=0 \
+             remaining=18446744073709551615\n"
+        );
+    }
+
+    #[test]
+    fn c_plugin_errors_are_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let library = compile_plugin(dir.path());
+
+        let err = load(&library, r#"Counter("")"#).unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "The C-ABI reporter Counter failed to initialise: the output path must not be \
+                 empty"
+            ),
+            "{err}"
+        );
+
+        let err = load(&library, r#"Unknown("")"#).unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "unknown reporter `Unknown`, expected one of `Counter`, `FailingInitialise`"
+            ),
+            "{err}"
+        );
+
+        let mut plugins = load(&library, r#"FailingInitialise("")"#).unwrap();
+        assert_eq!(
+            plugins[0].reporter.initialise(),
+            Err(String::from("the reporter refuses to start"))
+        );
+        std::mem::drop(plugins.pop());
+    }
+
+    #[test]
+    fn plugin_fields_are_validated() {
+        let dir = tempfile::tempdir().unwrap();
+        let library = compile_plugin(dir.path());
+
+        let parse = |plugin: &str| {
+            ron::from_str::<ReporterPluginLibrary>(plugin)
+                .map(|_| ())
+                .unwrap_err()
+                .to_string()
+        };
+
+        let err = parse(&format!("Plugin(reporters: [], library: {library:?})"));
+        assert!(
+            err.contains("the plugin `library` or `name` must be given before its `reporters`"),
+            "{err}"
+        );
+
+        let err = parse(&format!(
+            "Plugin(library: {library:?}, name: \"counter\", reporters: [])"
+        ));
+        assert!(
+            err.contains("either by its `library` path or by its `name`, not both"),
+            "{err}"
+        );
+
+        let err = parse(&format!("Plugin(library: {library:?})"));
+        assert!(err.contains("missing field `reporters`"), "{err}");
+
+        let err = parse(&format!(
+            "Plugin(library: {library:?}, reporters: [], reporters: [])"
+        ));
+        assert!(err.contains("duplicate field `reporters`"), "{err}");
+
+        let err = parse("Plugin()");
+        assert!(err.contains("missing field `library`"), "{err}");
+    }
+}
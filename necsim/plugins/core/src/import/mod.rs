@@ -1,7 +1,14 @@
+mod builtin;
 mod combinator;
+mod describe;
+mod ffi;
 mod plugin;
+mod search;
 mod serde;
 
 pub use self::serde::ReporterPluginLibrary;
+pub use builtin::BuiltinReporterPlugins;
 pub use combinator::{AnyReporterPluginVec, ReporterPluginVec};
+pub use describe::ReporterPluginLibraryDescription;
 pub use plugin::ReporterPlugin;
+pub use search::{plugin_search_path, resolve_plugin_library, NECSIM_PLUGIN_PATH};
@@ -29,13 +29,19 @@ impl Drop for ReporterPlugin {
             unsafe {
                 ManuallyDrop::take(&mut self.reporter).finalise_boxed();
             }
-        } else {
+        } else if let Some(declaration) = self.library.declaration.native() {
             unsafe {
-                (self.library.declaration.drop)(ManuallyDrop::new(UnsafeReporterPlugin {
+                (declaration.drop)(ManuallyDrop::new(UnsafeReporterPlugin {
                     reporter: ManuallyDrop::take(&mut self.reporter),
                     filter: self.filter,
                 }));
             }
+        } else {
+            // C-ABI reporters are wrapped on the host side and free their
+            //  state through the library when they are dropped
+            unsafe {
+                ManuallyDrop::drop(&mut self.reporter);
+            }
         }
     }
 }
@@ -0,0 +1,225 @@
+use std::{
+    env::{self, consts},
+    ffi::OsString,
+    io,
+    path::PathBuf,
+};
+
+/// Environment variable with the list of directories, separated like `PATH`,
+/// which are searched for plugin libraries by name
+pub const NECSIM_PLUGIN_PATH: &str = "NECSIM_PLUGIN_PATH";
+
+/// Returns the directories that are searched for plugin libraries by name.
+///
+/// The directories listed in the `NECSIM_PLUGIN_PATH` environment variable
+/// are searched first, followed by the standard install directory
+/// `../lib/necsim/plugins`, relative to the directory of the running
+/// executable, e.g. `~/.cargo/lib/necsim/plugins` for
+/// `~/.cargo/bin/rustcoalescence`.
+#[must_use]
+pub fn plugin_search_path() -> Vec<PathBuf> {
+    let mut search_path = env::var_os(NECSIM_PLUGIN_PATH)
+        .map(|paths| {
+            env::split_paths(&paths)
+                .filter(|path| !path.as_os_str().is_empty())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    if let Some(install_dir) = env::current_exe().ok().and_then(|exe| {
+        Some(
+            exe.parent()?
+                .parent()?
+                .join("lib")
+                .join("necsim")
+                .join("plugins"),
+        )
+    }) {
+        search_path.push(install_dir);
+    }
+
+    search_path
+}
+
+/// Resolves the platform-specific path of a plugin library from its crate
+/// name, e.g. `common` or `necsim-plugins-common`, by searching through the
+/// [`plugin_search_path`].
+///
+/// # Errors
+///
+/// Returns an error if no matching plugin library exists in any of the
+/// searched directories.
+pub fn resolve_plugin_library(name: &str) -> io::Result<PathBuf> {
+    resolve_plugin_library_in(name, &plugin_search_path())
+}
+
+/// Returns the candidate file names of a plugin library, in the order in which
+/// they are searched for in every directory
+fn plugin_library_file_names(name: &str) -> [OsString; 2] {
+    let stem = name.replace('-', "_");
+
+    // Plugins can be named with or without the `necsim_plugins_` prefix
+    [format!("necsim_plugins_{stem}"), stem].map(|stem| {
+        let mut file_name = OsString::from(consts::DLL_PREFIX);
+        file_name.push(stem);
+        file_name.push(consts::DLL_SUFFIX);
+        file_name
+    })
+}
+
+fn resolve_plugin_library_in(name: &str, search_path: &[PathBuf]) -> io::Result<PathBuf> {
+    let file_names = plugin_library_file_names(name);
+
+    for directory in search_path {
+        for file_name in &file_names {
+            let library_path = directory.join(file_name);
+
+            if library_path.is_file() {
+                return Ok(library_path);
+            }
+        }
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        format!(
+            "Plugin `{name}` was not found in the plugin search path [{}], which can be extended \
+             using the {NECSIM_PLUGIN_PATH} environment variable.",
+            search_path
+                .iter()
+                .map(|path| format!("{path:?}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env::{self, consts},
+        ffi::OsString,
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use super::{
+        plugin_library_file_names, plugin_search_path, resolve_plugin_library_in,
+        NECSIM_PLUGIN_PATH,
+    };
+
+    fn library(directory: &Path, stem: &str) -> PathBuf {
+        let path = directory.join(format!(
+            "{}{stem}{}",
+            consts::DLL_PREFIX,
+            consts::DLL_SUFFIX
+        ));
+        fs::write(&path, b"").unwrap();
+        path
+    }
+
+    #[test]
+    fn file_names_have_the_platform_prefix_and_suffix() {
+        assert_eq!(
+            plugin_library_file_names("my-plugin"),
+            [
+                format!(
+                    "{}necsim_plugins_my_plugin{}",
+                    consts::DLL_PREFIX,
+                    consts::DLL_SUFFIX
+                ),
+                format!("{}my_plugin{}", consts::DLL_PREFIX, consts::DLL_SUFFIX),
+            ]
+            .map(OsString::from)
+        );
+    }
+
+    #[test]
+    fn resolves_prefixed_and_unprefixed_names() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let common = library(dir.path(), "necsim_plugins_common");
+        let custom = library(dir.path(), "my_plugin");
+
+        let search_path = [dir.path().to_owned()];
+
+        assert_eq!(
+            resolve_plugin_library_in("common", &search_path).unwrap(),
+            common
+        );
+        assert_eq!(
+            resolve_plugin_library_in("necsim-plugins-common", &search_path).unwrap(),
+            common
+        );
+        assert_eq!(
+            resolve_plugin_library_in("my-plugin", &search_path).unwrap(),
+            custom
+        );
+        assert_eq!(
+            resolve_plugin_library_in("my_plugin", &search_path).unwrap(),
+            custom
+        );
+    }
+
+    #[test]
+    fn prefers_earlier_directories_and_prefixed_names() {
+        let first = tempfile::tempdir().unwrap();
+        let second = tempfile::tempdir().unwrap();
+
+        let unprefixed = library(second.path(), "common");
+        let search_path = [first.path().to_owned(), second.path().to_owned()];
+        assert_eq!(
+            resolve_plugin_library_in("common", &search_path).unwrap(),
+            unprefixed
+        );
+
+        // The prefixed name is preferred within the same directory
+        let prefixed = library(second.path(), "necsim_plugins_common");
+        assert_eq!(
+            resolve_plugin_library_in("common", &search_path).unwrap(),
+            prefixed
+        );
+
+        // An earlier directory is preferred even with an unprefixed name
+        let earlier = library(first.path(), "common");
+        assert_eq!(
+            resolve_plugin_library_in("common", &search_path).unwrap(),
+            earlier
+        );
+    }
+
+    #[test]
+    fn skips_directories_and_reports_the_search_path() {
+        let dir = tempfile::tempdir().unwrap();
+
+        fs::create_dir(dir.path().join(format!(
+            "{}necsim_plugins_common{}",
+            consts::DLL_PREFIX,
+            consts::DLL_SUFFIX
+        )))
+        .unwrap();
+
+        let err = resolve_plugin_library_in("common", &[dir.path().to_owned()]).unwrap_err();
+
+        assert!(err.to_string().contains("Plugin `common` was not found"));
+        assert!(err.to_string().contains(&format!("{:?}", dir.path())));
+        assert!(err.to_string().contains(NECSIM_PLUGIN_PATH));
+    }
+
+    #[test]
+    fn search_path_starts_with_the_environment() {
+        let first = tempfile::tempdir().unwrap();
+        let second = tempfile::tempdir().unwrap();
+
+        // This is the only test that reads or modifies the environment variable
+        env::set_var(
+            NECSIM_PLUGIN_PATH,
+            env::join_paths([first.path(), Path::new(""), second.path()]).unwrap(),
+        );
+        let search_path = plugin_search_path();
+        env::remove_var(NECSIM_PLUGIN_PATH);
+
+        assert_eq!(search_path[..2], [first.path(), second.path()]);
+        assert!(search_path[2].ends_with(Path::new("lib").join("necsim").join("plugins")));
+    }
+}
@@ -4,9 +4,18 @@ use std::{
 };
 
 use libloading::Library;
-use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::de::{
+    self, Deserialize, Deserializer, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
 
-use crate::{export::ReporterPluginDeclaration, import::ReporterPlugin};
+use crate::{
+    export::ReporterPluginDeclaration,
+    ffi::{
+        FfiReporterDeclaration, FfiReporterPluginDeclaration,
+        NECSIM_FFI_REPORTER_PLUGIN_ABI_VERSION, NECSIM_FFI_REPORTER_PLUGIN_DECLARATION_SYMBOL,
+    },
+    import::{ffi::FfiReporter, ReporterPlugin},
+};
 
 pub struct ReporterPluginLibrary {
     _library: Rc<PluginLibrary>,
@@ -27,7 +36,7 @@ impl<'de> Deserialize<'de> for ReporterPluginLibrary {
     where
         D: Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &["library", "reporters"];
+        const FIELDS: &[&str] = &["library", "name", "reporters"];
         deserializer.deserialize_struct("Plugin", FIELDS, ReporterPluginLibraryVisitor)
     }
 }
@@ -37,8 +46,57 @@ impl<'de> Deserialize<'de> for ReporterPluginLibrary {
 #[serde(try_from = "PathBuf")]
 pub(crate) struct PluginLibrary {
     pub(crate) path: PathBuf,
-    pub(crate) _library: Library,
-    pub(crate) declaration: ReporterPluginDeclaration,
+    // Built-in plugins are statically linked and have no dynamic library
+    pub(crate) _library: Option<Library>,
+    pub(crate) declaration: PluginDeclaration,
+}
+
+pub(crate) enum PluginDeclaration {
+    /// Rust-native plugin, which must match the host's rustc version
+    Native(ReporterPluginDeclaration),
+    /// Plugin with the stable C ABI, which only has to match the ABI version
+    Ffi(FfiReporterPluginDeclaration),
+    /// Rust-native plugin, which is statically linked into the host
+    Builtin(&'static ReporterPluginDeclaration),
+}
+
+impl PluginLibrary {
+    pub(crate) fn builtin(declaration: &'static ReporterPluginDeclaration) -> Self {
+        Self {
+            path: PathBuf::new(),
+            _library: None,
+            declaration: PluginDeclaration::Builtin(declaration),
+        }
+    }
+}
+
+impl PluginDeclaration {
+    /// Returns the declaration of a Rust-native or built-in plugin
+    pub(crate) fn native(&self) -> Option<&ReporterPluginDeclaration> {
+        match self {
+            Self::Native(declaration) => Some(declaration),
+            Self::Builtin(declaration) => Some(declaration),
+            Self::Ffi(_) => None,
+        }
+    }
+
+    /// Returns the reporter declarations of a C-ABI plugin library
+    pub(crate) fn ffi_reporters(&self) -> &[FfiReporterDeclaration] {
+        match self {
+            Self::Native(_) | Self::Builtin(_) => &[],
+            Self::Ffi(declaration) if declaration.reporters.is_null() => &[],
+            Self::Ffi(declaration) => unsafe {
+                std::slice::from_raw_parts(declaration.reporters, declaration.num_reporters)
+            },
+        }
+    }
+}
+
+impl PluginLibrary {
+    /// Loads a plugin library by its crate name from the plugin search path
+    fn try_from_name(name: &str) -> io::Result<Self> {
+        Self::try_from(crate::import::resolve_plugin_library(name)?)
+    }
 }
 
 impl TryFrom<PathBuf> for PluginLibrary {
@@ -49,52 +107,107 @@ impl TryFrom<PathBuf> for PluginLibrary {
         let library = unsafe { Library::new(library_path.clone()) }
             .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
 
-        // Load the plugin declaration symbol
-        let declaration = unsafe {
-            library
-                .get::<*const ReporterPluginDeclaration>(b"NECSIM_REPORTER_PLUGIN_DECLARATION")
-                .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?
-                .read()
+        // Load the Rust-native plugin declaration symbol
+        let declaration = match unsafe {
+            library.get::<*const ReporterPluginDeclaration>(b"NECSIM_REPORTER_PLUGIN_DECLARATION")
+        } {
+            Ok(declaration) => {
+                PluginDeclaration::Native(unsafe { check_native_declaration(*declaration) }?)
+            },
+            // Fall back to the C-ABI plugin declaration symbol
+            Err(native_err) => match unsafe {
+                library.get::<*const FfiReporterPluginDeclaration>(
+                    NECSIM_FFI_REPORTER_PLUGIN_DECLARATION_SYMBOL,
+                )
+            } {
+                Ok(declaration) => {
+                    PluginDeclaration::Ffi(check_ffi_declaration(unsafe { declaration.read() })?)
+                },
+                Err(_) => return Err(io::Error::new(io::ErrorKind::Other, native_err)),
+            },
         };
 
-        // Check for rustc version incompatibilities
-        if declaration.rustc_version != crate::RUSTC_VERSION {
-            return Err(io::Error::new(
-                io::ErrorKind::Other,
-                format!(
-                    "Plugin rustc version {} does not match system rustc version {}.",
-                    declaration.rustc_version,
-                    crate::RUSTC_VERSION
-                ),
-            ));
-        }
-
-        // Check for plugin system version incompatibilities
-        if declaration.core_version != crate::CORE_VERSION {
-            return Err(io::Error::new(
-                io::ErrorKind::Other,
-                format!(
-                    "Plugin system version {} does not match system version {}.",
-                    declaration.core_version,
-                    crate::CORE_VERSION
-                ),
-            ));
-        }
-
-        unsafe {
-            (declaration.init)(log::logger(), log::max_level());
-        }
-
-        let path = unsafe { (declaration.library_path)() }.unwrap_or(library_path);
+        let path = match &declaration {
+            PluginDeclaration::Native(declaration) => {
+                unsafe { (declaration.library_path)() }.unwrap_or(library_path)
+            },
+            PluginDeclaration::Ffi(_) | PluginDeclaration::Builtin(_) => library_path,
+        };
 
         Ok(Self {
             path,
-            _library: library,
+            _library: Some(library),
             declaration,
         })
     }
 }
 
+/// # Safety
+///
+/// The `declaration` must point to a [`ReporterPluginDeclaration`] of some
+/// plugin system version, which all start with the same version fields.
+unsafe fn check_native_declaration(
+    declaration: *const ReporterPluginDeclaration,
+) -> io::Result<ReporterPluginDeclaration> {
+    // Only the version fields are read before the versions have been checked,
+    //  since the remaining layout of the declaration may differ
+    let (rustc_version, core_version) = unsafe {
+        (
+            std::ptr::addr_of!((*declaration).rustc_version).read(),
+            std::ptr::addr_of!((*declaration).core_version).read(),
+        )
+    };
+
+    // Check for rustc version incompatibilities
+    if rustc_version != crate::RUSTC_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!(
+                "Plugin rustc version {} does not match system rustc version {}.",
+                rustc_version,
+                crate::RUSTC_VERSION
+            ),
+        ));
+    }
+
+    // Check for plugin system version incompatibilities
+    if core_version != crate::CORE_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!(
+                "Plugin system version {} does not match system version {}.",
+                core_version,
+                crate::CORE_VERSION
+            ),
+        ));
+    }
+
+    let declaration = unsafe { declaration.read() };
+
+    unsafe {
+        (declaration.init)(log::logger(), log::max_level());
+    }
+
+    Ok(declaration)
+}
+
+fn check_ffi_declaration(
+    declaration: FfiReporterPluginDeclaration,
+) -> io::Result<FfiReporterPluginDeclaration> {
+    // Check for C plugin ABI incompatibilities
+    if declaration.abi_version != NECSIM_FFI_REPORTER_PLUGIN_ABI_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!(
+                "Plugin C ABI version {} does not match system C ABI version {}.",
+                declaration.abi_version, NECSIM_FFI_REPORTER_PLUGIN_ABI_VERSION
+            ),
+        ));
+    }
+
+    Ok(declaration)
+}
+
 struct RcPluginLibrary(Rc<PluginLibrary>);
 
 // Deserialise a list of ReporterPlugins using the open library
@@ -114,10 +227,24 @@ impl<'de> serde::de::DeserializeSeed<'de> for RcPluginLibrary {
                 self,
                 deserializer: D,
             ) -> Result<Self::Value, D::Error> {
+                let declaration = match &self.library.declaration {
+                    PluginDeclaration::Native(declaration) => declaration,
+                    PluginDeclaration::Builtin(declaration) => *declaration,
+                    PluginDeclaration::Ffi(_) => {
+                        return deserializer.deserialize_enum(
+                            "Reporters",
+                            &[],
+                            FfiReporterVisitor {
+                                library: self.library,
+                            },
+                        )
+                    },
+                };
+
                 match unsafe {
-                    (self.library.declaration.deserialise)(
-                        &mut <dyn erased_serde::Deserializer>::erase(deserializer),
-                    )
+                    (declaration.deserialise)(&mut <dyn erased_serde::Deserializer>::erase(
+                        deserializer,
+                    ))
                 } {
                     Ok(reporter) => Ok(ReporterPlugin {
                         library: self.library,
@@ -168,16 +295,95 @@ impl<'de> serde::de::DeserializeSeed<'de> for RcPluginLibrary {
     }
 }
 
+// Helper struct to deserialise a single C-ABI ReporterPlugin from
+//  its `Name("arguments")` enum variant
+struct FfiReporterVisitor {
+    library: Rc<PluginLibrary>,
+}
+
+impl<'de> Visitor<'de> for FfiReporterVisitor {
+    type Value = ReporterPlugin;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a reporter with a string of arguments")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (ReporterName(name), variant) = data.variant()?;
+
+        let mut names = Vec::new();
+        let mut reporter_declaration = None;
+
+        for declaration in self.library.declaration.ffi_reporters() {
+            let reporter_name =
+                unsafe { FfiReporter::name_of(declaration) }.map_err(de::Error::custom)?;
+
+            if reporter_name == name {
+                reporter_declaration = Some(*declaration);
+                break;
+            }
+
+            names.push(reporter_name);
+        }
+
+        if let Some(declaration) = reporter_declaration {
+            let arguments: String = variant.newtype_variant()?;
+
+            let reporter = unsafe { FfiReporter::try_new(declaration, arguments) }
+                .map_err(de::Error::custom)?;
+
+            return Ok(ReporterPlugin {
+                library: self.library,
+                filter: FfiReporter::filter_of(&declaration),
+                reporter: ManuallyDrop::new(Box::new(reporter)),
+                finalised: false,
+            });
+        }
+
+        Err(de::Error::custom(format_args!(
+            "unknown reporter `{name}`, expected one of `{}`",
+            names.join("`, `")
+        )))
+    }
+}
+
+// Helper struct to deserialise the name of a C-ABI or built-in reporter
+pub(super) struct ReporterName(pub(super) String);
+
+impl<'de> Deserialize<'de> for ReporterName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ReporterNameVisitor;
+
+        impl<'de> Visitor<'de> for ReporterNameVisitor {
+            type Value = ReporterName;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a reporter name")
+            }
+
+            fn visit_str<E: de::Error>(self, name: &str) -> Result<Self::Value, E> {
+                Ok(ReporterName(String::from(name)))
+            }
+        }
+
+        deserializer.deserialize_identifier(ReporterNameVisitor)
+    }
+}
+
 // Helper enum to deserialise field names
 #[derive(serde::Deserialize)]
 #[serde(field_identifier, rename_all = "lowercase")]
 enum ReporterPluginLibraryField {
     Library,
+    Name,
     Reporters,
 }
 
+const LIBRARY_AND_NAME_ERROR: &str =
+    "a plugin must be given either by its `library` path or by its `name`, not both";
+
 // Helper struct to sequentially load the library, then the plugins
-struct ReporterPluginLibraryVisitor;
+pub(super) struct ReporterPluginLibraryVisitor;
 
 impl<'de> Visitor<'de> for ReporterPluginLibraryVisitor {
     type Value = ReporterPluginLibrary;
@@ -209,19 +415,41 @@ impl<'de> Visitor<'de> for ReporterPluginLibraryVisitor {
     where
         V: MapAccess<'de>,
     {
-        let library: Rc<PluginLibrary> =
-            if let Some(ReporterPluginLibraryField::Library) = map.next_key()? {
-                Rc::new(map.next_value()?)
-            } else {
-                return Err(de::Error::missing_field("library"));
-            };
-
-        let reporters: Vec<ReporterPlugin> =
-            if let Some(ReporterPluginLibraryField::Reporters) = map.next_key()? {
+        // The library is either given by its path or resolved from its name,
+        //  and must be loaded before its reporters can be deserialised
+        let library: Rc<PluginLibrary> = match map.next_key()? {
+            Some(ReporterPluginLibraryField::Library) => Rc::new(map.next_value()?),
+            Some(ReporterPluginLibraryField::Name) => Rc::new(
+                PluginLibrary::try_from_name(&map.next_value::<String>()?)
+                    .map_err(de::Error::custom)?,
+            ),
+            Some(ReporterPluginLibraryField::Reporters) => {
+                return Err(de::Error::custom(
+                    "the plugin `library` or `name` must be given before its `reporters`",
+                ))
+            },
+            None => return Err(de::Error::missing_field("library")),
+        };
+
+        let reporters: Vec<ReporterPlugin> = match map.next_key()? {
+            Some(ReporterPluginLibraryField::Reporters) => {
                 map.next_value_seed(RcPluginLibrary(library.clone()))?
-            } else {
-                return Err(de::Error::missing_field("reporters"));
-            };
+            },
+            Some(ReporterPluginLibraryField::Library | ReporterPluginLibraryField::Name) => {
+                return Err(de::Error::custom(LIBRARY_AND_NAME_ERROR))
+            },
+            None => return Err(de::Error::missing_field("reporters")),
+        };
+
+        match map.next_key()? {
+            Some(ReporterPluginLibraryField::Reporters) => {
+                return Err(de::Error::duplicate_field("reporters"))
+            },
+            Some(ReporterPluginLibraryField::Library | ReporterPluginLibraryField::Name) => {
+                return Err(de::Error::custom(LIBRARY_AND_NAME_ERROR))
+            },
+            None => (),
+        }
 
         Ok(ReporterPluginLibrary {
             _library: library,
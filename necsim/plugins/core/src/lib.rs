@@ -11,14 +11,23 @@ pub use erased_serde;
 pub use log;
 #[cfg(feature = "export")]
 #[doc(hidden)]
+pub use necsim_core;
+#[cfg(feature = "export")]
+#[doc(hidden)]
 pub use process_path;
 #[cfg(feature = "export")]
 #[doc(hidden)]
 pub use serde;
 
+#[cfg(any(feature = "export", feature = "import"))]
+pub mod describe;
 #[cfg(feature = "export")]
 pub mod export;
 #[cfg(all(feature = "import", not(feature = "export")))]
 mod export;
+pub mod ffi;
 #[cfg(feature = "import")]
 pub mod import;
+pub mod milestone;
+#[cfg(feature = "export")]
+pub mod output;
@@ -0,0 +1,7 @@
+/// Target of the log records which report lifecycle milestones
+///
+/// The records' messages are JSON objects with a `milestone` field. A host
+/// opts into receiving them by enabling this target, e.g. rustcoalescence with
+/// `--log-format json`, where reporters can report their own milestones, e.g.
+/// the `Progress` reporter's `progress`, instead of printing to stdout.
+pub const MILESTONE_TARGET: &str = "milestone";
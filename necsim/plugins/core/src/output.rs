@@ -0,0 +1,92 @@
+use std::{
+    fs::{self, OpenOptions},
+    io,
+    path::Path,
+};
+
+/// Checks that the `output` file can be written to once the reporter is
+/// initialised, without creating or modifying it.
+///
+/// Reporters call this while their arguments are parsed, since a config can
+/// be validated without running the simulation.
+///
+/// # Errors
+///
+/// Returns an error if `output` is a directory or a read-only file, or if it
+/// does not exist and its parent is not a writable directory.
+pub fn check_writable_file(output: &Path) -> io::Result<()> {
+    match fs::metadata(output) {
+        Ok(metadata) if metadata.is_dir() => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{output:?} is a directory."),
+        )),
+        // Opening an existing file for appending neither creates nor truncates it
+        Ok(_) => OpenOptions::new().append(true).open(output).map(drop),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            let parent = match output.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent,
+                _ => Path::new("."),
+            };
+
+            let metadata = fs::metadata(parent)?;
+
+            if !metadata.is_dir() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{parent:?} is not a directory."),
+                ));
+            }
+
+            if metadata.permissions().readonly() {
+                return Err(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    format!("{parent:?} is a read-only directory."),
+                ));
+            }
+
+            Ok(())
+        },
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::check_writable_file;
+
+    #[test]
+    fn missing_file_is_not_created() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("output.csv");
+
+        check_writable_file(&output).unwrap();
+        assert!(!output.exists());
+    }
+
+    #[test]
+    fn existing_file_is_not_modified() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("output.csv");
+        fs::write(&output, "reference,time\n").unwrap();
+
+        check_writable_file(&output).unwrap();
+        assert_eq!(fs::read_to_string(&output).unwrap(), "reference,time\n");
+    }
+
+    #[test]
+    fn directories_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+
+        assert!(check_writable_file(dir.path())
+            .unwrap_err()
+            .to_string()
+            .contains("is a directory"));
+        assert!(check_writable_file(&dir.path().join("missing").join("output.csv")).is_err());
+
+        let file = dir.path().join("file");
+        fs::write(&file, "").unwrap();
+        assert!(check_writable_file(&file.join("output.csv")).is_err());
+    }
+}
@@ -0,0 +1,160 @@
+/*
+ * Minimal C-ABI reporter plugin, which is compiled and loaded by the tests of
+ * `necsim_plugins_core::import` to check that the header and the host agree.
+ *
+ * The `Counter` reporter counts the reported events and writes a summary to
+ * the file whose path it receives as its arguments. The `FailingInitialise`
+ * reporter always fails to initialise.
+ */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "necsim_reporter_plugin.h"
+
+typedef struct counter {
+    char *path;
+    uint64_t speciation;
+    uint64_t dispersal;
+    uint64_t coalescence;
+    uint64_t last_parent;
+    double last_event_time;
+    uint64_t remaining;
+} counter;
+
+static void write_error(char *error, size_t error_capacity, const char *message) {
+    if (error_capacity > 0) {
+        strncpy(error, message, error_capacity - 1);
+        error[error_capacity - 1] = '\0';
+    }
+}
+
+static void *counter_init(const char *arguments, size_t arguments_len, char *error,
+                          size_t error_capacity) {
+    counter *state;
+
+    if (arguments_len == 0 || strlen(arguments) != arguments_len) {
+        write_error(error, error_capacity, "the output path must not be empty");
+        return NULL;
+    }
+
+    state = calloc(1, sizeof(counter));
+    if (state == NULL) {
+        write_error(error, error_capacity, "out of memory");
+        return NULL;
+    }
+
+    state->path = malloc(arguments_len + 1);
+    if (state->path == NULL) {
+        free(state);
+        write_error(error, error_capacity, "out of memory");
+        return NULL;
+    }
+    memcpy(state->path, arguments, arguments_len + 1);
+
+    state->remaining = UINT64_MAX;
+
+    return state;
+}
+
+static void counter_report_speciation(void *state, const necsim_speciation_event *event) {
+    counter *self = state;
+
+    self->speciation += 1;
+    self->last_event_time = event->event_time;
+}
+
+static void counter_report_dispersal(void *state, const necsim_dispersal_event *event) {
+    counter *self = state;
+
+    self->dispersal += 1;
+    self->last_event_time = event->event_time;
+
+    if (event->interaction == NECSIM_LINEAGE_INTERACTION_COALESCENCE) {
+        self->coalescence += 1;
+        self->last_parent = event->parent;
+    }
+}
+
+static void counter_report_progress(void *state, uint64_t remaining) {
+    counter *self = state;
+
+    self->remaining = remaining;
+}
+
+static void counter_free(counter *self, const char *status) {
+    FILE *file = fopen(self->path, "w");
+
+    if (file != NULL) {
+        fprintf(file, "%s speciation=%llu dispersal=%llu coalescence=%llu parent=%llu time=%g "
+                      "remaining=%llu\n",
+                status, (unsigned long long)self->speciation,
+                (unsigned long long)self->dispersal, (unsigned long long)self->coalescence,
+                (unsigned long long)self->last_parent, self->last_event_time,
+                (unsigned long long)self->remaining);
+        fclose(file);
+    }
+
+    free(self->path);
+    free(self);
+}
+
+static void counter_finalise(void *state) {
+    counter_free(state, "finalised");
+}
+
+static void counter_drop(void *state) {
+    counter_free(state, "dropped");
+}
+
+static void *failing_init(const char *arguments, size_t arguments_len, char *error,
+                          size_t error_capacity) {
+    (void)arguments;
+    (void)arguments_len;
+    (void)error;
+    (void)error_capacity;
+
+    return malloc(1);
+}
+
+static bool failing_initialise(void *state, char *error, size_t error_capacity) {
+    (void)state;
+
+    write_error(error, error_capacity, "the reporter refuses to start");
+
+    return false;
+}
+
+static void failing_free(void *state) {
+    free(state);
+}
+
+static const necsim_reporter_declaration REPORTERS[] = {
+    {
+        "Counter",
+        counter_init,
+        NULL,
+        counter_report_speciation,
+        counter_report_dispersal,
+        counter_report_progress,
+        counter_finalise,
+        counter_drop,
+    },
+    {
+        "FailingInitialise",
+        failing_init,
+        failing_initialise,
+        NULL,
+        NULL,
+        NULL,
+        failing_free,
+        failing_free,
+    },
+};
+
+const necsim_reporter_plugin_declaration NECSIM_FFI_REPORTER_PLUGIN_DECLARATION = {
+    NECSIM_FFI_REPORTER_PLUGIN_ABI_VERSION,
+    REPORTERS,
+    sizeof(REPORTERS) / sizeof(REPORTERS[0]),
+};
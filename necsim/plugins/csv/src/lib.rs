@@ -1,4 +1,5 @@
 #![deny(clippy::pedantic)]
+#![cfg_attr(feature = "builtin", feature(linkage))]
 
 use std::{
     convert::TryFrom,
@@ -53,11 +54,7 @@ impl TryFrom<CsvReporterArgs> for CsvReporter {
 
     fn try_from(args: CsvReporterArgs) -> Result<Self, Self::Error> {
         // Preliminary argument parsing check if the output is a writable file
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&args.output)?;
-        std::mem::drop(file);
+        necsim_plugins_core::output::check_writable_file(&args.output)?;
 
         Ok(Self {
             output: args.output,
@@ -0,0 +1,268 @@
+#![deny(clippy::pedantic)]
+#![cfg_attr(feature = "builtin", feature(linkage))]
+
+#[macro_use]
+extern crate log;
+
+use std::{
+    convert::TryFrom,
+    fmt,
+    fs::OpenOptions,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use necsim_core::{
+    event::{DispersalEvent, SpeciationEvent},
+    impl_finalise, impl_report,
+    reporter::Reporter,
+};
+
+necsim_plugins_core::export_plugin!(JsonLines => JsonLinesReporter);
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Deserialize)]
+#[serde(try_from = "JsonLinesReporterArgs")]
+pub struct JsonLinesReporter {
+    output: JsonLinesOutput,
+    progress: Duration,
+
+    writer: Option<BufWriter<Box<dyn Write>>>,
+    last_progress: Option<Instant>,
+    abandoned: bool,
+    completed: bool,
+}
+
+impl fmt::Debug for JsonLinesReporter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(JsonLinesReporter))
+            .field("output", &self.output)
+            .field("progress", &self.progress)
+            .finish_non_exhaustive()
+    }
+}
+
+impl serde::Serialize for JsonLinesReporter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        JsonLinesReporterArgs {
+            output: self.output.clone(),
+            progress: self.progress,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "JsonLines")]
+#[serde(deny_unknown_fields)]
+struct JsonLinesReporterArgs {
+    output: JsonLinesOutput,
+    #[serde(with = "humantime_duration")]
+    #[serde(default = "default_progress_interval")]
+    progress: Duration,
+}
+
+fn default_progress_interval() -> Duration {
+    Duration::from_secs(1)
+}
+
+/// Destination of the newline-delimited JSON stream
+///
+/// Stdout is not supported, as the simulation's log records and summaries
+/// are printed to it as well, which would corrupt the JSON stream.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum JsonLinesOutput {
+    /// Regular file, which is created or truncated
+    File(PathBuf),
+    /// Existing named pipe, which blocks until a reader has opened it
+    #[cfg(unix)]
+    Fifo(PathBuf),
+    /// Unix-domain stream socket, on which a reader must already be listening
+    #[cfg(unix)]
+    Socket(PathBuf),
+}
+
+impl TryFrom<JsonLinesReporterArgs> for JsonLinesReporter {
+    type Error = io::Error;
+
+    fn try_from(args: JsonLinesReporterArgs) -> Result<Self, Self::Error> {
+        // Preliminary argument parsing check if the output can be written to
+        match &args.output {
+            JsonLinesOutput::File(path) => necsim_plugins_core::output::check_writable_file(path)?,
+            #[cfg(unix)]
+            JsonLinesOutput::Fifo(path) => {
+                use std::os::unix::fs::FileTypeExt;
+
+                if !std::fs::metadata(path)?.file_type().is_fifo() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("{path:?} is not a named pipe."),
+                    ));
+                }
+            },
+            #[cfg(unix)]
+            JsonLinesOutput::Socket(path) => {
+                use std::os::unix::fs::FileTypeExt;
+
+                if !std::fs::metadata(path)?.file_type().is_socket() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("{path:?} is not a Unix-domain socket."),
+                    ));
+                }
+            },
+        }
+
+        Ok(Self {
+            output: args.output,
+            progress: args.progress,
+
+            writer: None,
+            last_progress: None,
+            abandoned: false,
+            completed: false,
+        })
+    }
+}
+
+/// A single line of the JSON stream, which is tagged with its `type`
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum JsonLine<'a> {
+    Speciation(&'a SpeciationEvent),
+    Dispersal(&'a DispersalEvent),
+    Progress { remaining: u64 },
+}
+
+impl Reporter for JsonLinesReporter {
+    impl_report!(speciation(&mut self, speciation: Used) {
+        self.write_line(&JsonLine::Speciation(speciation));
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        self.write_line(&JsonLine::Dispersal(dispersal));
+    });
+
+    impl_report!(progress(&mut self, remaining: Used) {
+        let now = Instant::now();
+
+        // Throttle the progress updates, but always report completion
+        if *remaining > 0 && self.last_progress.map_or(false, |last_progress| {
+            now.duration_since(last_progress) < self.progress
+        }) {
+            return;
+        }
+
+        self.last_progress = Some(now);
+
+        self.write_line(&JsonLine::Progress { remaining: *remaining });
+
+        // Flush with every progress update so that live readers keep up
+        if let Some(writer) = &mut self.writer {
+            match writer.flush() {
+                Ok(()) => self.completed = *remaining == 0,
+                Err(err) => self.abandon(&err),
+            }
+        }
+    });
+
+    impl_finalise!((mut self) {
+        if let Some(writer) = &mut self.writer {
+            std::mem::drop(writer.flush());
+        }
+
+        if self.abandoned && !self.completed {
+            warn!(
+                "The JSON lines written to {:?} end without the final progress update, so \
+                 readers cannot tell that the simulation has completed.",
+                self.output
+            );
+        }
+    });
+
+    fn initialise(&mut self) -> Result<(), String> {
+        if self.writer.is_some() {
+            return Ok(());
+        }
+
+        let result = (|| -> io::Result<Box<dyn Write>> {
+            Ok(match &self.output {
+                JsonLinesOutput::File(path) => Box::new(
+                    OpenOptions::new()
+                        .create(true)
+                        .truncate(true)
+                        .write(true)
+                        .open(path)?,
+                ),
+                #[cfg(unix)]
+                JsonLinesOutput::Fifo(path) => Box::new(OpenOptions::new().write(true).open(path)?),
+                #[cfg(unix)]
+                JsonLinesOutput::Socket(path) => {
+                    Box::new(std::os::unix::net::UnixStream::connect(path)?)
+                },
+            })
+        })();
+
+        match result {
+            Ok(writer) => {
+                self.writer = Some(BufWriter::new(writer));
+
+                Ok(())
+            },
+            Err(err) => Err(err.to_string()),
+        }
+    }
+}
+
+impl JsonLinesReporter {
+    fn write_line(&mut self, line: &JsonLine) {
+        let Some(writer) = &mut self.writer else {
+            return;
+        };
+
+        // A line that cannot be serialised is skipped, as the stream is intact
+        let mut bytes = match serde_json::to_vec(line) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                warn!("Skipped a JSON line that could not be serialised:\n{err}");
+                return;
+            },
+        };
+        bytes.push(b'\n');
+
+        if let Err(err) = writer.write_all(&bytes) {
+            self.abandon(&err);
+        }
+    }
+
+    /// Stops writing after the reader has gone away, without aborting the
+    /// simulation
+    fn abandon(&mut self, err: &io::Error) {
+        warn!(
+            "Stopped writing JSON lines to {:?} after an error:\n{}",
+            self.output, err
+        );
+
+        self.writer = None;
+        self.abandoned = true;
+    }
+}
+
+mod humantime_duration {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&humantime::format_duration(*duration))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let duration = String::deserialize(deserializer)?;
+
+        humantime::parse_duration(&duration).map_err(serde::de::Error::custom)
+    }
+}
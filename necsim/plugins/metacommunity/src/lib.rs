@@ -1,4 +1,5 @@
 #![deny(clippy::pedantic)]
+#![cfg_attr(feature = "builtin", feature(linkage))]
 
 #[macro_use]
 extern crate log;
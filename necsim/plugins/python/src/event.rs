@@ -0,0 +1,133 @@
+#![allow(clippy::module_name_repetitions)]
+
+use pyo3::prelude::*;
+
+use necsim_core::{
+    event::{DispersalEvent as RustDispersalEvent, SpeciationEvent as RustSpeciationEvent},
+    landscape::IndexedLocation as RustIndexedLocation,
+    lineage::{GlobalLineageReference, LineageInteraction},
+};
+
+/// Python view of a `necsim_core::landscape::IndexedLocation`
+#[pyclass(frozen, get_all, module = "necsim")]
+#[derive(Debug, Clone, Copy)]
+pub struct IndexedLocation {
+    pub x: u32,
+    pub y: u32,
+    pub index: u32,
+}
+
+#[pymethods]
+impl IndexedLocation {
+    fn __repr__(&self) -> String {
+        format!(
+            "IndexedLocation(x={}, y={}, index={})",
+            self.x, self.y, self.index
+        )
+    }
+}
+
+/// Python view of a `necsim_core::event::SpeciationEvent`
+#[pyclass(frozen, get_all, module = "necsim")]
+#[derive(Debug, Clone)]
+pub struct SpeciationEvent {
+    pub global_lineage_reference: u64,
+    pub prior_time: f64,
+    pub event_time: f64,
+    pub origin: IndexedLocation,
+}
+
+#[pymethods]
+impl SpeciationEvent {
+    fn __repr__(&self) -> String {
+        format!(
+            "SpeciationEvent(global_lineage_reference={}, prior_time={}, event_time={}, origin={})",
+            self.global_lineage_reference,
+            self.prior_time,
+            self.event_time,
+            self.origin.__repr__(),
+        )
+    }
+}
+
+/// Python view of a `necsim_core::event::DispersalEvent`.
+///
+/// The `interaction` is one of `"none"`, `"maybe"`, or `"coalescence"`, in
+/// which case `parent` is the lineage reference of the parent lineage.
+#[pyclass(frozen, get_all, module = "necsim")]
+#[derive(Debug, Clone)]
+pub struct DispersalEvent {
+    pub global_lineage_reference: u64,
+    pub prior_time: f64,
+    pub event_time: f64,
+    pub origin: IndexedLocation,
+    pub target: IndexedLocation,
+    pub interaction: &'static str,
+    pub parent: Option<u64>,
+}
+
+#[pymethods]
+impl DispersalEvent {
+    fn __repr__(&self) -> String {
+        format!(
+            "DispersalEvent(global_lineage_reference={}, prior_time={}, event_time={}, origin={}, \
+             target={}, interaction={:?}, parent={})",
+            self.global_lineage_reference,
+            self.prior_time,
+            self.event_time,
+            self.origin.__repr__(),
+            self.target.__repr__(),
+            self.interaction,
+            self.parent
+                .map_or_else(|| String::from("None"), |parent| parent.to_string()),
+        )
+    }
+}
+
+impl From<&RustIndexedLocation> for IndexedLocation {
+    fn from(location: &RustIndexedLocation) -> Self {
+        Self {
+            x: location.location().x(),
+            y: location.location().y(),
+            index: location.index(),
+        }
+    }
+}
+
+impl From<&RustSpeciationEvent> for SpeciationEvent {
+    fn from(event: &RustSpeciationEvent) -> Self {
+        Self {
+            global_lineage_reference: lineage_reference_to_u64(&event.global_lineage_reference),
+            prior_time: event.prior_time.get(),
+            event_time: event.event_time.get(),
+            origin: IndexedLocation::from(&event.origin),
+        }
+    }
+}
+
+impl From<&RustDispersalEvent> for DispersalEvent {
+    fn from(event: &RustDispersalEvent) -> Self {
+        let (interaction, parent) = match &event.interaction {
+            LineageInteraction::None => ("none", None),
+            LineageInteraction::Maybe => ("maybe", None),
+            LineageInteraction::Coalescence(parent) => {
+                ("coalescence", Some(lineage_reference_to_u64(parent)))
+            },
+        };
+
+        Self {
+            global_lineage_reference: lineage_reference_to_u64(&event.global_lineage_reference),
+            prior_time: event.prior_time.get(),
+            event_time: event.event_time.get(),
+            origin: IndexedLocation::from(&event.origin),
+            target: IndexedLocation::from(&event.target),
+            interaction,
+            parent,
+        }
+    }
+}
+
+fn lineage_reference_to_u64(reference: &GlobalLineageReference) -> u64 {
+    // Safety: the raw lineage reference is only used as an opaque identifier
+    unsafe { reference.clone().into_inner() }
+}
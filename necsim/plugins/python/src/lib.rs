@@ -0,0 +1,376 @@
+#![deny(clippy::pedantic)]
+#![cfg_attr(feature = "builtin", feature(linkage))]
+
+#[macro_use]
+extern crate log;
+
+use std::{convert::TryFrom, fmt, path::PathBuf};
+
+use pyo3::{
+    exceptions::PyTypeError,
+    prelude::*,
+    types::{PyDict, PyList, PyType},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use necsim_core::{impl_finalise, impl_report, reporter::Reporter};
+
+pub mod event;
+
+#[cfg(test)]
+mod test;
+
+use event::{DispersalEvent, SpeciationEvent};
+
+necsim_plugins_core::export_plugin!(Python => PythonReporter);
+
+/// Reporter which forwards all events to a user-supplied Python reporter.
+///
+/// The `reporter` is either a `module`, whose module-level functions are
+/// called, a `module:Class`, which is instantiated with the `kwargs`, or a
+/// `module:object`, which is used as is. The reporter may define any of the
+/// following functions or methods, all of which are optional:
+/// - `initialise()`
+/// - `report_speciation(event: SpeciationEvent)`
+/// - `report_dispersal(event: DispersalEvent)`
+/// - `report_progress(remaining: int)`
+/// - `finalise()`
+///
+/// A plain callable object, which defines none of the above, is instead
+/// called with every speciation and dispersal event.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Deserialize)]
+#[serde(try_from = "PythonReporterArgs")]
+pub struct PythonReporter {
+    reporter: String,
+    path: Vec<PathBuf>,
+    kwargs: Map<String, Value>,
+
+    methods: Option<PythonReporterMethods>,
+}
+
+struct PythonReporterMethods {
+    speciation: Option<PyObject>,
+    dispersal: Option<PyObject>,
+    progress: Option<PyObject>,
+    finalise: Option<PyObject>,
+}
+
+impl PythonReporterMethods {
+    fn is_empty(&self) -> bool {
+        self.speciation.is_none()
+            && self.dispersal.is_none()
+            && self.progress.is_none()
+            && self.finalise.is_none()
+    }
+}
+
+impl fmt::Debug for PythonReporter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(PythonReporter))
+            .field("reporter", &self.reporter)
+            .field("path", &self.path)
+            .field("kwargs", &self.kwargs)
+            .finish_non_exhaustive()
+    }
+}
+
+impl serde::Serialize for PythonReporter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PythonReporterArgs {
+            reporter: self.reporter.clone(),
+            path: self.path.clone(),
+            kwargs: self.kwargs.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Python")]
+#[serde(deny_unknown_fields)]
+struct PythonReporterArgs {
+    reporter: String,
+    /// Directories which are prepended to Python's `sys.path`
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    path: Vec<PathBuf>,
+    /// Keyword arguments which are passed to the reporter class
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_kwargs")]
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    kwargs: Map<String, Value>,
+}
+
+// Accept both `{"key": value}` maps and `(key: value)` structs
+fn deserialize_kwargs<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Map<String, Value>, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::Object(kwargs) => Ok(kwargs),
+        Value::Null => Ok(Map::new()),
+        kwargs => Err(serde::de::Error::invalid_type(
+            serde::de::Unexpected::Other(&kwargs.to_string()),
+            &"a map of keyword arguments",
+        )),
+    }
+}
+
+impl TryFrom<PythonReporterArgs> for PythonReporter {
+    type Error = String;
+
+    fn try_from(args: PythonReporterArgs) -> Result<Self, Self::Error> {
+        let module = match args.reporter.split_once(':') {
+            Some((module, _attribute)) => module,
+            None if args.kwargs.is_empty() => args.reporter.as_str(),
+            None => {
+                return Err(format!(
+                    "The Python reporter {:?} is a module, and only a module:Class can be passed \
+                     kwargs.",
+                    args.reporter
+                ))
+            },
+        };
+
+        // Preliminary argument parsing check if the reporter's module can be
+        //  found, which neither imports it nor modifies Python's `sys.path`
+        let package = module.split('.').next().unwrap_or(module);
+
+        let found = Python::with_gil(|py| -> PyResult<bool> {
+            if !py
+                .import("importlib.util")?
+                .call_method1("find_spec", (package,))?
+                .is_none()
+            {
+                return Ok(true);
+            }
+
+            let path = PyList::new(py, &args.path);
+            for directory in py.import("sys")?.getattr("path")?.iter()? {
+                path.append(directory?)?;
+            }
+
+            let spec = py
+                .import("importlib.machinery")?
+                .getattr("PathFinder")?
+                .call_method1("find_spec", (package, path))?;
+
+            Ok(!spec.is_none())
+        })
+        .map_err(|err| {
+            format!(
+                "Failed to look up the Python reporter {:?}:\n{}",
+                args.reporter,
+                Python::with_gil(|py| format_python_error(py, &err))
+            )
+        })?;
+
+        if !found {
+            return Err(format!(
+                "The Python reporter {:?} cannot be found, as there is no module {package:?}.",
+                args.reporter
+            ));
+        }
+
+        Ok(Self {
+            reporter: args.reporter,
+            path: args.path,
+            kwargs: args.kwargs,
+
+            methods: None,
+        })
+    }
+}
+
+impl Reporter for PythonReporter {
+    impl_report!(speciation(&mut self, speciation: Used) {
+        self.forward(
+            |methods| methods.speciation.as_ref(),
+            |py, method| method.call1(py, (Py::new(py, SpeciationEvent::from(speciation))?,)),
+        );
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        self.forward(
+            |methods| methods.dispersal.as_ref(),
+            |py, method| method.call1(py, (Py::new(py, DispersalEvent::from(dispersal))?,)),
+        );
+    });
+
+    impl_report!(progress(&mut self, remaining: Used) {
+        self.forward(
+            |methods| methods.progress.as_ref(),
+            |py, method| method.call1(py, (*remaining,)),
+        );
+    });
+
+    impl_finalise!((self) {
+        if let Some(method) = self.methods.as_ref().and_then(|m| m.finalise.as_ref()) {
+            if let Err(err) = Python::with_gil(|py| method.call0(py)) {
+                error!(
+                    "The Python reporter {:?} failed to finalise:\n{}",
+                    self.reporter,
+                    Python::with_gil(|py| format_python_error(py, &err))
+                );
+            }
+        }
+    });
+
+    fn initialise(&mut self) -> Result<(), String> {
+        if self.methods.is_some() {
+            return Ok(());
+        }
+
+        Python::with_gil(|py| -> PyResult<()> {
+            // Python module, class, or object; classes are instantiated here
+            let target = self.import(py)?;
+
+            let reporter = if target.is_instance_of::<PyType>() {
+                let kwargs = PyDict::new(py);
+
+                for (key, value) in &self.kwargs {
+                    kwargs.set_item(key, json_to_python(py, value))?;
+                }
+
+                target.call((), Some(kwargs))?
+            } else if self.kwargs.is_empty() {
+                target
+            } else {
+                return Err(PyTypeError::new_err(
+                    "the reporter is not a class, and only a module:Class can be passed kwargs",
+                ));
+            };
+
+            let lookup = |name: &str| -> PyResult<Option<PyObject>> {
+                if reporter.hasattr(name)? {
+                    Ok(Some(reporter.getattr(name)?.into()))
+                } else {
+                    Ok(None)
+                }
+            };
+
+            if let Some(initialise) = lookup("initialise")? {
+                initialise.call0(py)?;
+            }
+
+            let mut methods = PythonReporterMethods {
+                speciation: lookup("report_speciation")?,
+                dispersal: lookup("report_dispersal")?,
+                progress: lookup("report_progress")?,
+                finalise: lookup("finalise")?,
+            };
+
+            // A plain callable is called with every speciation and dispersal event
+            if methods.is_empty() && reporter.is_callable() {
+                methods.speciation = Some(reporter.into());
+                methods.dispersal = Some(reporter.into());
+            }
+
+            self.methods = Some(methods);
+
+            Ok(())
+        })
+        .map_err(|err| {
+            format!(
+                "Failed to initialise the Python reporter {:?}:\n{}",
+                self.reporter,
+                Python::with_gil(|py| format_python_error(py, &err))
+            )
+        })
+    }
+}
+
+impl PythonReporter {
+    /// Imports the Python reporter's module, after prepending those `path`
+    /// directories to Python's `sys.path` that it does not yet contain
+    fn import<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        let (module, attribute) = match self.reporter.split_once(':') {
+            Some((module, attribute)) => (module, Some(attribute)),
+            None => (self.reporter.as_str(), None),
+        };
+
+        let sys_path: &PyList = py.import("sys")?.getattr("path")?.downcast()?;
+
+        for directory in self.path.iter().rev() {
+            if !sys_path.contains(directory)? {
+                sys_path.insert(0, directory)?;
+            }
+        }
+
+        let module = py.import(module)?;
+
+        match attribute {
+            Some(attribute) => module.getattr(attribute),
+            None => Ok(module),
+        }
+    }
+
+    /// Forwards an event to the selected method of the Python reporter, if
+    /// it has been initialised and defines that method
+    fn forward(
+        &mut self,
+        select: impl FnOnce(&PythonReporterMethods) -> Option<&PyObject>,
+        call: impl FnOnce(Python, &PyObject) -> PyResult<PyObject>,
+    ) {
+        let Some(method) = self.methods.as_ref().and_then(select) else {
+            return;
+        };
+
+        if let Err(err) = Python::with_gil(|py| call(py, method)) {
+            self.abandon(&err);
+        }
+    }
+
+    /// Stops forwarding events after the Python reporter raised an
+    /// exception, without aborting the simulation
+    fn abandon(&mut self, err: &PyErr) {
+        error!(
+            "Stopped forwarding events to the Python reporter {:?} after an exception:\n{}",
+            self.reporter,
+            Python::with_gil(|py| format_python_error(py, err))
+        );
+
+        self.methods = None;
+    }
+}
+
+fn format_python_error(py: Python, err: &PyErr) -> String {
+    match err
+        .traceback(py)
+        .and_then(|traceback| traceback.format().ok())
+    {
+        Some(traceback) => format!("{traceback}{err}"),
+        None => err.to_string(),
+    }
+}
+
+fn json_to_python(py: Python, value: &Value) -> PyObject {
+    match value {
+        Value::Null => py.None(),
+        Value::Bool(value) => value.into_py(py),
+        Value::Number(number) => {
+            if let Some(number) = number.as_i64() {
+                number.into_py(py)
+            } else if let Some(number) = number.as_u64() {
+                number.into_py(py)
+            } else {
+                number.as_f64().unwrap_or(f64::NAN).into_py(py)
+            }
+        },
+        Value::String(value) => value.into_py(py),
+        Value::Array(values) => {
+            PyList::new(py, values.iter().map(|value| json_to_python(py, value))).into_py(py)
+        },
+        Value::Object(values) => {
+            let dict = PyDict::new(py);
+
+            for (key, value) in values {
+                // Inserting a string key into a fresh dict cannot fail
+                std::mem::drop(dict.set_item(key, json_to_python(py, value)));
+            }
+
+            dict.into_py(py)
+        },
+    }
+}
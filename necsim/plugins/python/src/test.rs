@@ -0,0 +1,292 @@
+use std::{fs, path::Path};
+
+use pyo3::{prelude::*, types::PyList};
+use serde_json::{json, Value};
+
+use necsim_core::{
+    event::{DispersalEvent, SpeciationEvent},
+    landscape::{IndexedLocation, Location},
+    lineage::{GlobalLineageReference, LineageInteraction},
+    reporter::Reporter,
+};
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+use super::PythonReporter;
+
+fn parse_reporter(
+    directory: &Path,
+    module: &str,
+    source: &[&str],
+    reporter: &str,
+    kwargs: &Value,
+) -> Result<PythonReporter, String> {
+    pyo3::prepare_freethreaded_python();
+
+    fs::write(directory.join(format!("{module}.py")), source.join("\n")).unwrap();
+
+    serde_json::from_value(json!({
+        "reporter": reporter,
+        "path": [directory],
+        "kwargs": kwargs,
+    }))
+    .map_err(|err| err.to_string())
+}
+
+fn reported_events(module: &str) -> Vec<String> {
+    Python::with_gil(|py| {
+        py.import(module)
+            .and_then(|module| module.getattr("EVENTS"))
+            .and_then(PyAny::extract)
+            .unwrap()
+    })
+}
+
+fn is_imported(module: &str) -> bool {
+    Python::with_gil(|py| {
+        py.import("sys")
+            .and_then(|sys| sys.getattr("modules"))
+            .and_then(|modules| modules.contains(module))
+            .unwrap()
+    })
+}
+
+fn speciation(x: u32) -> SpeciationEvent {
+    SpeciationEvent {
+        global_lineage_reference: unsafe { GlobalLineageReference::from_inner(u64::from(x)) },
+        prior_time: NonNegativeF64::zero(),
+        event_time: PositiveF64::new(1.0).unwrap(),
+        origin: IndexedLocation::new(Location::new(x, 0), 0),
+    }
+}
+
+fn dispersal(interaction: LineageInteraction) -> DispersalEvent {
+    DispersalEvent {
+        global_lineage_reference: unsafe { GlobalLineageReference::from_inner(1) },
+        prior_time: NonNegativeF64::zero(),
+        event_time: PositiveF64::new(1.0).unwrap(),
+        origin: IndexedLocation::new(Location::new(0, 0), 0),
+        target: IndexedLocation::new(Location::new(1, 0), 0),
+        interaction,
+    }
+}
+
+#[test]
+fn module_functions() {
+    let directory = tempfile::tempdir().unwrap();
+
+    let mut reporter = parse_reporter(
+        directory.path(),
+        "necsim_test_module",
+        &[
+            "EVENTS = []",
+            "def initialise(): EVENTS.append('initialise')",
+            "def report_speciation(event): EVENTS.append(f'speciation {event.origin.x}')",
+            "def report_dispersal(event): EVENTS.append(f'dispersal {event.interaction}')",
+            "def report_progress(remaining): EVENTS.append(f'progress {remaining}')",
+            "def finalise(): EVENTS.append('finalise')",
+        ],
+        "necsim_test_module",
+        &Value::Null,
+    )
+    .unwrap();
+
+    // Parsing the reporter neither imports it nor modifies `sys.path`
+    assert!(!is_imported("necsim_test_module"));
+    assert!(!Python::with_gil(|py| {
+        py.import("sys")
+            .and_then(|sys| sys.getattr("path"))
+            .and_then(|path| path.downcast::<PyList>().map_err(PyErr::from))
+            .and_then(|path| path.contains(directory.path()))
+            .unwrap()
+    }));
+
+    reporter.initialise().unwrap();
+    reporter.initialise().unwrap();
+
+    reporter.report_speciation((&speciation(42)).into());
+    reporter.report_dispersal((&dispersal(LineageInteraction::Maybe)).into());
+    reporter.report_progress((&7).into());
+    reporter.finalise();
+
+    assert_eq!(
+        reported_events("necsim_test_module"),
+        [
+            "initialise",
+            "speciation 42",
+            "dispersal maybe",
+            "progress 7",
+            "finalise"
+        ]
+    );
+}
+
+#[test]
+fn class_with_kwargs() {
+    let directory = tempfile::tempdir().unwrap();
+
+    let mut reporter = parse_reporter(
+        directory.path(),
+        "necsim_test_class",
+        &[
+            "EVENTS = []",
+            "class Reporter:",
+            "    def __init__(self, threshold, names):",
+            "        EVENTS.append(f'init {threshold} {names}')",
+            "    def report_speciation(self, event):",
+            "        EVENTS.append('speciation')",
+        ],
+        "necsim_test_class:Reporter",
+        &json!({ "threshold": 10, "names": ["a", "b"] }),
+    )
+    .unwrap();
+
+    reporter.initialise().unwrap();
+
+    reporter.report_speciation((&speciation(0)).into());
+    reporter.report_dispersal((&dispersal(LineageInteraction::None)).into());
+
+    assert_eq!(
+        reported_events("necsim_test_class"),
+        ["init 10 ['a', 'b']", "speciation"]
+    );
+}
+
+#[test]
+fn module_object() {
+    let directory = tempfile::tempdir().unwrap();
+
+    let mut reporter = parse_reporter(
+        directory.path(),
+        "necsim_test_object",
+        &[
+            "EVENTS = []",
+            "class _Reporter:",
+            "    def report_dispersal(self, event):",
+            "        EVENTS.append(f'dispersal {event.parent}')",
+            "reporter = _Reporter()",
+        ],
+        "necsim_test_object:reporter",
+        &Value::Null,
+    )
+    .unwrap();
+
+    reporter.initialise().unwrap();
+
+    reporter.report_speciation((&speciation(0)).into());
+    reporter.report_dispersal(
+        (&dispersal(LineageInteraction::Coalescence(unsafe {
+            GlobalLineageReference::from_inner(7)
+        })))
+            .into(),
+    );
+
+    assert_eq!(reported_events("necsim_test_object"), ["dispersal 7"]);
+}
+
+#[test]
+fn plain_callable() {
+    let directory = tempfile::tempdir().unwrap();
+
+    let mut reporter = parse_reporter(
+        directory.path(),
+        "necsim_test_callable",
+        &[
+            "EVENTS = []",
+            "def reporter(event): EVENTS.append(type(event).__name__)",
+        ],
+        "necsim_test_callable:reporter",
+        &Value::Null,
+    )
+    .unwrap();
+
+    reporter.initialise().unwrap();
+
+    reporter.report_speciation((&speciation(0)).into());
+    reporter.report_dispersal((&dispersal(LineageInteraction::None)).into());
+    reporter.report_progress((&1).into());
+
+    assert_eq!(
+        reported_events("necsim_test_callable"),
+        ["SpeciationEvent", "DispersalEvent"]
+    );
+}
+
+#[test]
+fn exception_abandons_forwarding() {
+    let directory = tempfile::tempdir().unwrap();
+
+    let mut reporter = parse_reporter(
+        directory.path(),
+        "necsim_test_exception",
+        &[
+            "EVENTS = []",
+            "def report_speciation(event):",
+            "    EVENTS.append('speciation')",
+            "    raise ValueError('failure')",
+            "def report_dispersal(event): EVENTS.append('dispersal')",
+            "def finalise(): EVENTS.append('finalise')",
+        ],
+        "necsim_test_exception",
+        &Value::Null,
+    )
+    .unwrap();
+
+    reporter.initialise().unwrap();
+
+    reporter.report_speciation((&speciation(0)).into());
+    reporter.report_speciation((&speciation(1)).into());
+    reporter.report_dispersal((&dispersal(LineageInteraction::None)).into());
+    reporter.finalise();
+
+    assert_eq!(reported_events("necsim_test_exception"), ["speciation"]);
+}
+
+#[test]
+fn kwargs_require_a_class() {
+    let directory = tempfile::tempdir().unwrap();
+    let source = &[
+        "EVENTS = []",
+        "class Reporter: pass",
+        "reporter = Reporter()",
+    ];
+
+    // A module is rejected while parsing, without importing it
+    let err = parse_reporter(
+        directory.path(),
+        "necsim_test_kwargs",
+        source,
+        "necsim_test_kwargs",
+        &json!({ "threshold": 10 }),
+    )
+    .unwrap_err();
+    assert!(
+        err.contains("only a module:Class can be passed kwargs"),
+        "{err}"
+    );
+    assert!(!is_imported("necsim_test_kwargs"));
+
+    // An object is only rejected once it has been imported
+    let mut reporter = parse_reporter(
+        directory.path(),
+        "necsim_test_kwargs",
+        source,
+        "necsim_test_kwargs:reporter",
+        &json!({ "threshold": 10 }),
+    )
+    .unwrap();
+    let err = reporter.initialise().unwrap_err();
+    assert!(
+        err.contains("only a module:Class can be passed kwargs"),
+        "{err}"
+    );
+
+    let err = parse_reporter(
+        directory.path(),
+        "necsim_test_kwargs",
+        source,
+        "necsim_test_missing:Reporter",
+        &Value::Null,
+    )
+    .unwrap_err();
+    assert!(err.contains("cannot be found"), "{err}");
+}
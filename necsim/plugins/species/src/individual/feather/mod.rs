@@ -8,11 +8,9 @@ use fnv::FnvBuildHasher;
 use serde::{Deserialize, Deserializer, Serialize};
 
 use necsim_core::{
-    event::{DispersalEvent, SpeciationEvent},
     landscape::{IndexedLocation, Location},
     lineage::GlobalLineageReference,
 };
-use necsim_core_bond::NonNegativeF64;
 
 use crate::{LastEventState, SpeciesIdentity};
 
@@ -21,9 +19,7 @@ mod reporter;
 
 #[allow(clippy::module_name_repetitions)]
 pub struct IndividualSpeciesFeatherReporter {
-    last_parent_prior_time: Option<(GlobalLineageReference, NonNegativeF64)>,
-    last_speciation_event: Option<SpeciationEvent>,
-    last_dispersal_event: Option<DispersalEvent>,
+    last_event: LastEventState,
 
     // Original (present-time) locations of all lineages
     origins: HashMap<GlobalLineageReference, IndexedLocation, FnvBuildHasher>,
@@ -69,9 +65,7 @@ impl<'de> Deserialize<'de> for IndividualSpeciesFeatherReporter {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let args = IndividualSpeciesFeatherReporterArgs::deserialize(deserializer)?;
 
-        let mut self_last_parent_prior_time = None;
-        let mut self_last_speciation_event = None;
-        let mut self_last_dispersal_event = None;
+        let mut self_last_event = LastEventState::default();
 
         let mut self_origins = HashMap::default();
         let mut self_parents = HashMap::default();
@@ -113,9 +107,7 @@ impl<'de> Deserialize<'de> for IndividualSpeciesFeatherReporter {
                 },
             };
 
-            self_last_parent_prior_time = last_event.last_parent_prior_time;
-            self_last_speciation_event = last_event.last_speciation_event;
-            self_last_dispersal_event = last_event.last_dispersal_event;
+            self_last_event = last_event;
 
             for chunk in arrow2::io::ipc::read::FileReader::new(reader, metadata, None, None) {
                 let chunk = chunk.map_err(serde::de::Error::custom)?;
@@ -209,9 +201,7 @@ impl<'de> Deserialize<'de> for IndividualSpeciesFeatherReporter {
         }
 
         Ok(Self {
-            last_parent_prior_time: self_last_parent_prior_time,
-            last_speciation_event: self_last_speciation_event,
-            last_dispersal_event: self_last_dispersal_event,
+            last_event: self_last_event,
 
             origins: self_origins,
             parents: self_parents,
@@ -291,11 +291,7 @@ impl IndividualSpeciesSQLiteReporter {
                 )
             })?;
 
-        let LastEventState {
-            last_parent_prior_time,
-            last_speciation_event,
-            last_dispersal_event,
-        } = LastEventState::from_string(&last_event).map_err(|()| {
+        self.last_event = LastEventState::from_string(&last_event).map_err(|()| {
             rusqlite::Error::SqliteFailure(
                 rusqlite::ffi::Error {
                     code: rusqlite::ffi::ErrorCode::TypeMismatch,
@@ -307,10 +303,6 @@ impl IndividualSpeciesSQLiteReporter {
             )
         })?;
 
-        self.last_parent_prior_time = last_parent_prior_time;
-        self.last_speciation_event = last_speciation_event;
-        self.last_dispersal_event = last_dispersal_event;
-
         Ok(())
     }
 
@@ -361,13 +353,7 @@ impl IndividualSpeciesSQLiteReporter {
 
         insertion.finalize()?;
 
-        let last_event_state = LastEventState {
-            last_parent_prior_time: self.last_parent_prior_time,
-            last_speciation_event: self.last_speciation_event,
-            last_dispersal_event: self.last_dispersal_event,
-        }
-        .into_string()
-        .map_err(|()| {
+        let last_event_state = self.last_event.into_string().map_err(|()| {
             rusqlite::Error::SqliteFailure(
                 rusqlite::ffi::Error {
                     code: rusqlite::ffi::ErrorCode::TypeMismatch,
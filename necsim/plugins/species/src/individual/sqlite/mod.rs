@@ -4,23 +4,16 @@ use fnv::FnvBuildHasher;
 use rusqlite::{Connection, OpenFlags};
 use serde::{Deserialize, Deserializer, Serialize};
 
-use necsim_core::{
-    event::{DispersalEvent, SpeciationEvent},
-    landscape::IndexedLocation,
-    lineage::GlobalLineageReference,
-};
-use necsim_core_bond::NonNegativeF64;
+use necsim_core::{landscape::IndexedLocation, lineage::GlobalLineageReference};
 
-use crate::SpeciesIdentity;
+use crate::{LastEventState, SpeciesIdentity};
 
 mod database;
 mod reporter;
 
 #[allow(clippy::module_name_repetitions)]
 pub struct IndividualSpeciesSQLiteReporter {
-    last_parent_prior_time: Option<(GlobalLineageReference, NonNegativeF64)>,
-    last_speciation_event: Option<SpeciationEvent>,
-    last_dispersal_event: Option<DispersalEvent>,
+    last_event: LastEventState,
 
     // Original (present-time) locations of all lineages
     origins: HashMap<GlobalLineageReference, IndexedLocation, FnvBuildHasher>,
@@ -76,9 +69,7 @@ impl<'de> Deserialize<'de> for IndividualSpeciesSQLiteReporter {
         .map_err(serde::de::Error::custom)?;
 
         Ok(Self {
-            last_parent_prior_time: None,
-            last_speciation_event: None,
-            last_dispersal_event: None,
+            last_event: LastEventState::default(),
 
             origins: HashMap::default(),
             parents: HashMap::default(),
@@ -18,7 +18,7 @@ use necsim_core::{
 };
 use necsim_core_bond::PositiveF64;
 
-use crate::{LastEventState, SpeciesIdentity};
+use crate::SpeciesIdentity;
 
 use super::LocationSpeciesFeatherReporter;
 
@@ -92,13 +92,7 @@ impl LocationSpeciesFeatherReporter {
         let mut metadata = BTreeMap::new();
         metadata.insert(
             String::from("last-event"),
-            LastEventState {
-                last_parent_prior_time: self.last_parent_prior_time.clone(),
-                last_speciation_event: self.last_speciation_event.clone(),
-                last_dispersal_event: self.last_dispersal_event.clone(),
-            }
-            .into_string()
-            .map_err(|()| {
+            self.last_event.clone().into_string().map_err(|()| {
                 std::io::Error::new(
                     std::io::ErrorKind::Other,
                     "failed to write metadata to species dataframe",
@@ -7,12 +7,8 @@ use arrow2::{
 use fnv::FnvBuildHasher;
 use serde::{Deserialize, Deserializer, Serialize};
 
-use necsim_core::{
-    event::{DispersalEvent, SpeciationEvent},
-    landscape::Location,
-    lineage::GlobalLineageReference,
-};
-use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
+use necsim_core::{landscape::Location, lineage::GlobalLineageReference};
+use necsim_core_bond::{ClosedUnitF64, PositiveF64};
 
 use crate::{LastEventState, SpeciesIdentity};
 
@@ -21,9 +17,7 @@ mod reporter;
 
 #[allow(clippy::module_name_repetitions)]
 pub struct LocationSpeciesFeatherReporter {
-    last_parent_prior_time: Option<(GlobalLineageReference, NonNegativeF64)>,
-    last_speciation_event: Option<SpeciationEvent>,
-    last_dispersal_event: Option<DispersalEvent>,
+    last_event: LastEventState,
 
     // Representation counts for all resumed lineages
     counts: HashMap<GlobalLineageReference, u64, FnvBuildHasher>,
@@ -83,9 +77,7 @@ impl<'de> Deserialize<'de> for LocationSpeciesFeatherReporter {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let args = LocationSpeciesFeatherReporterArgs::deserialize(deserializer)?;
 
-        let mut self_last_parent_prior_time = None;
-        let mut self_last_speciation_event = None;
-        let mut self_last_dispersal_event = None;
+        let mut self_last_event = LastEventState::default();
 
         let mut self_counts = HashMap::default();
         let mut self_origins = HashMap::default();
@@ -128,9 +120,7 @@ impl<'de> Deserialize<'de> for LocationSpeciesFeatherReporter {
                 },
             };
 
-            self_last_parent_prior_time = last_event.last_parent_prior_time;
-            self_last_speciation_event = last_event.last_speciation_event;
-            self_last_dispersal_event = last_event.last_dispersal_event;
+            self_last_event = last_event;
 
             for chunk in arrow2::io::ipc::read::FileReader::new(reader, metadata, None, None) {
                 let chunk = chunk.map_err(serde::de::Error::custom)?;
@@ -207,9 +197,7 @@ impl<'de> Deserialize<'de> for LocationSpeciesFeatherReporter {
         }
 
         Ok(Self {
-            last_parent_prior_time: self_last_parent_prior_time,
-            last_speciation_event: self_last_speciation_event,
-            last_dispersal_event: self_last_dispersal_event,
+            last_event: self_last_event,
 
             counts: self_counts,
             activity: HashMap::default(),
@@ -10,8 +10,8 @@ impl Reporter for LocationSpeciesFeatherReporter {
             self.store_individual_origin(&speciation.global_lineage_reference, speciation.origin.location());
         }
 
-        if Some(speciation) == self.last_speciation_event.as_ref() {
-            if let Some((parent, prior_time)) = &self.last_parent_prior_time {
+        if self.last_event.speciation_events.is_duplicate(speciation) {
+            if let Some((parent, prior_time)) = &self.last_event.last_parent_prior_time {
                 if prior_time != &speciation.prior_time {
                     let parent = parent.clone();
                     self.store_individual_coalescence(&speciation.global_lineage_reference, &parent);
@@ -21,8 +21,7 @@ impl Reporter for LocationSpeciesFeatherReporter {
             self.store_individual_speciation(&speciation.global_lineage_reference, &speciation.origin, speciation.event_time);
         }
 
-        self.last_speciation_event = Some(speciation.clone());
-        self.last_parent_prior_time = Some(
+        self.last_event.last_parent_prior_time = Some(
             (speciation.global_lineage_reference.clone(), speciation.prior_time)
         );
     });
@@ -42,8 +41,8 @@ impl Reporter for LocationSpeciesFeatherReporter {
             self.activity.insert(dispersal.global_lineage_reference.clone(), dispersal.event_time);
         }
 
-        if Some(dispersal) == self.last_dispersal_event.as_ref() {
-            if let Some((parent, prior_time)) = &self.last_parent_prior_time {
+        if self.last_event.dispersal_events.is_duplicate(dispersal) {
+            if let Some((parent, prior_time)) = &self.last_event.last_parent_prior_time {
                 if prior_time != &dispersal.prior_time {
                     let parent = parent.clone();
                     self.store_individual_coalescence(&dispersal.global_lineage_reference, &parent);
@@ -53,8 +52,7 @@ impl Reporter for LocationSpeciesFeatherReporter {
             self.store_individual_coalescence(&dispersal.global_lineage_reference, parent);
         }
 
-        self.last_dispersal_event = Some(dispersal.clone());
-        self.last_parent_prior_time = Some(
+        self.last_event.last_parent_prior_time = Some(
             (dispersal.global_lineage_reference.clone(), dispersal.prior_time)
         );
     });
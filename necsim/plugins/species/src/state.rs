@@ -4,20 +4,39 @@ use serde::{Deserialize, Serialize};
 use necsim_core::{
     event::{DispersalEvent, SpeciationEvent},
     lineage::GlobalLineageReference,
+    reporter::RecentEvents,
 };
 use necsim_core_bond::NonNegativeF64;
 
-#[allow(clippy::module_name_repetitions, clippy::struct_field_names)]
-#[derive(Serialize, Deserialize)]
+/// Duplicate event detection state of the species reporters, which need to
+/// observe duplicate events to reconstruct the parent-offspring relationships
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Default)]
 pub struct LastEventState {
     pub last_parent_prior_time: Option<(GlobalLineageReference, NonNegativeF64)>,
-    pub last_speciation_event: Option<SpeciationEvent>,
-    pub last_dispersal_event: Option<DispersalEvent>,
+    pub speciation_events: RecentEvents<SpeciationEvent>,
+    pub dispersal_events: RecentEvents<DispersalEvent>,
+}
+
+// The resume metadata only stores the latest distinct events, which keeps
+//  it compatible with species reporter outputs from earlier versions
+#[allow(clippy::struct_field_names)]
+#[derive(Serialize, Deserialize)]
+struct LastEventMetadata {
+    last_parent_prior_time: Option<(GlobalLineageReference, NonNegativeF64)>,
+    last_speciation_event: Option<SpeciationEvent>,
+    last_dispersal_event: Option<DispersalEvent>,
 }
 
 impl LastEventState {
     pub fn into_string(self) -> Result<String, ()> {
-        let bytes = bincode::options().serialize(&self).map_err(|_| ())?;
+        let metadata = LastEventMetadata {
+            last_parent_prior_time: self.last_parent_prior_time,
+            last_speciation_event: self.speciation_events.latest().cloned(),
+            last_dispersal_event: self.dispersal_events.latest().cloned(),
+        };
+
+        let bytes = bincode::options().serialize(&metadata).map_err(|_| ())?;
 
         Ok(base32::encode(base32::Alphabet::Crockford, &bytes))
     }
@@ -25,6 +44,52 @@ impl LastEventState {
     pub fn from_string(string: &str) -> Result<LastEventState, ()> {
         let bytes = base32::decode(base32::Alphabet::Crockford, string).ok_or(())?;
 
-        bincode::options().deserialize(&bytes).map_err(|_| ())
+        let metadata: LastEventMetadata = bincode::options().deserialize(&bytes).map_err(|_| ())?;
+
+        let mut state = LastEventState {
+            last_parent_prior_time: metadata.last_parent_prior_time,
+            ..LastEventState::default()
+        };
+        state
+            .speciation_events
+            .extend(metadata.last_speciation_event);
+        state.dispersal_events.extend(metadata.last_dispersal_event);
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use necsim_core::{
+        event::SpeciationEvent,
+        landscape::{IndexedLocation, Location},
+        lineage::GlobalLineageReference,
+    };
+    use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+    use super::LastEventState;
+
+    fn speciation(x: u32) -> SpeciationEvent {
+        SpeciationEvent {
+            global_lineage_reference: unsafe { GlobalLineageReference::from_inner(u64::from(x)) },
+            prior_time: NonNegativeF64::zero(),
+            event_time: PositiveF64::new(1.0).unwrap(),
+            origin: IndexedLocation::new(Location::new(x, 0), 0),
+        }
+    }
+
+    #[test]
+    fn resume_keeps_latest_events() {
+        let mut state = LastEventState::default();
+
+        assert!(!state.speciation_events.is_duplicate(&speciation(0)));
+        assert!(!state.speciation_events.is_duplicate(&speciation(1)));
+
+        let mut resumed = LastEventState::from_string(&state.into_string().unwrap()).unwrap();
+
+        assert!(resumed.last_parent_prior_time.is_none());
+        assert!(resumed.speciation_events.is_duplicate(&speciation(1)));
+        assert!(resumed.dispersal_events.latest().is_none());
     }
 }
@@ -8,14 +8,12 @@ use std::{
 
 use serde::{Deserialize, Serialize};
 
-use necsim_core::{event::DispersalEvent, impl_finalise, impl_report, reporter::Reporter};
+use necsim_core::{impl_finalise, impl_report, reporter::Reporter};
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Deserialize)]
 #[serde(try_from = "GlobalCoverageReporterArgs")]
 pub struct GlobalCoverageReporter {
-    last_dispersal_event: Option<DispersalEvent>,
-
     output: PathBuf,
     writer: Option<BufWriter<File>>,
 }
@@ -48,15 +46,9 @@ impl TryFrom<GlobalCoverageReporterArgs> for GlobalCoverageReporter {
 
     fn try_from(args: GlobalCoverageReporterArgs) -> Result<Self, Self::Error> {
         // Preliminary argument parsing check if the output is a writable file
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&args.output)?;
-        std::mem::drop(file);
+        necsim_plugins_core::output::check_writable_file(&args.output)?;
 
         Ok(Self {
-            last_dispersal_event: None,
-
             output: args.output,
             writer: None,
         })
@@ -67,12 +59,6 @@ impl Reporter for GlobalCoverageReporter {
     impl_report!(speciation(&mut self, _speciation: Ignored) {});
 
     impl_report!(dispersal(&mut self, dispersal: Used) {
-        if Some(dispersal) == self.last_dispersal_event.as_ref() {
-            return;
-        }
-
-        self.last_dispersal_event = Some(dispersal.clone());
-
         if let Some(writer) = &mut self.writer {
             std::mem::drop(writeln!(
                 writer, "{},{},{},{},{},{}",
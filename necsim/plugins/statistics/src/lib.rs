@@ -1,11 +1,12 @@
 #![deny(clippy::pedantic)]
+#![cfg_attr(feature = "builtin", feature(linkage))]
 
 mod coverage;
 mod speciation;
 mod turnover;
 
 necsim_plugins_core::export_plugin!(
-    GlobalTurnover => turnover::GlobalTurnoverReporter,
-    GlobalSpeciation => speciation::GlobalSpeciationReporter,
-    GlobalCoverage => coverage::GlobalCoverageReporter,
+    #[deduplicate] GlobalTurnover => turnover::GlobalTurnoverReporter,
+    #[deduplicate] GlobalSpeciation => speciation::GlobalSpeciationReporter,
+    #[deduplicate] GlobalCoverage => coverage::GlobalCoverageReporter,
 );
@@ -8,14 +8,12 @@ use std::{
 
 use serde::{Deserialize, Serialize};
 
-use necsim_core::{event::SpeciationEvent, impl_finalise, impl_report, reporter::Reporter};
+use necsim_core::{impl_finalise, impl_report, reporter::Reporter};
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Deserialize)]
 #[serde(try_from = "GlobalSpeciationReporterArgs")]
 pub struct GlobalSpeciationReporter {
-    last_speciation_event: Option<SpeciationEvent>,
-
     output: PathBuf,
     writer: Option<BufWriter<File>>,
 }
@@ -48,15 +46,9 @@ impl TryFrom<GlobalSpeciationReporterArgs> for GlobalSpeciationReporter {
 
     fn try_from(args: GlobalSpeciationReporterArgs) -> Result<Self, Self::Error> {
         // Preliminary argument parsing check if the output is a writable file
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&args.output)?;
-        std::mem::drop(file);
+        necsim_plugins_core::output::check_writable_file(&args.output)?;
 
         Ok(Self {
-            last_speciation_event: None,
-
             output: args.output,
             writer: None,
         })
@@ -65,12 +57,6 @@ impl TryFrom<GlobalSpeciationReporterArgs> for GlobalSpeciationReporter {
 
 impl Reporter for GlobalSpeciationReporter {
     impl_report!(speciation(&mut self, speciation: Used) {
-        if Some(speciation) == self.last_speciation_event.as_ref() {
-            return;
-        }
-
-        self.last_speciation_event = Some(speciation.clone());
-
         if let Some(writer) = &mut self.writer {
             std::mem::drop(writeln!(writer, "{}", speciation.event_time.get()));
         }
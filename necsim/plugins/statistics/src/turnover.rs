@@ -8,19 +8,12 @@ use std::{
 
 use serde::{Deserialize, Serialize};
 
-use necsim_core::{
-    event::{DispersalEvent, SpeciationEvent},
-    impl_finalise, impl_report,
-    reporter::Reporter,
-};
+use necsim_core::{impl_finalise, impl_report, reporter::Reporter};
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Deserialize)]
 #[serde(try_from = "GlobalTurnoverReporterArgs")]
 pub struct GlobalTurnoverReporter {
-    last_speciation_event: Option<SpeciationEvent>,
-    last_dispersal_event: Option<DispersalEvent>,
-
     output: PathBuf,
     writer: Option<BufWriter<File>>,
 }
@@ -53,16 +46,9 @@ impl TryFrom<GlobalTurnoverReporterArgs> for GlobalTurnoverReporter {
 
     fn try_from(args: GlobalTurnoverReporterArgs) -> Result<Self, Self::Error> {
         // Preliminary argument parsing check if the output is a writable file
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&args.output)?;
-        std::mem::drop(file);
+        necsim_plugins_core::output::check_writable_file(&args.output)?;
 
         Ok(Self {
-            last_speciation_event: None,
-            last_dispersal_event: None,
-
             output: args.output,
             writer: None,
         })
@@ -79,22 +65,10 @@ impl GlobalTurnoverReporter {
 
 impl Reporter for GlobalTurnoverReporter {
     impl_report!(speciation(&mut self, speciation: Used) {
-        if Some(speciation) == self.last_speciation_event.as_ref() {
-            return;
-        }
-
-        self.last_speciation_event = Some(speciation.clone());
-
         self.write_turnover(speciation.event_time.get() - speciation.prior_time.get());
     });
 
     impl_report!(dispersal(&mut self, dispersal: Used) {
-        if Some(dispersal) == self.last_dispersal_event.as_ref() {
-            return;
-        }
-
-        self.last_dispersal_event = Some(dispersal.clone());
-
         self.write_turnover(dispersal.event_time.get() - dispersal.prior_time.get());
     });
 
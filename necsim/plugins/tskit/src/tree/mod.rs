@@ -1,4 +1,4 @@
-use std::{collections::HashMap, convert::TryFrom, fmt, fs::OpenOptions, io};
+use std::{collections::HashMap, convert::TryFrom, fmt, io, path::Path};
 
 use fnv::FnvBuildHasher;
 use serde::{Deserialize, Serialize, Serializer};
@@ -74,11 +74,7 @@ impl TryFrom<TskitTreeReporterArgs> for TskitTreeReporter {
 
     fn try_from(args: TskitTreeReporterArgs) -> Result<Self, Self::Error> {
         // Preliminary argument parsing check if the output is a writable file
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&args.output)?;
-        std::mem::drop(file);
+        necsim_plugins_core::output::check_writable_file(Path::new(&args.output))?;
 
         let table = TableCollection::new(TSK_SEQUENCE_MAX)
             .map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))?;
@@ -224,7 +224,11 @@ where
     }
 
     match status {
-        Status::Done => Ok(SimulationOutcome::Done { time, steps }),
+        Status::Done => Ok(SimulationOutcome::Done {
+            time,
+            steps,
+            rng: simulation.rng_mut().clone(),
+        }),
         Status::Paused => Ok(SimulationOutcome::Paused {
             time,
             steps,
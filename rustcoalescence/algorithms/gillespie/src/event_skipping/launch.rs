@@ -111,7 +111,11 @@ where
             );
 
             match status {
-                Status::Done => Ok(SimulationOutcome::Done { time, steps }),
+                Status::Done => Ok(SimulationOutcome::Done {
+                    time,
+                    steps,
+                    rng: simulation.rng_mut().clone(),
+                }),
                 Status::Paused => Ok(SimulationOutcome::Paused {
                     time,
                     steps,
@@ -213,7 +217,11 @@ where
             };
 
             // TODO: Adapt for parallel pausing
-            Ok(SimulationOutcome::Done { time, steps })
+            Ok(SimulationOutcome::Done {
+                time,
+                steps,
+                rng: simulation.rng_mut().clone(),
+            })
         },
     }
 }
@@ -106,7 +106,11 @@ where
             );
 
             match status {
-                Status::Done => Ok(SimulationOutcome::Done { time, steps }),
+                Status::Done => Ok(SimulationOutcome::Done {
+                    time,
+                    steps,
+                    rng: simulation.rng_mut().clone(),
+                }),
                 Status::Paused => Ok(SimulationOutcome::Paused {
                     time,
                     steps,
@@ -205,7 +209,11 @@ where
             };
 
             // TODO: Adapt for parallel pausing
-            Ok(SimulationOutcome::Done { time, steps })
+            Ok(SimulationOutcome::Done {
+                time,
+                steps,
+                rng: simulation.rng_mut().clone(),
+            })
         },
     }
 }
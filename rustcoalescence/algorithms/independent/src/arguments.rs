@@ -1,4 +1,4 @@
-use std::num::NonZeroU64;
+use std::num::{NonZeroU64, NonZeroUsize};
 
 use serde::{Deserialize, Serialize};
 use serde_state::DeserializeState;
@@ -14,6 +14,13 @@ pub struct MonolithicParallelismMode {
     pub event_slice: EventSlice,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThreadedParallelismMode {
+    pub workers: NonZeroUsize,
+    pub event_slice: EventSlice,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct IsolatedParallelismMode {
@@ -31,6 +38,7 @@ pub struct ProbabilisticParallelismMode {
 #[derive(Debug, Serialize, Deserialize)]
 pub enum ParallelismMode {
     Monolithic(MonolithicParallelismMode),
+    Threaded(ThreadedParallelismMode),
     IsolatedIndividuals(IsolatedParallelismMode),
     IsolatedLandscape(IsolatedParallelismMode),
     Individuals,
@@ -49,6 +57,7 @@ impl<'de> DeserializeState<'de, Partition> for ParallelismMode {
 
         match parallelism_mode {
             ParallelismMode::Monolithic(..)
+            | ParallelismMode::Threaded(..)
             | ParallelismMode::IsolatedIndividuals(..)
             | ParallelismMode::IsolatedLandscape(..)
                 if partition.size().get() > 1 =>
@@ -82,6 +91,18 @@ pub struct IndependentArguments {
     pub parallelism_mode: ParallelismMode,
 }
 
+impl IndependentArguments {
+    /// Returns the number of worker threads in the `Threaded` parallelism
+    /// mode, or `None` if the simulation runs on a single thread.
+    #[must_use]
+    pub fn threaded_workers(&self) -> Option<NonZeroUsize> {
+        match &self.parallelism_mode {
+            ParallelismMode::Threaded(ThreadedParallelismMode { workers, .. }) => Some(*workers),
+            _ => None,
+        }
+    }
+}
+
 impl<'de> DeserializeState<'de, Partition> for IndependentArguments {
     fn deserialize_state<D>(partition: &mut Partition, deserializer: D) -> Result<Self, D::Error>
     where
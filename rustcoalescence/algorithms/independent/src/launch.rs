@@ -37,9 +37,10 @@ use rustcoalescence_scenarios::Scenario;
 use crate::{
     arguments::{
         IndependentArguments, IsolatedParallelismMode, MonolithicParallelismMode, ParallelismMode,
-        ProbabilisticParallelismMode,
+        ProbabilisticParallelismMode, ThreadedParallelismMode,
     },
     initialiser::IndependentLineageStoreSampleInitialiser,
+    threaded::ThreadedSimulation,
 };
 
 #[allow(clippy::too_many_lines)]
@@ -64,6 +65,7 @@ pub fn initialise_and_simulate<
 ) -> Result<SimulationOutcome<M, G>, Error> {
     match args.parallelism_mode {
         ParallelismMode::Monolithic(MonolithicParallelismMode { event_slice })
+        | ParallelismMode::Threaded(ThreadedParallelismMode { event_slice, .. })
         | ParallelismMode::IsolatedIndividuals(IsolatedParallelismMode { event_slice, .. })
         | ParallelismMode::IsolatedLandscape(IsolatedParallelismMode { event_slice, .. }) => {
             let (
@@ -79,12 +81,14 @@ pub fn initialise_and_simulate<
 
             let (lineage_store, dispersal_sampler, active_lineage_sampler, lineages, passthrough) =
                 match args.parallelism_mode {
-                    // Apply no lineage origin partitioning in the `Monolithic` mode
-                    ParallelismMode::Monolithic(..) => lineage_store_sampler_initialiser.init(
-                        O::sample_habitat(&habitat, pre_sampler, origin_sampler_auxiliary),
-                        dispersal_sampler,
-                        PoissonEventTimeSampler::new(args.delta_t),
-                    )?,
+                    // Apply no lineage origin partitioning in the `Monolithic` and `Threaded` modes
+                    ParallelismMode::Monolithic(..) | ParallelismMode::Threaded(..) => {
+                        lineage_store_sampler_initialiser.init(
+                            O::sample_habitat(&habitat, pre_sampler, origin_sampler_auxiliary),
+                            dispersal_sampler,
+                            PoissonEventTimeSampler::new(args.delta_t),
+                        )?
+                    },
                     // Apply lineage origin partitioning in the `IsolatedIndividuals` mode
                     ParallelismMode::IsolatedIndividuals(IsolatedParallelismMode {
                         partition,
@@ -132,23 +136,44 @@ pub fn initialise_and_simulate<
             }
             .build();
 
-            let (mut status, time, steps, lineages) =
-                parallelisation::independent::monolithic::simulate(
-                    &mut simulation,
-                    lineages,
-                    args.dedup_cache,
-                    args.step_slice,
-                    event_slice,
-                    pause_before,
-                    local_partition,
-                );
+            let (mut status, time, steps, lineages): (_, _, _, Vec<_>) =
+                if let ParallelismMode::Threaded(ThreadedParallelismMode { workers, .. }) =
+                    args.parallelism_mode
+                {
+                    simulation.simulate_threaded(
+                        lineages,
+                        args.dedup_cache,
+                        args.step_slice,
+                        event_slice,
+                        workers,
+                        pause_before,
+                        local_partition,
+                    )
+                } else {
+                    let (status, time, steps, lineages) =
+                        parallelisation::independent::monolithic::simulate(
+                            &mut simulation,
+                            lineages,
+                            args.dedup_cache,
+                            args.step_slice,
+                            event_slice,
+                            pause_before,
+                            local_partition,
+                        );
+
+                    (status, time, steps, lineages.into_iter().collect())
+                };
 
             if !passthrough.is_empty() {
                 status = Status::Paused;
             }
 
             match status {
-                Status::Done => Ok(SimulationOutcome::Done { time, steps }),
+                Status::Done => Ok(SimulationOutcome::Done {
+                    time,
+                    steps,
+                    rng: simulation.rng_mut().clone(),
+                }),
                 Status::Paused => Ok(SimulationOutcome::Paused {
                     time,
                     steps,
@@ -211,7 +236,11 @@ pub fn initialise_and_simulate<
 
             // TODO: Adapt for parallel pausing
             // TODO: Adapt for lineage passthrough
-            Ok(SimulationOutcome::Done { time, steps })
+            Ok(SimulationOutcome::Done {
+                time,
+                steps,
+                rng: simulation.rng_mut().clone(),
+            })
         },
         ParallelismMode::Landscape => {
             let (
@@ -272,7 +301,11 @@ pub fn initialise_and_simulate<
 
             // TODO: Adapt for parallel pausing
             // TODO: Adapt for lineage passthrough
-            Ok(SimulationOutcome::Done { time, steps })
+            Ok(SimulationOutcome::Done {
+                time,
+                steps,
+                rng: simulation.rng_mut().clone(),
+            })
         },
         ParallelismMode::Probabilistic(ProbabilisticParallelismMode {
             communication_probability,
@@ -337,7 +370,11 @@ pub fn initialise_and_simulate<
 
             // TODO: Adapt for parallel pausing
             // TODO: Adapt for lineage passthrough
-            Ok(SimulationOutcome::Done { time, steps })
+            Ok(SimulationOutcome::Done {
+                time,
+                steps,
+                rng: simulation.rng_mut().clone(),
+            })
         },
     }
 }
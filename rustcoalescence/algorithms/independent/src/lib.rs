@@ -1,9 +1,14 @@
 #![deny(clippy::pedantic)]
 #![feature(never_type)]
+#![feature(specialization)]
+#![allow(incomplete_features)]
 
 #[macro_use]
 extern crate serde_derive_state;
 
+#[macro_use]
+extern crate log;
+
 use necsim_core::{cogs::MathsCore, lineage::Lineage, reporter::Reporter};
 use necsim_core_bond::{NonNegativeF64, PositiveF64};
 
@@ -23,6 +28,7 @@ use rustcoalescence_scenarios::Scenario;
 mod arguments;
 mod initialiser;
 mod launch;
+mod threaded;
 
 use arguments::{IndependentArguments, IsolatedParallelismMode, ParallelismMode};
 use initialiser::{
@@ -49,7 +55,9 @@ impl<'p, O: Scenario<M, WyHash<M>>, R: Reporter, P: LocalPartition<'p, R>, M: Ma
 
     fn get_logical_partition(args: &Self::Arguments, local_partition: &P) -> Partition {
         match &args.parallelism_mode {
-            ParallelismMode::Monolithic(_) => Partition::monolithic(),
+            ParallelismMode::Monolithic(_) | ParallelismMode::Threaded(_) => {
+                Partition::monolithic()
+            },
             ParallelismMode::IsolatedIndividuals(IsolatedParallelismMode { partition, .. })
             | ParallelismMode::IsolatedLandscape(IsolatedParallelismMode { partition, .. }) => {
                 *partition
@@ -0,0 +1,222 @@
+use std::{
+    any::type_name,
+    num::{NonZeroU64, NonZeroUsize},
+};
+
+use necsim_core::{
+    cogs::{
+        DispersalSampler, Habitat, MathsCore, PrimeableRng, SpeciationProbability, TurnoverRate,
+    },
+    lineage::Lineage,
+    reporter::Reporter,
+    simulation::Simulation,
+};
+use necsim_core_bond::NonNegativeF64;
+
+use necsim_impls_no_std::{
+    cogs::{
+        active_lineage_sampler::singular::SingularActiveLineageSampler,
+        coalescence_sampler::independent::IndependentCoalescenceSampler,
+        emigration_exit::never::NeverEmigrationExit,
+        event_sampler::independent::IndependentEventSampler,
+        immigration_entry::never::NeverImmigrationEntry,
+        lineage_store::independent::IndependentLineageStore,
+    },
+    parallelisation::{
+        independent::{monolithic, DedupCache, EventSlice},
+        Status,
+    },
+};
+use necsim_impls_std::parallelisation::independent::threaded;
+use necsim_partitioning_core::LocalPartition;
+
+/// Runs the `Threaded` parallelism mode, which requires all components of the
+/// simulation to be [`Send`], and the read-only components which the workers
+/// share to also be [`Sync`]. Simulations with any components which cannot be
+/// sent to or shared with another thread fall back to the `Monolithic` mode,
+/// which produces the same events on a single thread.
+pub trait ThreadedSimulation<'p, R: Reporter, P: LocalPartition<'p, R>> {
+    #[allow(clippy::too_many_arguments)]
+    fn simulate_threaded(
+        &mut self,
+        lineages: Vec<Lineage>,
+        dedup_cache: DedupCache,
+        step_slice: NonZeroU64,
+        event_slice: EventSlice,
+        workers: NonZeroUsize,
+        pause_before: Option<NonNegativeF64>,
+        local_partition: &mut P,
+    ) -> (Status, NonNegativeF64, u64, Vec<Lineage>);
+}
+
+impl<
+        'p,
+        M: MathsCore,
+        H: Habitat<M>,
+        G: PrimeableRng<M>,
+        D: DispersalSampler<M, H, G>,
+        T: TurnoverRate<M, H>,
+        N: SpeciationProbability<M, H>,
+        A: SingularActiveLineageSampler<
+            M,
+            H,
+            G,
+            IndependentLineageStore<M, H>,
+            NeverEmigrationExit,
+            D,
+            IndependentCoalescenceSampler<M, H>,
+            T,
+            N,
+            IndependentEventSampler<M, H, G, NeverEmigrationExit, D, T, N>,
+            NeverImmigrationEntry,
+        >,
+        R: Reporter,
+        P: LocalPartition<'p, R>,
+    > ThreadedSimulation<'p, R, P>
+    for Simulation<
+        M,
+        H,
+        G,
+        IndependentLineageStore<M, H>,
+        NeverEmigrationExit,
+        D,
+        IndependentCoalescenceSampler<M, H>,
+        T,
+        N,
+        IndependentEventSampler<M, H, G, NeverEmigrationExit, D, T, N>,
+        NeverImmigrationEntry,
+        A,
+    >
+{
+    default fn simulate_threaded(
+        &mut self,
+        lineages: Vec<Lineage>,
+        dedup_cache: DedupCache,
+        step_slice: NonZeroU64,
+        event_slice: EventSlice,
+        workers: NonZeroUsize,
+        pause_before: Option<NonNegativeF64>,
+        local_partition: &mut P,
+    ) -> (Status, NonNegativeF64, u64, Vec<Lineage>) {
+        let unthreadable = [
+            (type_name::<M>(), M::IS_SEND),
+            (type_name::<H>(), H::IS_SEND && H::IS_SYNC),
+            (type_name::<G>(), G::IS_SEND),
+            (type_name::<D>(), D::IS_SEND && D::IS_SYNC),
+            (type_name::<T>(), T::IS_SEND && T::IS_SYNC),
+            (type_name::<N>(), N::IS_SEND && N::IS_SYNC),
+            (type_name::<A>(), A::IS_SEND),
+        ]
+        .into_iter()
+        .filter_map(|(component, threadable)| (!threadable).then_some(component))
+        .collect::<Vec<_>>();
+
+        warn!(
+            "The Threaded parallelism mode with {workers} workers falls back to running on a \
+             single thread, as the simulation components {unthreadable:?} cannot be sent to or \
+             shared with other threads."
+        );
+
+        let (status, time, steps, lineages) = monolithic::simulate(
+            self,
+            lineages,
+            dedup_cache,
+            step_slice,
+            event_slice,
+            pause_before,
+            local_partition,
+        );
+
+        (status, time, steps, lineages.into_iter().collect())
+    }
+}
+
+impl<
+        'p,
+        M: MathsCore + Send,
+        H: Habitat<M> + Send + Sync,
+        G: PrimeableRng<M> + Send,
+        D: DispersalSampler<M, H, G> + Send + Sync,
+        T: TurnoverRate<M, H> + Send + Sync,
+        N: SpeciationProbability<M, H> + Send + Sync,
+        A: SingularActiveLineageSampler<
+                M,
+                H,
+                G,
+                IndependentLineageStore<M, H>,
+                NeverEmigrationExit,
+                D,
+                IndependentCoalescenceSampler<M, H>,
+                T,
+                N,
+                IndependentEventSampler<M, H, G, NeverEmigrationExit, D, T, N>,
+                NeverImmigrationEntry,
+            > + Send,
+        R: Reporter,
+        P: LocalPartition<'p, R>,
+    > ThreadedSimulation<'p, R, P>
+    for Simulation<
+        M,
+        H,
+        G,
+        IndependentLineageStore<M, H>,
+        NeverEmigrationExit,
+        D,
+        IndependentCoalescenceSampler<M, H>,
+        T,
+        N,
+        IndependentEventSampler<M, H, G, NeverEmigrationExit, D, T, N>,
+        NeverImmigrationEntry,
+        A,
+    >
+{
+    fn simulate_threaded(
+        &mut self,
+        lineages: Vec<Lineage>,
+        dedup_cache: DedupCache,
+        step_slice: NonZeroU64,
+        event_slice: EventSlice,
+        workers: NonZeroUsize,
+        pause_before: Option<NonNegativeF64>,
+        local_partition: &mut P,
+    ) -> (Status, NonNegativeF64, u64, Vec<Lineage>) {
+        let (status, time, steps, lineages) = threaded::simulate(
+            self,
+            lineages,
+            dedup_cache,
+            step_slice,
+            event_slice,
+            workers,
+            pause_before,
+            local_partition,
+        );
+
+        (status, time, steps, lineages.into_iter().collect())
+    }
+}
+
+/// Checks whether a simulation component can be sent to another thread.
+trait IsSend {
+    const IS_SEND: bool;
+}
+
+impl<T> IsSend for T {
+    default const IS_SEND: bool = false;
+}
+
+impl<T: Send> IsSend for T {
+    const IS_SEND: bool = true;
+}
+
+/// Checks whether a simulation component can be shared between threads.
+trait IsSync {
+    const IS_SYNC: bool;
+}
+
+impl<T> IsSync for T {
+    default const IS_SYNC: bool = false;
+}
+
+impl<T: Sync> IsSync for T {
+    const IS_SYNC: bool = true;
+}
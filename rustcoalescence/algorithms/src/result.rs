@@ -12,6 +12,7 @@ pub enum SimulationOutcome<M: MathsCore, G: RngCore<M>> {
     Done {
         time: NonNegativeF64,
         steps: u64,
+        rng: G,
     },
     Paused {
         time: NonNegativeF64,
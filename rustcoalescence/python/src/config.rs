@@ -0,0 +1,384 @@
+use pyo3::{
+    exceptions::PyTypeError,
+    prelude::*,
+    types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple},
+};
+
+/// Converts a `simulate` or `replay` config into its RON representation.
+///
+/// The `config` is either a RON string, or a dict or dataclass whose fields
+/// mirror the config struct. The `extra_reporters` are appended to the
+/// config's `reporters`.
+pub fn serialise(config: &PyAny, extra_reporters: &[String]) -> PyResult<String> {
+    if let Ok(config) = config.downcast::<PyString>() {
+        if !extra_reporters.is_empty() {
+            return Err(PyTypeError::new_err(
+                "Python reporters can only be combined with a dict or dataclass config",
+            ));
+        }
+
+        return Ok(config.to_str()?.to_owned());
+    }
+
+    let fields = if let Ok(config) = config.downcast::<PyDict>() {
+        config
+            .iter()
+            .map(|(key, value)| Ok((key.extract::<String>()?, value)))
+            .collect::<PyResult<Vec<_>>>()?
+    } else if let Some(fields) = dataclass_fields(config)? {
+        fields
+    } else {
+        return Err(PyTypeError::new_err(format!(
+            "the config must be a RON string, dict, or dataclass, not {}",
+            config.get_type().name()?
+        )));
+    };
+
+    let mut ron_fields = Vec::with_capacity(fields.len() + 1);
+    let mut has_reporters = false;
+
+    for (name, value) in fields {
+        if name == "reporters" {
+            let mut reporters = value
+                .iter()?
+                .map(|reporter| to_ron(reporter?))
+                .collect::<PyResult<Vec<_>>>()?;
+            reporters.extend_from_slice(extra_reporters);
+
+            ron_fields.push(format!("reporters: [{}]", reporters.join(", ")));
+            has_reporters = true;
+        } else {
+            ron_fields.push(format!("{name}: {}", to_ron(value)?));
+        }
+    }
+
+    if !has_reporters {
+        ron_fields.push(format!("reporters: [{}]", extra_reporters.join(", ")));
+    }
+
+    Ok(format!("({})", ron_fields.join(", ")))
+}
+
+/// Converts a Python value into its RON representation:
+/// - `None`, `bool`, `int`, `float`, `str`, and path-like objects map to the
+///   corresponding RON values
+/// - `tuple`s map to RON tuples and `list`s to RON lists
+/// - `dict`s with identifier keys map to RON structs, and other `dict`s to RON
+///   maps
+/// - `dict`s with a single capitalised key, e.g. `{"Seed": 42}`, map to enum
+///   variants, e.g. `Seed(42)`
+/// - dataclasses map to structs, which are named after their class, and whose
+///   `None` fields are omitted
+/// - `enum.Enum` members map to unit variants, which are named after the member
+pub fn to_ron(value: &PyAny) -> PyResult<String> {
+    if value.is_none() {
+        return Ok(String::from("None"));
+    }
+
+    if let Ok(value) = value.downcast::<PyBool>() {
+        return Ok(value.is_true().to_string());
+    }
+
+    if value.is_instance_of::<PyInt>() {
+        return Ok(value.str()?.to_str()?.to_owned());
+    }
+
+    if let Ok(value) = value.downcast::<PyFloat>() {
+        return ron_literal(&value.value());
+    }
+
+    if let Ok(value) = value.downcast::<PyString>() {
+        return ron_literal(value.to_str()?);
+    }
+
+    if let Ok(value) = value.downcast::<PyTuple>() {
+        return Ok(format!("({})", values_to_ron(value.iter())?));
+    }
+
+    if let Ok(value) = value.downcast::<PyList>() {
+        return Ok(format!("[{}]", values_to_ron(value.iter())?));
+    }
+
+    if let Ok(value) = value.downcast::<PyDict>() {
+        return dict_to_ron(value);
+    }
+
+    if let Some(fields) = dataclass_fields(value)? {
+        return Ok(format!(
+            "{}({})",
+            value.get_type().name()?,
+            fields_to_ron(fields)?
+        ));
+    }
+
+    let py = value.py();
+
+    if value.is_instance(py.import("enum")?.getattr("Enum")?)? {
+        return value.getattr("name")?.extract();
+    }
+
+    if value.hasattr("__fspath__")? {
+        return ron_literal(&value.call_method0("__fspath__")?.extract::<String>()?);
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "cannot convert a {} into a config value",
+        value.get_type().name()?
+    )))
+}
+
+fn dict_to_ron(dict: &PyDict) -> PyResult<String> {
+    // A dict with a single capitalised key is an enum variant
+    if dict.len() == 1 {
+        if let Some((variant, value)) = dict.iter().next() {
+            if let Ok(variant) = variant.extract::<&str>() {
+                if variant.starts_with(|c: char| c.is_ascii_uppercase()) && is_identifier(variant) {
+                    return variant_to_ron(variant, value);
+                }
+            }
+        }
+    }
+
+    if let Some(fields) = identifier_fields(dict) {
+        return Ok(format!("({})", fields_to_ron(fields)?));
+    }
+
+    let entries = dict
+        .iter()
+        .map(|(key, value)| Ok(format!("{}: {}", to_ron(key)?, to_ron(value)?)))
+        .collect::<PyResult<Vec<_>>>()?;
+
+    Ok(format!("{{{}}}", entries.join(", ")))
+}
+
+fn variant_to_ron(variant: &str, value: &PyAny) -> PyResult<String> {
+    if value.is_none() {
+        return Ok(String::from(variant));
+    }
+
+    if let Ok(value) = value.downcast::<PyTuple>() {
+        return Ok(format!("{variant}({})", values_to_ron(value.iter())?));
+    }
+
+    if let Ok(value) = value.downcast::<PyDict>() {
+        if let Some(fields) = identifier_fields(value) {
+            return Ok(format!("{variant}({})", fields_to_ron(fields)?));
+        }
+    }
+
+    if let Some(fields) = dataclass_fields(value)? {
+        return Ok(format!("{variant}({})", fields_to_ron(fields)?));
+    }
+
+    Ok(format!("{variant}({})", to_ron(value)?))
+}
+
+fn identifier_fields(dict: &PyDict) -> Option<Vec<(String, &PyAny)>> {
+    let mut fields = Vec::with_capacity(dict.len());
+
+    for (key, value) in dict {
+        match key.extract::<String>() {
+            Ok(key) if is_identifier(&key) => fields.push((key, value)),
+            _ => return None,
+        }
+    }
+
+    Some(fields)
+}
+
+fn dataclass_fields(value: &PyAny) -> PyResult<Option<Vec<(String, &PyAny)>>> {
+    let dataclasses = value.py().import("dataclasses")?;
+
+    if value.is_instance_of::<pyo3::types::PyType>()
+        || !dataclasses
+            .call_method1("is_dataclass", (value,))?
+            .is_true()?
+    {
+        return Ok(None);
+    }
+
+    let mut fields = Vec::new();
+
+    for field in dataclasses.call_method1("fields", (value,))?.iter()? {
+        let name: String = field?.getattr("name")?.extract()?;
+        let field = value.getattr(name.as_str())?;
+
+        // Unset optional fields fall back to their config default
+        if !field.is_none() {
+            fields.push((name, field));
+        }
+    }
+
+    Ok(Some(fields))
+}
+
+fn fields_to_ron(fields: Vec<(String, &PyAny)>) -> PyResult<String> {
+    Ok(fields
+        .into_iter()
+        .map(|(name, value)| Ok(format!("{name}: {}", to_ron(value)?)))
+        .collect::<PyResult<Vec<_>>>()?
+        .join(", "))
+}
+
+fn values_to_ron<'py>(values: impl Iterator<Item = &'py PyAny>) -> PyResult<String> {
+    Ok(values.map(to_ron).collect::<PyResult<Vec<_>>>()?.join(", "))
+}
+
+fn ron_literal<T: serde::Serialize + ?Sized>(value: &T) -> PyResult<String> {
+    ron::to_string(value).map_err(|err| PyTypeError::new_err(err.to_string()))
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::{prelude::*, types::PyDict};
+
+    use super::{serialise, to_ron};
+
+    fn with_python<T>(code: &str, inner: impl FnOnce(&PyDict) -> T) -> T {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let globals = PyDict::new(py);
+            py.run(code, Some(globals), None).unwrap();
+            inner(globals)
+        })
+    }
+
+    fn convert(code: &str) -> String {
+        with_python(code, |globals| {
+            to_ron(globals.get_item("value").unwrap().unwrap()).unwrap()
+        })
+    }
+
+    #[test]
+    fn test_primitive_conversion() {
+        assert_eq!(convert("value = None"), "None");
+        assert_eq!(convert("value = True"), "true");
+        assert_eq!(convert("value = 42"), "42");
+        assert_eq!(convert("value = 0.5"), "0.5");
+        assert_eq!(
+            convert("value = 'a \"quoted\" \\\\ str'"),
+            r#""a \"quoted\" \\ str""#
+        );
+        assert_eq!(convert("value = (1, [2, 3])"), "(1, [2, 3])");
+    }
+
+    #[test]
+    fn test_dict_conversion() {
+        assert_eq!(
+            convert("value = {'area': (20, 20), 'deme': 10}"),
+            "(area: (20, 20), deme: 10)"
+        );
+        assert_eq!(
+            convert("value = {1: 'a', 'b c': 2}"),
+            r#"{1: "a", "b c": 2}"#
+        );
+        assert_eq!(convert("value = {'Seed': 42}"), "Seed(42)");
+        assert_eq!(convert("value = {'Monolithic': None}"), "Monolithic");
+        assert_eq!(
+            convert("value = {'NonSpatial': {'area': (2, 2), 'deme': 1}}"),
+            "NonSpatial(area: (2, 2), deme: 1)"
+        );
+        assert_eq!(convert("value = {'Pair': (1, 2)}"), "Pair(1, 2)");
+    }
+
+    #[test]
+    fn test_dataclass_conversion() {
+        let code = "
+from dataclasses import dataclass
+from typing import Optional
+
+@dataclass
+class Classical:
+    parallelism_mode: Optional[str] = None
+
+@dataclass
+class Percentage:
+    percentage: float
+
+value = {'algorithm': Classical(), 'sample': Percentage(0.2), 'Gillespie': Classical('Monolithic')}
+";
+
+        assert_eq!(
+            convert(code),
+            "(algorithm: Classical(), sample: Percentage(percentage: 0.2), Gillespie: \
+             Classical(parallelism_mode: \"Monolithic\"))"
+        );
+
+        assert_eq!(
+            convert(&format!("{code}\nvalue = {{'Gillespie': Percentage(0.5)}}")),
+            "Gillespie(percentage: 0.5)"
+        );
+
+        // Dataclass types themselves are not config values
+        assert!(with_python(code, |globals| {
+            to_ron(globals.get_item("Percentage").unwrap().unwrap()).is_err()
+        }));
+    }
+
+    #[test]
+    fn test_enum_conversion() {
+        let code = "
+import enum
+
+class Mode(enum.Enum):
+    Monolithic = 1
+    Lockstep = 2
+
+value = [Mode.Monolithic, {'mode': Mode.Lockstep}]
+";
+
+        assert_eq!(convert(code), "[Monolithic, (mode: Lockstep)]");
+    }
+
+    #[test]
+    fn test_path_conversion() {
+        assert_eq!(
+            convert("import pathlib\nvalue = pathlib.PurePosixPath('maps') / 'fg0size12.tif'"),
+            r#""maps/fg0size12.tif""#
+        );
+        assert!(with_python("value = object()", |globals| {
+            to_ron(globals.get_item("value").unwrap().unwrap()).is_err()
+        }));
+    }
+
+    #[test]
+    fn test_config_serialisation() {
+        let code = "value = {'speciation': 0.1, 'rng': {'Seed': 42}, 'reporters': [{'Builtin': \
+                    'Verbose'}]}";
+
+        let config = with_python(code, |globals| {
+            serialise(
+                globals.get_item("value").unwrap().unwrap(),
+                &[String::from("Python()")],
+            )
+            .unwrap()
+        });
+        assert_eq!(
+            config,
+            "(speciation: 0.1, rng: Seed(42), reporters: [Builtin(\"Verbose\"), Python()])"
+        );
+
+        let config = with_python("value = '(speciation: 0.1)'", |globals| {
+            serialise(globals.get_item("value").unwrap().unwrap(), &[]).unwrap()
+        });
+        assert_eq!(config, "(speciation: 0.1)");
+
+        assert!(with_python("value = '(speciation: 0.1)'", |globals| {
+            serialise(
+                globals.get_item("value").unwrap().unwrap(),
+                &[String::from("Python()")],
+            )
+            .is_err()
+        }));
+    }
+}
@@ -0,0 +1,220 @@
+#![deny(clippy::pedantic)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use log::LevelFilter;
+use pyo3::{exceptions::PyRuntimeError, prelude::*, types::PyModule};
+
+use necsim_core::lineage::Lineage as RustLineage;
+use necsim_plugins_python::event::{DispersalEvent, IndexedLocation, SpeciationEvent};
+
+use rustcoalescence::{Config, ConfigFormat, LogFormat, MinimalLogger, SimulationOutcome};
+
+mod config;
+
+static MINIMAL_LOGGER: MinimalLogger = MinimalLogger::new(LogFormat::Text);
+
+/// Name of the module in which the Python reporters of a run are registered,
+/// such that the built-in `Python` reporter can import them
+const REPORTER_REGISTRY: &str = "necsim._reporters";
+
+/// Python bindings to run `rustcoalescence` simulations and replays.
+#[pymodule]
+fn necsim(py: Python, module: &PyModule) -> PyResult<()> {
+    // Another logger may have already been installed by the host, whose
+    //  configured level is then left untouched
+    if log::set_logger(&MINIMAL_LOGGER).is_ok() {
+        log::set_max_level(LevelFilter::Info);
+    }
+
+    let registry = PyModule::new(py, REPORTER_REGISTRY)?;
+    py.import("sys")?
+        .getattr("modules")?
+        .set_item(REPORTER_REGISTRY, registry)?;
+    module.add("_reporters", registry)?;
+
+    module.add_class::<SimulationResult>()?;
+    module.add_class::<Lineage>()?;
+    module.add_class::<IndexedLocation>()?;
+    module.add_class::<SpeciationEvent>()?;
+    module.add_class::<DispersalEvent>()?;
+
+    module.add_function(wrap_pyfunction!(simulate, module)?)?;
+    module.add_function(wrap_pyfunction!(replay, module)?)?;
+
+    Ok(())
+}
+
+/// Runs a simulation, or only estimates its cost in a `dry_run`, in which
+/// case `None` is returned.
+///
+/// The `config` is either a RON string, or a dict or dataclass which mirrors
+/// the simulation config. The `reporters` are Python reporters which are
+/// appended to the config's reporters. Each is either a reporter object with
+/// any of the `report_speciation`, `report_dispersal`, `report_progress`, and
+/// `finalise` methods, or a callable, which is called with every speciation
+/// and dispersal event.
+#[pyfunction]
+#[pyo3(signature = (config, reporters = Vec::new(), dry_run = false))]
+fn simulate(
+    py: Python,
+    config: &PyAny,
+    reporters: Vec<&PyAny>,
+    dry_run: bool,
+) -> PyResult<Option<SimulationResult>> {
+    let reporters = PythonReporters::register(py, reporters)?;
+    let config = Config::new(
+        ConfigFormat::Ron,
+        config::serialise(config, &reporters.config())?,
+    );
+
+    let outcome = py
+        .allow_threads(|| rustcoalescence::simulate(&config, dry_run))
+        .map_err(|err| PyRuntimeError::new_err(format!("{err:?}")))?;
+
+    Ok(outcome.map(SimulationResult::from))
+}
+
+/// Replays an event log to its reporters.
+///
+/// The `config` and `reporters` are given in the same way as for `simulate`.
+#[pyfunction]
+#[pyo3(signature = (config, reporters = Vec::new()))]
+fn replay(py: Python, config: &PyAny, reporters: Vec<&PyAny>) -> PyResult<()> {
+    let reporters = PythonReporters::register(py, reporters)?;
+    let config = Config::new(
+        ConfigFormat::Ron,
+        config::serialise(config, &reporters.config())?,
+    );
+
+    py.allow_threads(|| rustcoalescence::replay(&config))
+        .map_err(|err| PyRuntimeError::new_err(format!("{err:?}")))
+}
+
+/// Python reporters, which are registered for the duration of a single run
+struct PythonReporters<'py> {
+    registry: &'py PyAny,
+    names: Vec<String>,
+}
+
+impl<'py> PythonReporters<'py> {
+    fn register(py: Python<'py>, reporters: Vec<&'py PyAny>) -> PyResult<Self> {
+        static NEXT_REPORTER_ID: AtomicUsize = AtomicUsize::new(0);
+
+        let mut registered = Self {
+            registry: py
+                .import("sys")?
+                .getattr("modules")?
+                .get_item(REPORTER_REGISTRY)?,
+            names: Vec::with_capacity(reporters.len()),
+        };
+
+        for reporter in reporters {
+            let name = format!(
+                "reporter_{}",
+                NEXT_REPORTER_ID.fetch_add(1, Ordering::Relaxed)
+            );
+
+            registered.registry.setattr(name.as_str(), reporter)?;
+            registered.names.push(name);
+        }
+
+        Ok(registered)
+    }
+
+    fn config(&self) -> Vec<String> {
+        self.names
+            .iter()
+            .map(|name| format!("Builtin(Python(reporter: \"{REPORTER_REGISTRY}:{name}\"))"))
+            .collect()
+    }
+}
+
+impl<'py> Drop for PythonReporters<'py> {
+    fn drop(&mut self) {
+        for name in &self.names {
+            std::mem::drop(self.registry.delattr(name.as_str()));
+        }
+    }
+}
+
+/// Result of a simulation, which has either finished or paused. The
+/// `lineages` which remained active are only given if the simulation paused.
+#[pyclass(frozen, get_all, module = "necsim")]
+struct SimulationResult {
+    time: f64,
+    steps: u64,
+    paused: bool,
+    lineages: Vec<Lineage>,
+}
+
+#[pymethods]
+impl SimulationResult {
+    fn __repr__(&self) -> String {
+        format!(
+            "SimulationResult(time={}, steps={}, paused={}, lineages=[{} lineages])",
+            self.time,
+            self.steps,
+            if self.paused { "True" } else { "False" },
+            self.lineages.len(),
+        )
+    }
+}
+
+impl From<SimulationOutcome> for SimulationResult {
+    fn from(outcome: SimulationOutcome) -> Self {
+        match outcome {
+            SimulationOutcome::Done { time, steps } => Self {
+                time: time.get(),
+                steps,
+                paused: false,
+                lineages: Vec::new(),
+            },
+            SimulationOutcome::Paused {
+                time,
+                steps,
+                lineages,
+            } => Self {
+                time: time.get(),
+                steps,
+                paused: true,
+                lineages: lineages.iter().map(Lineage::from).collect(),
+            },
+        }
+    }
+}
+
+/// Python view of a `necsim_core::lineage::Lineage`
+#[pyclass(frozen, get_all, module = "necsim")]
+#[derive(Clone)]
+struct Lineage {
+    global_reference: u64,
+    last_event_time: f64,
+    indexed_location: IndexedLocation,
+}
+
+#[pymethods]
+impl Lineage {
+    fn __repr__(&self) -> String {
+        format!(
+            "Lineage(global_reference={}, last_event_time={}, \
+             indexed_location=IndexedLocation(x={}, y={}, index={}))",
+            self.global_reference,
+            self.last_event_time,
+            self.indexed_location.x,
+            self.indexed_location.y,
+            self.indexed_location.index,
+        )
+    }
+}
+
+impl From<&RustLineage> for Lineage {
+    fn from(lineage: &RustLineage) -> Self {
+        Self {
+            // Safety: the raw lineage reference is only used as an identifier
+            global_reference: unsafe { lineage.global_reference.clone().into_inner() },
+            last_event_time: lineage.last_event_time.get(),
+            indexed_location: IndexedLocation::from(&lineage.indexed_location),
+        }
+    }
+}
@@ -32,7 +32,7 @@ pub struct AlmostInfiniteClark2DtDispersalScenario<M: MathsCore, G: RngCore<M>>
     speciation_probability: UniformSpeciationProbability,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[allow(clippy::module_name_repetitions)]
 #[serde(rename = "AlmostInfiniteClark2DtDispersal")]
 pub struct AlmostInfiniteClark2DtDispersalArguments {
@@ -32,7 +32,7 @@ pub struct AlmostInfiniteNormalDispersalScenario<M: MathsCore, G: RngCore<M>> {
     speciation_probability: UniformSpeciationProbability,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[allow(clippy::module_name_repetitions)]
 #[serde(rename = "AlmostInfiniteNormalDispersal")]
 pub struct AlmostInfiniteNormalDispersalArguments {
@@ -157,7 +157,7 @@ impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for SpatiallyExplicitTurnoverMa
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 #[serde(try_from = "SpatiallyExplicitTurnoverMapArgumentsRaw")]
 #[allow(clippy::module_name_repetitions)]
 pub struct SpatiallyExplicitTurnoverMapArguments {
@@ -156,7 +156,7 @@ impl<M: MathsCore, G: RngCore<M>> Scenario<M, G>
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 #[serde(try_from = "SpatiallyExplicitUniformTurnoverArgumentsRaw")]
 #[allow(clippy::module_name_repetitions)]
 pub struct SpatiallyExplicitUniformTurnoverArguments {
@@ -1,23 +1,228 @@
-use clap::Parser;
+use std::{num::NonZeroUsize, path::PathBuf};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::{
+    de::{self, IntoDeserializer},
+    Deserialize,
+};
+
+use necsim_core::lineage::GlobalLineageReference;
+
+use super::utils::parse::{Config, ConfigFormat};
 
 pub mod replay;
 
 #[derive(Debug, Parser)]
-pub enum RustcoalescenceArgs {
-    Simulate(CommandArgs),
+pub struct RustcoalescenceArgs {
+    /// Format in which log records are printed, where `json` prints every log
+    /// record and lifecycle milestone as one JSON object on stderr
+    #[arg(long, global = true, value_enum, default_value_t = LogFormat::Text)]
+    pub log_format: LogFormat,
+    #[command(subcommand)]
+    pub command: RustcoalescenceCommand,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum RustcoalescenceCommand {
+    Simulate(SimulateArgs),
     Replay(CommandArgs),
+    Info(InfoArgs),
+    Sweep(SweepArgs),
+    #[command(subcommand)]
+    Validate(ValidateArgs),
+    Schema(SchemaArgs),
+    #[command(subcommand)]
+    Log(LogArgs),
+}
+
+#[derive(Debug, Parser)]
+pub struct SimulateArgs {
+    /// Parse and build the scenario to estimate the cost of the simulation
+    /// without running it
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Path of a JSON file into which the outcome of the simulation, i.e.
+    /// whether it finished or paused, its time and its steps, is written
+    #[arg(long)]
+    pub outcome: Option<PathBuf>,
+    /// Path of a JSON file into which the provenance manifest of the
+    /// simulation, e.g. its normalised config, the software that ran it, its
+    /// rng states, and the hashes of its input files, is written
+    #[arg(long)]
+    pub manifest: Option<PathBuf>,
+    #[command(flatten)]
+    pub command: CommandArgs,
+}
+
+#[derive(Debug, Parser)]
+pub struct InfoArgs {
+    /// Reporter plugin libraries whose exported reporters should be listed,
+    /// given either by their path or by their name, e.g. `common`
+    pub plugins: Vec<PathBuf>,
+}
+
+#[derive(Debug, Parser)]
+pub struct SweepArgs {
+    /// Base simulate config file, whose `${name}` placeholders are replaced
+    /// by the values of the swept parameters, as well as by the `${run}` ID
+    /// and `${output}` directory of each run
+    #[arg(long)]
+    pub config: PathBuf,
+    /// File with the `Values`, `Range`, `Product`, or `Zip` sweep axes
+    #[arg(long)]
+    pub axes: PathBuf,
+    /// Empty directory into which the config, log, outcome, and manifest of
+    /// each run, as well as the `index.csv` of all runs, are written
+    #[arg(long, short)]
+    pub output: PathBuf,
+    /// Number of simulations which are run in parallel worker processes
+    #[arg(long, default_value = "1")]
+    pub workers: NonZeroUsize,
+}
+
+/// Check a config, including that the files it refers to can be loaded,
+/// without running it
+#[derive(Debug, Subcommand)]
+pub enum ValidateArgs {
+    /// Check a simulate config
+    Simulate(ValidateCommandArgs),
+    /// Check a replay config
+    Replay(ValidateCommandArgs),
+}
+
+#[derive(Debug, Parser)]
+pub struct ValidateCommandArgs {
+    /// Path of a new config file into which the normalised config is written,
+    /// whose format is detected from its extension, e.g. to convert a config
+    /// between formats without running it
+    #[arg(long)]
+    pub normalised: Option<PathBuf>,
+    #[command(flatten)]
+    pub command: CommandArgs,
+}
+
+#[derive(Debug, Parser)]
+pub struct SchemaArgs {
+    /// Config whose JSON Schema is printed
+    #[arg(value_enum)]
+    pub config: SchemaConfig,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum SchemaConfig {
+    Simulate,
+    Replay,
+}
+
+/// Tools to maintain and analyse event logs
+#[derive(Debug, Subcommand)]
+pub enum LogArgs {
+    /// Merge event log segments into few large sorted segments
+    Compact(LogCompactArgs),
+    /// Export the events of an event log into a columnar Apache Arrow IPC file
+    Export(LogExportArgs),
+    /// Summarise the segments of an event log and check them for problems
+    Inspect(LogInspectArgs),
+    /// Trace the ancestry of lineages through an event log
+    Trace(LogTraceArgs),
+}
+
+#[derive(Debug, Parser)]
+pub struct LogCompactArgs {
+    /// Glob patterns of the event log segments which should be compacted
+    #[arg(required = true)]
+    pub segments: Vec<String>,
+    /// Empty directory into which the compacted segments are written
+    #[arg(long, short)]
+    pub output: PathBuf,
+    /// Event capacity of each compacted segment
+    #[arg(long, default_value = "1000000")]
+    pub capacity: NonZeroUsize,
+}
+
+#[derive(Debug, Parser)]
+pub struct LogExportArgs {
+    /// Glob patterns of the event log segments which should be exported
+    #[arg(required = true)]
+    pub segments: Vec<String>,
+    /// Path of the new Arrow IPC file into which the events are written
+    #[arg(long, short)]
+    pub output: PathBuf,
+    /// Number of events in each record batch of the Arrow IPC file
+    #[arg(long, default_value = "65536")]
+    pub batch: NonZeroUsize,
+}
+
+#[derive(Debug, Parser)]
+pub struct LogInspectArgs {
+    /// Glob patterns of the event log segments which should be inspected
+    #[arg(required = true)]
+    pub segments: Vec<String>,
+    /// Also read all events to count them by type and to check that every
+    /// segment is sorted and matches its header
+    #[arg(long)]
+    pub scan: bool,
+}
+
+#[derive(Debug, Parser)]
+pub struct LogTraceArgs {
+    /// Glob patterns of the event log segments which should be traced through
+    #[arg(required = true)]
+    pub segments: Vec<String>,
+    /// Global reference of a lineage whose ancestry should be traced
+    #[arg(long = "lineage", required = true, value_parser = parse_lineage)]
+    pub lineages: Vec<GlobalLineageReference>,
+    /// Path of the new file into which the ancestry traces are written
+    #[arg(long, short)]
+    pub output: PathBuf,
+    /// Format of the output file, which is otherwise inferred from its
+    /// extension, i.e. GeoJSON for `.geojson` and `.json`, CSV otherwise
+    #[arg(long, value_enum)]
+    pub format: Option<LogTraceFormat>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum LogTraceFormat {
+    Csv,
+    Geojson,
+}
+
+fn parse_lineage(lineage: &str) -> Result<GlobalLineageReference, String> {
+    let lineage = lineage.parse::<u64>().map_err(|err| err.to_string())?;
+
+    GlobalLineageReference::deserialize(IntoDeserializer::<de::value::Error>::into_deserializer(
+        lineage,
+    ))
+    .map_err(|err| err.to_string())
 }
 
 #[derive(Debug, Parser)]
 pub struct CommandArgs {
+    /// Config file in RON, TOML, JSON, or YAML, whose format is detected from
+    /// its extension, which replaces the RON config arguments
+    #[arg(long, conflicts_with = "args")]
+    config: Option<PathBuf>,
+    /// Format in which the normalised config is printed
+    #[arg(long, value_enum, default_value = "ron")]
+    normalise_to: ConfigFormat,
+    /// RON config, which may be split across several arguments
     #[arg(allow_hyphen_values = true)]
     args: Vec<String>,
 }
 
 impl CommandArgs {
-    pub fn into_config_string(self) -> String {
-        let config = self.args.join(" ");
-        std::mem::drop(self);
-        config
+    pub fn into_config(self) -> anyhow::Result<Config> {
+        let config = match self.config {
+            Some(path) => Config::from_file(&path)?,
+            None => Config::new(ConfigFormat::Ron, self.args.join(" ")),
+        };
+
+        Ok(config.normalise_to(self.normalise_to))
     }
 }
@@ -2,7 +2,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 
 use necsim_impls_std::event_log::replay::EventLogReplay;
 
-use necsim_plugins_core::import::{AnyReporterPluginVec, ReporterPluginLibrary};
+use necsim_plugins_core::import::AnyReporterPluginVec;
 
 #[derive(Serialize, Debug)]
 #[serde(rename = "Replay")]
@@ -37,7 +37,7 @@ impl<'de> Deserialize<'de> for ReplayArgs {
 
         let event_log = raw.event_log;
         let mode = raw.mode;
-        let reporters = raw.reporters.into_iter().flatten().collect();
+        let reporters = raw.reporters;
 
         let (report_speciation, report_dispersal) = match &reporters {
             AnyReporterPluginVec::IgnoreSpeciationIgnoreDispersalIgnoreProgress(..)
@@ -75,23 +75,36 @@ impl<'de> Deserialize<'de> for ReplayArgs {
             Ok(())
         };
 
-        match (valid, mode) {
-            (Ok(()), _) => Ok(Self {
-                event_log,
-                mode,
-                reporters,
-            }),
-            (Err(error), ReplayMode::WarnOnly) => {
-                warn!("{}", error);
+        let provenance = match event_log.runs() {
+            [] | [_] => Ok(()),
+            runs => Err(format!(
+                "The event log mixes segments from {} different simulation runs: {}.",
+                runs.len(),
+                runs.iter()
+                    .map(|provenance| match provenance {
+                        Some(provenance) => provenance.run().to_string(),
+                        None => String::from("unknown"),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )),
+        };
 
-                Ok(Self {
-                    event_log,
-                    mode,
-                    reporters,
-                })
-            },
-            (Err(error), ReplayMode::Strict) => Err(serde::de::Error::custom(error)),
+        for error in [provenance, valid.map_err(String::from)]
+            .into_iter()
+            .filter_map(Result::err)
+        {
+            match mode {
+                ReplayMode::WarnOnly => warn!("{}", error),
+                ReplayMode::Strict => return Err(serde::de::Error::custom(error)),
+            }
         }
+
+        Ok(Self {
+            event_log,
+            mode,
+            reporters,
+        })
     }
 }
 
@@ -104,5 +117,6 @@ struct ReplayArgsRaw {
     event_log: EventLogReplay,
     #[serde(default)]
     mode: ReplayMode,
-    reporters: Vec<ReporterPluginLibrary>,
+    #[serde(deserialize_with = "crate::reporter::deserialize_reporters")]
+    reporters: AnyReporterPluginVec,
 }
@@ -31,11 +31,11 @@ pub enum Algorithm {
     Independent(
         #[serde(deserialize_state)] <rustcoalescence_algorithms_independent::IndependentAlgorithm as AlgorithmParamters>::Arguments,
     ),
+    Auto,
 }
 
 impl Serialize for Algorithm {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        #[allow(unreachable_patterns, clippy::single_match_else)]
         match self {
             #[cfg(feature = "gillespie-algorithms")]
             Self::Gillespie(args) => {
@@ -56,13 +56,7 @@ impl Serialize for Algorithm {
             Self::Independent(args) => {
                 serializer.serialize_newtype_variant(stringify!(Algorithm), 3, "Independent", args)
             },
-            _ => {
-                std::mem::drop(serializer);
-
-                Err(serde::ser::Error::custom(
-                    "rustcoalescence must be compiled to support at least one algorithm",
-                ))
-            },
+            Self::Auto => serializer.serialize_unit_variant(stringify!(Algorithm), 4, "Auto"),
         }
     }
 }
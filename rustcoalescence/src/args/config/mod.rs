@@ -4,3 +4,4 @@ pub mod pause;
 pub mod rng;
 pub mod sample;
 pub mod scenario;
+pub mod sweep;
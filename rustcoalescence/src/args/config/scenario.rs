@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Debug)]
@@ -22,6 +24,27 @@ pub enum Scenario {
     WrappingNoise(rustcoalescence_scenarios::wrapping_noise::WrappingNoiseArguments),
 }
 
+impl Scenario {
+    /// Returns the paths of the maps from which the scenario was loaded
+    #[must_use]
+    pub fn maps(&self) -> Vec<&Path> {
+        #[allow(unreachable_patterns, clippy::match_single_binding)]
+        match *self {
+            #[cfg(feature = "spatially-explicit-uniform-turnover-scenario")]
+            Self::SpatiallyExplicitUniformTurnover(ref args) => {
+                vec![args.habitat_path.as_path(), args.dispersal_path.as_path()]
+            },
+            #[cfg(feature = "spatially-explicit-turnover-map-scenario")]
+            Self::SpatiallyExplicitTurnoverMap(ref args) => vec![
+                args.habitat_path.as_path(),
+                args.dispersal_path.as_path(),
+                args.turnover_path.as_path(),
+            ],
+            _ => Vec::new(),
+        }
+    }
+}
+
 impl Serialize for Scenario {
     #[allow(unused_variables)]
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -0,0 +1,400 @@
+use std::fmt;
+
+use serde::Deserialize;
+
+/// Axes of a parameter sweep, which are expanded into the parameter values
+/// of every run
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum SweepAxes {
+    /// List of values of the parameter `name`
+    Values {
+        name: String,
+        values: Vec<SweepValue>,
+    },
+    /// Evenly spaced values `start`, `start + step`, ... of the parameter
+    /// `name`, up to and including `end`
+    Range {
+        name: String,
+        start: SweepValue,
+        end: SweepValue,
+        step: SweepValue,
+    },
+    /// All combinations of the values of the axes
+    Product(Vec<SweepAxes>),
+    /// Element-wise combination of the values of axes with the same length
+    Zip(Vec<SweepAxes>),
+}
+
+/// Value of a sweep parameter, which is substituted verbatim into the config
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum SweepValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+}
+
+impl fmt::Display for SweepValue {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Bool(value) => write!(fmt, "{value}"),
+            Self::Int(value) => write!(fmt, "{value}"),
+            // Debug formatting keeps the decimal point, e.g. `1.0`
+            Self::Float(value) => write!(fmt, "{value:?}"),
+            Self::Str(value) => fmt.write_str(value),
+        }
+    }
+}
+
+/// Parameter values of a single run, in the order of the axes
+#[allow(clippy::module_name_repetitions)]
+pub type SweepParameters = Vec<(String, SweepValue)>;
+
+impl SweepAxes {
+    /// Expands the axes into the parameter values of every run
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a range is empty or infinite, zipped axes differ
+    /// in length, or a parameter is swept by several axes.
+    pub fn expand(&self) -> Result<Vec<SweepParameters>, String> {
+        match self {
+            Self::Values { name, values } => Ok(values
+                .iter()
+                .map(|value| vec![(name.clone(), value.clone())])
+                .collect()),
+            Self::Range {
+                name,
+                start,
+                end,
+                step,
+            } => Ok(expand_range(name, start, end, step)?
+                .into_iter()
+                .map(|value| vec![(name.clone(), value)])
+                .collect()),
+            Self::Product(axes) => {
+                let mut runs = vec![Vec::new()];
+
+                for axis in axes {
+                    let values = axis.expand()?;
+
+                    runs = runs
+                        .iter()
+                        .flat_map(|run| {
+                            values.iter().map(move |value| {
+                                let mut run: SweepParameters = run.clone();
+                                run.extend(value.iter().cloned());
+                                run
+                            })
+                        })
+                        .collect();
+                }
+
+                check_unique(runs)
+            },
+            Self::Zip(axes) => {
+                let mut runs: Option<Vec<SweepParameters>> = None;
+
+                for axis in axes {
+                    let values = axis.expand()?;
+
+                    runs = Some(match runs {
+                        None => values,
+                        Some(runs) if runs.len() == values.len() => runs
+                            .into_iter()
+                            .zip(values)
+                            .map(|(mut run, value)| {
+                                run.extend(value);
+                                run
+                            })
+                            .collect(),
+                        Some(runs) => {
+                            return Err(format!(
+                                "Zip axes must have the same length, but have {} and {} values.",
+                                runs.len(),
+                                values.len()
+                            ))
+                        },
+                    });
+                }
+
+                check_unique(runs.unwrap_or_default())
+            },
+        }
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn expand_range(
+    name: &str,
+    start: &SweepValue,
+    end: &SweepValue,
+    step: &SweepValue,
+) -> Result<Vec<SweepValue>, String> {
+    let values = match (start, end, step) {
+        (SweepValue::Int(start), SweepValue::Int(end), SweepValue::Int(step)) => {
+            if *step == 0 || (end < start && *step > 0) || (end > start && *step < 0) {
+                return Err(format!(
+                    "The range of {name:?} from {start} to {end} by {step} is empty or infinite."
+                ));
+            }
+
+            // Checked steps end the range instead of overflowing past `end`
+            std::iter::successors(Some(*start), |value| value.checked_add(*step))
+                .take_while(|value| {
+                    if *step > 0 {
+                        value <= end
+                    } else {
+                        value >= end
+                    }
+                })
+                .map(SweepValue::Int)
+                .collect()
+        },
+        (start, end, step) => {
+            let (Some(start), Some(end), Some(step)) =
+                (as_float(start), as_float(end), as_float(step))
+            else {
+                return Err(format!("The range of {name:?} must only contain numbers."));
+            };
+
+            let steps = (end - start) / step;
+
+            if !steps.is_finite() || steps < 0.0 {
+                return Err(format!(
+                    "The range of {name:?} from {start} to {end} by {step} is empty or infinite."
+                ));
+            }
+
+            // Allow for rounding errors when reaching the end of the range
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let len = (steps + 1e-9).floor() as u64 + 1;
+
+            (0..len)
+                .map(|i| SweepValue::Float(start + (i as f64) * step))
+                .collect()
+        },
+    };
+
+    Ok(values)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn as_float(value: &SweepValue) -> Option<f64> {
+    match value {
+        SweepValue::Int(value) => Some(*value as f64),
+        SweepValue::Float(value) => Some(*value),
+        SweepValue::Bool(_) | SweepValue::Str(_) => None,
+    }
+}
+
+fn check_unique(runs: Vec<SweepParameters>) -> Result<Vec<SweepParameters>, String> {
+    if let Some(run) = runs.first() {
+        for (i, (name, _)) in run.iter().enumerate() {
+            if run[..i].iter().any(|(other, _)| other == name) {
+                return Err(format!("The parameter {name:?} is swept by several axes."));
+            }
+        }
+    }
+
+    Ok(runs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SweepAxes, SweepValue};
+
+    fn values(name: &str, values: &[SweepValue]) -> SweepAxes {
+        SweepAxes::Values {
+            name: String::from(name),
+            values: values.to_vec(),
+        }
+    }
+
+    fn range(name: &str, start: SweepValue, end: SweepValue, step: SweepValue) -> SweepAxes {
+        SweepAxes::Range {
+            name: String::from(name),
+            start,
+            end,
+            step,
+        }
+    }
+
+    fn expand_values(axes: &SweepAxes) -> Vec<Vec<SweepValue>> {
+        axes.expand()
+            .unwrap()
+            .into_iter()
+            .map(|run| run.into_iter().map(|(_, value)| value).collect())
+            .collect()
+    }
+
+    #[test]
+    fn products_combine_all_values() {
+        let axes = SweepAxes::Product(vec![
+            values("a", &[SweepValue::Int(1), SweepValue::Int(2)]),
+            values(
+                "b",
+                &[
+                    SweepValue::Str(String::from("x")),
+                    SweepValue::Str(String::from("y")),
+                ],
+            ),
+        ]);
+
+        let runs = axes.expand().unwrap();
+
+        assert_eq!(runs.len(), 4);
+        assert_eq!(
+            runs[1],
+            vec![
+                (String::from("a"), SweepValue::Int(1)),
+                (String::from("b"), SweepValue::Str(String::from("y"))),
+            ]
+        );
+        assert_eq!(runs[2][0].1, SweepValue::Int(2));
+        assert_eq!(runs[2][1].1, SweepValue::Str(String::from("x")));
+
+        assert_eq!(SweepAxes::Product(Vec::new()).expand().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn zips_combine_values_element_wise() {
+        let axes = SweepAxes::Zip(vec![
+            values("a", &[SweepValue::Int(1), SweepValue::Int(2)]),
+            range(
+                "b",
+                SweepValue::Float(0.5),
+                SweepValue::Float(1.0),
+                SweepValue::Float(0.5),
+            ),
+        ]);
+
+        assert_eq!(
+            expand_values(&axes),
+            vec![
+                vec![SweepValue::Int(1), SweepValue::Float(0.5)],
+                vec![SweepValue::Int(2), SweepValue::Float(1.0)],
+            ]
+        );
+
+        let mismatched = SweepAxes::Zip(vec![
+            values("a", &[SweepValue::Int(1), SweepValue::Int(2)]),
+            values("b", &[SweepValue::Bool(true)]),
+        ]);
+
+        assert!(mismatched.expand().is_err());
+    }
+
+    #[test]
+    fn parameters_must_be_swept_once() {
+        let axes = SweepAxes::Product(vec![
+            values("a", &[SweepValue::Int(1)]),
+            values("a", &[SweepValue::Int(2)]),
+        ]);
+
+        assert!(axes.expand().is_err());
+    }
+
+    #[test]
+    fn int_ranges_include_their_end() {
+        let int = SweepValue::Int;
+
+        assert_eq!(
+            expand_values(&range("a", int(0), int(10), int(5))),
+            vec![vec![int(0)], vec![int(5)], vec![int(10)]]
+        );
+        assert_eq!(
+            expand_values(&range("a", int(10), int(0), int(-4))),
+            vec![vec![int(10)], vec![int(6)], vec![int(2)]]
+        );
+        assert_eq!(
+            expand_values(&range("a", int(3), int(3), int(1))),
+            vec![vec![int(3)]]
+        );
+
+        assert!(range("a", int(0), int(10), int(0)).expand().is_err());
+        assert!(range("a", int(0), int(10), int(-1)).expand().is_err());
+    }
+
+    #[test]
+    fn int_ranges_do_not_overflow() {
+        let int = SweepValue::Int;
+
+        assert_eq!(
+            expand_values(&range("a", int(i64::MIN), int(i64::MAX), int(i64::MAX))),
+            vec![vec![int(i64::MIN)], vec![int(-1)], vec![int(i64::MAX - 1)]]
+        );
+        assert_eq!(
+            expand_values(&range("a", int(i64::MAX - 1), int(i64::MAX), int(5))),
+            vec![vec![int(i64::MAX - 1)]]
+        );
+        assert_eq!(
+            expand_values(&range("a", int(i64::MAX), int(i64::MIN), int(i64::MIN))),
+            vec![vec![int(i64::MAX)], vec![int(-1)]]
+        );
+    }
+
+    #[test]
+    fn float_ranges_include_their_end() {
+        let runs = expand_values(&range(
+            "a",
+            SweepValue::Float(0.0),
+            SweepValue::Float(1.0),
+            SweepValue::Float(0.1),
+        ));
+
+        assert_eq!(runs.len(), 11);
+        assert!(matches!(runs[10][0], SweepValue::Float(end) if (end - 1.0).abs() < 1e-9));
+
+        // Mixed integer and float bounds produce a float range
+        assert_eq!(
+            expand_values(&range(
+                "a",
+                SweepValue::Int(0),
+                SweepValue::Int(1),
+                SweepValue::Float(0.5)
+            )),
+            vec![
+                vec![SweepValue::Float(0.0)],
+                vec![SweepValue::Float(0.5)],
+                vec![SweepValue::Float(1.0)]
+            ]
+        );
+
+        assert!(range(
+            "a",
+            SweepValue::Float(1.0),
+            SweepValue::Float(0.0),
+            SweepValue::Float(0.5)
+        )
+        .expand()
+        .is_err());
+        assert!(range(
+            "a",
+            SweepValue::Float(0.0),
+            SweepValue::Float(1.0),
+            SweepValue::Float(0.0)
+        )
+        .expand()
+        .is_err());
+        assert!(range(
+            "a",
+            SweepValue::Int(0),
+            SweepValue::Str(String::from("1")),
+            SweepValue::Int(1)
+        )
+        .expand()
+        .is_err());
+    }
+
+    #[test]
+    fn floats_keep_their_decimal_point() {
+        assert_eq!(SweepValue::Float(1.0).to_string(), "1.0");
+        assert_eq!(SweepValue::Int(1).to_string(), "1");
+        assert_eq!(SweepValue::Bool(true).to_string(), "true");
+    }
+}
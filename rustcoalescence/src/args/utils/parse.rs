@@ -1,19 +1,130 @@
+use std::{fmt, fs, path::Path};
+
 use anyhow::{Context, Result};
+use clap::ValueEnum;
 use ron::{extensions::Extensions, ser::PrettyConfig, Options};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_state::DeserializeState;
 
+use super::ser::BufferingSerializeResult;
+
+/// Format of a `simulate` or `replay` config
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ConfigFormat {
+    Ron,
+    Toml,
+    Json,
+    Yaml,
+}
+
+impl ConfigFormat {
+    /// Detects the format of a config file from its extension
+    #[must_use]
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "ron" => Some(Self::Ron),
+            "toml" => Some(Self::Toml),
+            "json" => Some(Self::Json),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+
+    /// Canonical file extension of the format
+    #[must_use]
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Ron => "ron",
+            Self::Toml => "toml",
+            Self::Json => "json",
+            Self::Yaml => "yaml",
+        }
+    }
+}
+
+impl fmt::Display for ConfigFormat {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(match self {
+            Self::Ron => "RON",
+            Self::Toml => "TOML",
+            Self::Json => "JSON",
+            Self::Yaml => "YAML",
+        })
+    }
+}
+
+/// `simulate` or `replay` config, which is parsed in its `format` and printed
+/// in the `normalise_to` format once it has been normalised
+#[derive(Debug)]
+pub struct Config {
+    format: ConfigFormat,
+    source: String,
+    normalise_to: ConfigFormat,
+}
+
+impl Config {
+    #[must_use]
+    pub fn new(format: ConfigFormat, source: String) -> Self {
+        Self {
+            format,
+            source,
+            normalise_to: ConfigFormat::Ron,
+        }
+    }
+
+    /// Reads a config file, whose format is detected from its extension
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file has an unknown extension or cannot be
+    /// read.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let format = ConfigFormat::from_path(path).with_context(|| {
+            format!(
+                "The config file {path:?} must have a .ron, .toml, .json, .yaml, or .yml \
+                 extension."
+            )
+        })?;
+
+        let source = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read the config file {path:?}."))?;
+
+        Ok(Self::new(format, source))
+    }
+
+    #[must_use]
+    pub fn normalise_to(mut self, normalise_to: ConfigFormat) -> Self {
+        self.normalise_to = normalise_to;
+        self
+    }
+
+    #[must_use]
+    pub fn format(&self) -> ConfigFormat {
+        self.format
+    }
+
+    #[must_use]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    #[must_use]
+    pub fn normalised_format(&self) -> ConfigFormat {
+        self.normalise_to
+    }
+}
+
 #[allow(clippy::module_name_repetitions)]
-pub fn try_parse<'de, D: Deserialize<'de>>(subcommand: &str, ron_args: &'de str) -> Result<D> {
-    try_parse_inner(subcommand, ron_args, |de| D::deserialize(de))
+pub fn try_parse<'de, D: Deserialize<'de>>(subcommand: &str, config: &'de Config) -> Result<D> {
+    try_parse_inner(subcommand, config, Stateless)
 }
 
 pub fn try_parse_state<'de, D: DeserializeState<'de, Seed>, Seed: ?Sized>(
     subcommand: &str,
-    ron_args: &'de str,
+    config: &'de Config,
     seed: &mut Seed,
 ) -> Result<D> {
-    try_parse_inner(subcommand, ron_args, |de| D::deserialize_state(seed, de))
+    try_parse_inner(subcommand, config, Stateful(seed))
 }
 
 pub fn try_print<S: Serialize>(value: &S) -> Result<String> {
@@ -22,6 +133,23 @@ pub fn try_print<S: Serialize>(value: &S) -> Result<String> {
         .map_err(anyhow::Error::new)
 }
 
+/// Prints the `value` in the `format`, where RON output is equivalent to
+/// [`try_print`]
+///
+/// Since TOML has no unit value, units are printed as empty tables, which
+/// unit-like arguments, e.g. reporters without options, accept as well.
+pub fn try_print_as<S: Serialize>(value: &S, format: ConfigFormat) -> Result<String> {
+    match format {
+        ConfigFormat::Ron => try_print(value),
+        ConfigFormat::Toml => {
+            toml::to_string_pretty(&BufferingSerializeResult::from(value).with_units_as_tables())
+                .map_err(anyhow::Error::new)
+        },
+        ConfigFormat::Json => serde_json::to_string_pretty(value).map_err(anyhow::Error::new),
+        ConfigFormat::Yaml => serde_yaml::to_string(value).map_err(anyhow::Error::new),
+    }
+}
+
 fn ron_options() -> Options {
     Options::default()
         .with_default_extension(Extensions::IMPLICIT_SOME)
@@ -29,30 +157,106 @@ fn ron_options() -> Options {
         .with_default_extension(Extensions::UNWRAP_VARIANT_NEWTYPES)
 }
 
-fn try_parse_inner<
-    'de,
-    D,
-    F: FnOnce(
-        serde_path_to_error::Deserializer<&mut ron::Deserializer<'de>>,
-    ) -> Result<D, ron::error::Error>,
->(
+// Helper trait to deserialise a value from any of the config formats'
+//  deserializers, with or without state
+trait ParseWith<'de, D> {
+    fn parse_with<De: Deserializer<'de>>(self, deserializer: De) -> Result<D, De::Error>;
+}
+
+struct Stateless;
+
+impl<'de, D: Deserialize<'de>> ParseWith<'de, D> for Stateless {
+    fn parse_with<De: Deserializer<'de>>(self, deserializer: De) -> Result<D, De::Error> {
+        D::deserialize(deserializer)
+    }
+}
+
+struct Stateful<'s, Seed: ?Sized>(&'s mut Seed);
+
+impl<'de, 's, D: DeserializeState<'de, Seed>, Seed: ?Sized> ParseWith<'de, D>
+    for Stateful<'s, Seed>
+{
+    fn parse_with<De: Deserializer<'de>>(self, deserializer: De) -> Result<D, De::Error> {
+        D::deserialize_state(self.0, deserializer)
+    }
+}
+
+fn try_parse_inner<'de, D, P: ParseWith<'de, D>>(
     subcommand: &str,
-    ron_args: &'de str,
-    deserializer: F,
+    config: &'de Config,
+    parser: P,
 ) -> Result<D> {
-    let mut de_ron = ron::Deserializer::from_str_with_options(ron_args, ron_options())
-        .with_context(|| {
-            format!("Failed to create the {subcommand} subcommand argument parser.")
-        })?;
-
     let mut track = serde_path_to_error::Track::new();
-    let de = serde_path_to_error::Deserializer::new(&mut de_ron, &mut track);
 
-    match deserializer(de) {
+    // Parse errors are reported as their (line:column) position and message
+    let result = match config.format {
+        ConfigFormat::Ron => {
+            let mut de_ron =
+                ron::Deserializer::from_str_with_options(&config.source, ron_options())
+                    .with_context(|| {
+                        format!("Failed to create the {subcommand} subcommand argument parser.")
+                    })?;
+
+            parser
+                .parse_with(serde_path_to_error::Deserializer::new(
+                    &mut de_ron,
+                    &mut track,
+                ))
+                .map_err(|err| {
+                    let err = de_ron.span_error(err);
+                    (err.position.to_string(), err.code.to_string())
+                })
+        },
+        ConfigFormat::Toml => parser
+            .parse_with(serde_path_to_error::Deserializer::new(
+                toml::Deserializer::new(&config.source),
+                &mut track,
+            ))
+            .map_err(|err| {
+                let position = err.span().map_or_else(
+                    || String::from("?"),
+                    |span| {
+                        // toml only reports the byte span of the error
+                        let before = &config.source[..span.start];
+                        let line = before.matches('\n').count() + 1;
+                        let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+                        format!("{line}:{column}")
+                    },
+                );
+                (position, err.message().to_owned())
+            }),
+        ConfigFormat::Json => {
+            let mut de_json = serde_json::Deserializer::from_str(&config.source);
+
+            parser
+                .parse_with(serde_path_to_error::Deserializer::new(
+                    &mut de_json,
+                    &mut track,
+                ))
+                .map_err(|err| {
+                    let position = format!("{}:{}", err.line(), err.column());
+                    let message = strip_position(err.to_string(), err.line(), err.column());
+                    (position, message)
+                })
+        },
+        ConfigFormat::Yaml => parser
+            .parse_with(serde_path_to_error::Deserializer::new(
+                serde_yaml::Deserializer::from_str(&config.source),
+                &mut track,
+            ))
+            .map_err(|err| match err.location() {
+                Some(location) => (
+                    format!("{}:{}", location.line(), location.column()),
+                    strip_position(err.to_string(), location.line(), location.column()),
+                ),
+                None => (String::from("?"), err.to_string()),
+            }),
+    };
+
+    match result {
         Ok(args) => Ok(args),
-        Err(err) => {
+        Err((position, message)) => {
             let path = track.path();
-            let err = de_ron.span_error(err);
 
             Err(anyhow::anyhow!(
                 "{}{}{}{} @ ({}):\n{}",
@@ -60,10 +264,135 @@ fn try_parse_inner<
                 if path.iter().count() >= 1 { "." } else { "" },
                 path,
                 if path.iter().count() >= 1 { "" } else { "*" },
-                err.position,
-                err.code,
+                position,
+                message,
             ))
         },
     }
-    .with_context(|| format!("Failed to parse the {subcommand} subcommand arguments."))
+    .with_context(|| {
+        format!(
+            "Failed to parse the {subcommand} subcommand {} arguments.",
+            config.format
+        )
+    })
+}
+
+// JSON and YAML errors append their position to their message
+fn strip_position(message: String, line: usize, column: usize) -> String {
+    match message.strip_suffix(&format!(" at line {line} column {column}")) {
+        Some(message) => message.to_owned(),
+        None => message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::args::config::partitioning::Partitioning;
+
+    use super::{try_parse, try_print, try_print_as, Config, ConfigFormat};
+
+    const FORMATS: [ConfigFormat; 4] = [
+        ConfigFormat::Ron,
+        ConfigFormat::Toml,
+        ConfigFormat::Json,
+        ConfigFormat::Yaml,
+    ];
+
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Test {
+        name: String,
+        speciation: f64,
+        seed: Option<u64>,
+        limit: Option<u64>,
+        mode: Mode,
+        modes: Vec<Mode>,
+        origin: (u32, u32),
+        ratio: Ratio,
+        partitioning: Partitioning,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    enum Mode {
+        Unit,
+        Newtype(f64),
+        Struct { x: u32 },
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Ratio(f64);
+
+    fn example(format: ConfigFormat) -> Config {
+        let source = match format {
+            ConfigFormat::Ron => {
+                "Test(name: \"test\", speciation: 0.1, seed: None, limit: 5, mode: Unit, modes: \
+                 [Unit, Newtype(0.5), Struct(x: 3)], origin: (1, 2), ratio: 0.25, partitioning: \
+                 Monolithic())"
+            },
+            ConfigFormat::Toml => {
+                "name = \"test\"\nspeciation = 0.1\nlimit = 5\nmode = \"Unit\"\nmodes = [\"Unit\", \
+                 { Newtype = 0.5 }, { Struct = { x = 3 } }]\norigin = [1, 2]\nratio = \
+                 0.25\npartitioning = { Monolithic = {} }\n"
+            },
+            ConfigFormat::Json => {
+                "{\"name\": \"test\", \"speciation\": 0.1, \"seed\": null, \"limit\": 5, \"mode\": \
+                 \"Unit\", \"modes\": [\"Unit\", {\"Newtype\": 0.5}, {\"Struct\": {\"x\": 3}}], \
+                 \"origin\": [1, 2], \"ratio\": 0.25, \"partitioning\": {\"Monolithic\": null}}"
+            },
+            ConfigFormat::Yaml => {
+                "name: test\nspeciation: 0.1\nseed: null\nlimit: 5\nmode: Unit\nmodes: [Unit, \
+                 {Newtype: 0.5}, {Struct: {x: 3}}]\norigin: [1, 2]\nratio: 0.25\npartitioning: \
+                 {Monolithic: null}\n"
+            },
+        };
+
+        Config::new(format, String::from(source))
+    }
+
+    #[test]
+    fn config_formats_parse_identically() {
+        let expected =
+            try_print(&try_parse::<Test>("test", &example(ConfigFormat::Ron)).unwrap()).unwrap();
+
+        for format in FORMATS {
+            let parsed: Test = try_parse("test", &example(format))
+                .unwrap_or_else(|err| panic!("{format}: {err:?}"));
+
+            assert_eq!(try_print(&parsed).unwrap(), expected, "{format}");
+        }
+    }
+
+    #[test]
+    fn normalised_configs_reparse() {
+        let config = example(ConfigFormat::Ron);
+        let parsed: Test = try_parse("test", &config).unwrap();
+        let expected = try_print(&parsed).unwrap();
+
+        for format in FORMATS {
+            let normalised =
+                try_print_as(&parsed, format).unwrap_or_else(|err| panic!("{format}: {err:?}"));
+
+            let reparsed: Test = try_parse("test", &Config::new(format, normalised))
+                .unwrap_or_else(|err| panic!("{format}: {err:?}"));
+
+            assert_eq!(try_print(&reparsed).unwrap(), expected, "{format}");
+        }
+    }
+
+    #[test]
+    fn unit_arguments_are_rejected() {
+        for (format, source) in [
+            (ConfigFormat::Ron, "Monolithic(threads: 4)"),
+            (ConfigFormat::Toml, "Monolithic = { threads = 4 }"),
+            (ConfigFormat::Json, "{\"Monolithic\": {\"threads\": 4}}"),
+        ] {
+            assert!(
+                try_parse::<Partitioning>("test", &Config::new(format, String::from(source)))
+                    .is_err(),
+                "{format}"
+            );
+        }
+    }
 }
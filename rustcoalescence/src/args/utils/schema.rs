@@ -0,0 +1,915 @@
+use std::{collections::VecDeque, fmt};
+
+use serde::{
+    de::{self, value::StrDeserializer, DeserializeSeed, Visitor},
+    Deserialize, Deserializer,
+};
+use serde_json::{json, Map, Value};
+use serde_state::DeserializeState;
+
+/// Field name which no config type accepts, used to probe whether a struct
+/// denies unknown fields
+const UNKNOWN_FIELD: &str = "\u{0}unknown";
+
+/// Maximum nesting depth up to which the config types are traced
+const MAX_DEPTH: usize = 64;
+
+/// JSON Schema of a `simulate` or `replay` config, which is traced from the
+/// requests that the config types make to a deserializer
+///
+/// The config types are never constructed while they are traced, i.e. no
+/// maps, plugins, or other files are loaded or created.
+#[allow(clippy::module_name_repetitions)]
+pub struct ConfigSchema {
+    root: Struct,
+    definitions: Vec<Definition>,
+}
+
+impl ConfigSchema {
+    #[must_use]
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            root: Struct {
+                name,
+                key: None,
+                fields: Vec::new(),
+                deny_unknown_fields: false,
+            },
+            definitions: Vec::new(),
+        }
+    }
+
+    /// Traces the fields of the config struct `D`, which are merged into
+    /// the fields that have been traced before
+    pub fn trace<'de, D: Deserialize<'de>>(&mut self) {
+        self.trace_with(|tracer| D::deserialize(tracer).map(drop));
+    }
+
+    /// Traces the fields of the config struct `D`, which is deserialised
+    /// with the `seed` state
+    pub fn trace_state<'de, D: DeserializeState<'de, Seed>, Seed: ?Sized>(
+        &mut self,
+        seed: &mut Seed,
+    ) {
+        self.trace_with(|tracer| D::deserialize_state(seed, tracer).map(drop));
+    }
+
+    /// Renders the traced config as a JSON Schema document
+    #[must_use]
+    pub fn to_json(&self) -> Value {
+        let mut definitions = Map::new();
+
+        let mut schema = Map::new();
+        schema.insert(
+            String::from("$schema"),
+            json!("https://json-schema.org/draft/2020-12/schema"),
+        );
+
+        if let Value::Object(root) = render_struct(&self.root, &mut definitions) {
+            schema.extend(root);
+        }
+
+        if !definitions.is_empty() {
+            schema.insert(String::from("$defs"), Value::Object(definitions));
+        }
+
+        Value::Object(schema)
+    }
+
+    fn trace_with<F: FnMut(Tracer) -> Result<(), TraceError>>(&mut self, mut deserialize: F) {
+        let mut root = Shape::Untraced;
+
+        let mut state = TraceState {
+            path: Vec::new(),
+            queue: VecDeque::from([Vec::new()]),
+            definitions: std::mem::take(&mut self.definitions),
+        };
+
+        // Every pass re-traces the config from its root to reach one more shape
+        while let Some(path) = state.queue.pop_front() {
+            state.path = path;
+
+            std::mem::drop(deserialize(Tracer {
+                shape: &mut root,
+                depth: 0,
+                state: &mut state,
+            }));
+        }
+
+        self.definitions = state.definitions;
+
+        if let Shape::Struct(root) = root {
+            self.root.merge(root);
+        }
+    }
+}
+
+/// Shape of the value that a config type requests from a deserializer
+enum Shape {
+    /// The shape has not (yet) been reached by the tracer
+    Untraced,
+    /// The shape is only known once the value is deserialised
+    Any,
+    Bool,
+    Integer {
+        signed: bool,
+        bits: u32,
+    },
+    Number,
+    Char,
+    String,
+    Null,
+    Option(Box<Shape>),
+    Newtype(Box<Shape>),
+    Seq(Box<Shape>),
+    Tuple {
+        len: usize,
+        elements: Vec<Shape>,
+    },
+    Map(Box<Shape>),
+    Struct(Struct),
+    Enum {
+        name: &'static str,
+        key: Option<String>,
+        variants: Vec<(&'static str, Variant)>,
+    },
+    /// Reference to the definition of a named struct or enum
+    Ref(String),
+}
+
+impl Shape {
+    fn merge(&mut self, other: Self) {
+        match (self, other) {
+            (Self::Option(this), Self::Option(other))
+            | (Self::Newtype(this), Self::Newtype(other))
+            | (Self::Seq(this), Self::Seq(other)) => this.merge(*other),
+            (this, other) => {
+                if matches!(this, Self::Untraced | Self::Any) {
+                    *this = other;
+                }
+            },
+        }
+    }
+}
+
+struct Struct {
+    name: &'static str,
+    key: Option<String>,
+    fields: Vec<(&'static str, Shape)>,
+    deny_unknown_fields: bool,
+}
+
+impl Struct {
+    fn merge(&mut self, other: Self) {
+        for (name, shape) in other.fields {
+            match self.fields.iter_mut().find(|(field, _)| *field == name) {
+                // Fields which are ignored by one config struct may be traced
+                //  by another
+                Some((_, field)) => field.merge(shape),
+                None => self.fields.push((name, shape)),
+            }
+        }
+
+        self.deny_unknown_fields |= other.deny_unknown_fields;
+    }
+}
+
+enum Variant {
+    Untraced,
+    Unit,
+    Newtype(Shape),
+    Tuple { len: usize, elements: Vec<Shape> },
+    Struct(Struct),
+}
+
+/// Named struct or enum, which is defined once and then referenced
+struct Definition {
+    name: &'static str,
+    signature: &'static [&'static str],
+    key: String,
+}
+
+/// Step from a shape to one of its inner shapes
+#[derive(Clone, Copy)]
+enum Step {
+    Inner,
+    Element,
+    Value,
+    Field(usize),
+    Variant(usize),
+}
+
+struct TraceState {
+    path: Vec<Step>,
+    queue: VecDeque<Vec<Step>>,
+    definitions: Vec<Definition>,
+}
+
+impl TraceState {
+    fn enqueue(&mut self, depth: usize, steps: impl IntoIterator<Item = Step>) {
+        if depth >= MAX_DEPTH {
+            return;
+        }
+
+        for step in steps {
+            let mut path = self.path[..depth].to_vec();
+            path.push(step);
+            self.queue.push_back(path);
+        }
+    }
+
+    /// Returns the key of a new definition, or the reference to an existing
+    /// definition of the same named struct or enum
+    fn define(
+        &mut self,
+        depth: usize,
+        name: &'static str,
+        signature: &'static [&'static str],
+    ) -> Result<Option<String>, String> {
+        // The root config struct is always defined inline
+        if depth == 0 {
+            return Ok(None);
+        }
+
+        if let Some(definition) = self
+            .definitions
+            .iter()
+            .find(|definition| definition.name == name && definition.signature == signature)
+        {
+            return Err(definition.key.clone());
+        }
+
+        let homonyms = self
+            .definitions
+            .iter()
+            .filter(|definition| definition.name == name)
+            .count();
+
+        let key = if homonyms == 0 {
+            String::from(name)
+        } else {
+            format!("{name}{}", homonyms + 1)
+        };
+
+        self.definitions.push(Definition {
+            name,
+            signature,
+            key: key.clone(),
+        });
+
+        Ok(Some(key))
+    }
+}
+
+/// Deserializer which records the shape that is requested at the end of its
+/// path, and otherwise follows the path into the requested shape
+struct Tracer<'a> {
+    shape: &'a mut Shape,
+    depth: usize,
+    state: &'a mut TraceState,
+}
+
+#[derive(Debug)]
+enum TraceError {
+    Traced,
+    UnknownField,
+    Custom,
+}
+
+impl fmt::Display for TraceError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(match self {
+            Self::Traced => "traced config shape",
+            Self::UnknownField => "unknown config field",
+            Self::Custom => "invalid config shape",
+        })
+    }
+}
+
+impl std::error::Error for TraceError {}
+
+impl de::Error for TraceError {
+    fn custom<T: fmt::Display>(_msg: T) -> Self {
+        Self::Custom
+    }
+
+    fn unknown_field(_field: &str, _expected: &'static [&'static str]) -> Self {
+        Self::UnknownField
+    }
+}
+
+impl<'a> Tracer<'a> {
+    fn step(&self) -> Option<Step> {
+        self.state.path.get(self.depth).copied()
+    }
+
+    /// Records the `shape` and schedules the tracing of its inner shapes
+    fn record<T>(
+        self,
+        shape: Shape,
+        steps: impl IntoIterator<Item = Step>,
+    ) -> Result<T, TraceError> {
+        if matches!(self.shape, Shape::Untraced) {
+            *self.shape = shape;
+            self.state.enqueue(self.depth, steps);
+        }
+
+        Err(TraceError::Traced)
+    }
+
+    fn record_struct<'de, V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        let key = match self.state.define(self.depth, name, fields) {
+            Ok(key) => key,
+            Err(key) => return self.record(Shape::Ref(key), []),
+        };
+
+        let deny_unknown_fields = probe_unknown_field(visitor);
+
+        self.record(
+            Shape::Struct(Struct {
+                name,
+                key,
+                fields: fields
+                    .iter()
+                    .map(|field| (*field, Shape::Untraced))
+                    .collect(),
+                deny_unknown_fields,
+            }),
+            (0..fields.len()).map(Step::Field),
+        )
+    }
+
+    fn trace_tuple<'de, V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        match self.step() {
+            // Only the first element can be reached without deserialising
+            //  any values, a zero length is used by arbitrary length tuples
+            None => self.record(
+                Shape::Tuple {
+                    len,
+                    elements: (0..len.max(1)).map(|_| Shape::Untraced).collect(),
+                },
+                [Step::Element],
+            ),
+            Some(Step::Element) => match self.shape {
+                Shape::Tuple { elements, .. } => visitor.visit_seq(ElementAccess {
+                    tracer: Some(Tracer {
+                        shape: &mut elements[0],
+                        depth: self.depth + 1,
+                        state: self.state,
+                    }),
+                }),
+                _ => Err(TraceError::Traced),
+            },
+            Some(_) => Err(TraceError::Traced),
+        }
+    }
+}
+
+macro_rules! trace_primitive {
+    ($($method:ident => $shape:expr),*) => {
+        $(fn $method<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+            self.record($shape, [])
+        })*
+    };
+}
+
+impl<'a, 'de> Deserializer<'de> for Tracer<'a> {
+    type Error = TraceError;
+
+    trace_primitive! {
+        deserialize_any => Shape::Any, deserialize_ignored_any => Shape::Any,
+        deserialize_bool => Shape::Bool,
+        deserialize_i8 => Shape::Integer { signed: true, bits: 8 },
+        deserialize_i16 => Shape::Integer { signed: true, bits: 16 },
+        deserialize_i32 => Shape::Integer { signed: true, bits: 32 },
+        deserialize_i64 => Shape::Integer { signed: true, bits: 64 },
+        deserialize_i128 => Shape::Integer { signed: true, bits: 128 },
+        deserialize_u8 => Shape::Integer { signed: false, bits: 8 },
+        deserialize_u16 => Shape::Integer { signed: false, bits: 16 },
+        deserialize_u32 => Shape::Integer { signed: false, bits: 32 },
+        deserialize_u64 => Shape::Integer { signed: false, bits: 64 },
+        deserialize_u128 => Shape::Integer { signed: false, bits: 128 },
+        deserialize_f32 => Shape::Number, deserialize_f64 => Shape::Number,
+        deserialize_char => Shape::Char,
+        deserialize_str => Shape::String, deserialize_string => Shape::String,
+        deserialize_identifier => Shape::String,
+        deserialize_bytes => Shape::Any, deserialize_byte_buf => Shape::Any,
+        deserialize_unit => Shape::Null
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.record(Shape::Null, [])
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.step() {
+            None => self.record(Shape::Option(Box::new(Shape::Untraced)), [Step::Inner]),
+            Some(Step::Inner) => match self.shape {
+                Shape::Option(inner) => visitor.visit_some(Tracer {
+                    shape: inner,
+                    depth: self.depth + 1,
+                    state: self.state,
+                }),
+                _ => Err(TraceError::Traced),
+            },
+            Some(_) => Err(TraceError::Traced),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.step() {
+            None => self.record(Shape::Newtype(Box::new(Shape::Untraced)), [Step::Inner]),
+            Some(Step::Inner) => match self.shape {
+                Shape::Newtype(inner) => visitor.visit_newtype_struct(Tracer {
+                    shape: inner,
+                    depth: self.depth + 1,
+                    state: self.state,
+                }),
+                _ => Err(TraceError::Traced),
+            },
+            Some(_) => Err(TraceError::Traced),
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.step() {
+            None => self.record(Shape::Seq(Box::new(Shape::Untraced)), [Step::Element]),
+            Some(Step::Element) => match self.shape {
+                Shape::Seq(element) => visitor.visit_seq(ElementAccess {
+                    tracer: Some(Tracer {
+                        shape: element,
+                        depth: self.depth + 1,
+                        state: self.state,
+                    }),
+                }),
+                _ => Err(TraceError::Traced),
+            },
+            Some(_) => Err(TraceError::Traced),
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.trace_tuple(len, visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.trace_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.step() {
+            // Only maps with string keys can be traced
+            None => self.record(Shape::Map(Box::new(Shape::Untraced)), [Step::Value]),
+            Some(Step::Value) => match self.shape {
+                Shape::Map(value) => visitor.visit_map(EntryAccess {
+                    key: Some("key"),
+                    value: Some(Tracer {
+                        shape: value,
+                        depth: self.depth + 1,
+                        state: self.state,
+                    }),
+                }),
+                _ => Err(TraceError::Traced),
+            },
+            Some(_) => Err(TraceError::Traced),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.step() {
+            None => self.record_struct(name, fields, visitor),
+            Some(Step::Field(i)) => match self.shape {
+                Shape::Struct(Struct { fields, .. }) => {
+                    let (field, shape) = &mut fields[i];
+
+                    visitor.visit_map(EntryAccess {
+                        key: Some(*field),
+                        value: Some(Tracer {
+                            shape,
+                            depth: self.depth + 1,
+                            state: self.state,
+                        }),
+                    })
+                },
+                _ => Err(TraceError::Traced),
+            },
+            Some(_) => Err(TraceError::Traced),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.step() {
+            None => {
+                let key = match self.state.define(self.depth, name, variants) {
+                    Ok(key) => key,
+                    Err(key) => return self.record(Shape::Ref(key), []),
+                };
+
+                self.record(
+                    Shape::Enum {
+                        name,
+                        key,
+                        variants: variants
+                            .iter()
+                            .map(|variant| (*variant, Variant::Untraced))
+                            .collect(),
+                    },
+                    (0..variants.len()).map(Step::Variant),
+                )
+            },
+            Some(Step::Variant(i)) => match self.shape {
+                Shape::Enum { variants, .. } => {
+                    let (name, variant) = &mut variants[i];
+
+                    visitor.visit_enum(VariantTracer {
+                        name,
+                        variant,
+                        depth: self.depth + 1,
+                        state: self.state,
+                    })
+                },
+                _ => Err(TraceError::Traced),
+            },
+            Some(_) => Err(TraceError::Traced),
+        }
+    }
+}
+
+/// Probes whether the struct `visitor` rejects an unknown field
+fn probe_unknown_field<'de, V: Visitor<'de>>(visitor: V) -> bool {
+    matches!(
+        visitor.visit_map(EntryAccess {
+            key: Some(UNKNOWN_FIELD),
+            value: None,
+        }),
+        Err(TraceError::UnknownField)
+    )
+}
+
+/// Map access which yields a single entry with a known key, whose value is
+/// traced
+struct EntryAccess<'a> {
+    key: Option<&'static str>,
+    value: Option<Tracer<'a>>,
+}
+
+impl<'a, 'de> de::MapAccess<'de> for EntryAccess<'a> {
+    type Error = TraceError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        match self.key.take() {
+            Some(key) => seed
+                .deserialize(StrDeserializer::<TraceError>::new(key))
+                .map(Some),
+            // Ending the map early could construct a value from its defaults
+            None => Err(TraceError::Traced),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.value.take() {
+            Some(tracer) => seed.deserialize(tracer),
+            None => Err(TraceError::Traced),
+        }
+    }
+}
+
+/// Seq access which yields a single element, which is traced
+struct ElementAccess<'a> {
+    tracer: Option<Tracer<'a>>,
+}
+
+impl<'a, 'de> de::SeqAccess<'de> for ElementAccess<'a> {
+    type Error = TraceError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        match self.tracer.take() {
+            Some(tracer) => seed.deserialize(tracer).map(Some),
+            // Ending the seq early could construct a value from its elements
+            None => Err(TraceError::Traced),
+        }
+    }
+}
+
+/// Enum access which selects a known variant, whose content is traced
+struct VariantTracer<'a> {
+    name: &'static str,
+    variant: &'a mut Variant,
+    depth: usize,
+    state: &'a mut TraceState,
+}
+
+impl<'a> VariantTracer<'a> {
+    fn step(&self) -> Option<Step> {
+        self.state.path.get(self.depth).copied()
+    }
+
+    /// Records the `variant` and schedules the tracing of its inner shapes
+    fn record<T>(
+        self,
+        variant: Variant,
+        steps: impl IntoIterator<Item = Step>,
+    ) -> Result<T, TraceError> {
+        if matches!(self.variant, Variant::Untraced) {
+            *self.variant = variant;
+            self.state.enqueue(self.depth, steps);
+        }
+
+        Err(TraceError::Traced)
+    }
+}
+
+impl<'a, 'de> de::EnumAccess<'de> for VariantTracer<'a> {
+    type Error = TraceError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let variant = seed.deserialize(StrDeserializer::<TraceError>::new(self.name))?;
+
+        Ok((variant, self))
+    }
+}
+
+impl<'a, 'de> de::VariantAccess<'de> for VariantTracer<'a> {
+    type Error = TraceError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.record(Variant::Unit, [])
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        match self.step() {
+            None => self.record(Variant::Newtype(Shape::Untraced), [Step::Inner]),
+            Some(Step::Inner) => match self.variant {
+                Variant::Newtype(inner) => seed.deserialize(Tracer {
+                    shape: inner,
+                    depth: self.depth + 1,
+                    state: self.state,
+                }),
+                _ => Err(TraceError::Traced),
+            },
+            Some(_) => Err(TraceError::Traced),
+        }
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.step() {
+            None => self.record(
+                Variant::Tuple {
+                    len,
+                    elements: (0..len.max(1)).map(|_| Shape::Untraced).collect(),
+                },
+                [Step::Element],
+            ),
+            Some(Step::Element) => match self.variant {
+                Variant::Tuple { elements, .. } => visitor.visit_seq(ElementAccess {
+                    tracer: Some(Tracer {
+                        shape: &mut elements[0],
+                        depth: self.depth + 1,
+                        state: self.state,
+                    }),
+                }),
+                _ => Err(TraceError::Traced),
+            },
+            Some(_) => Err(TraceError::Traced),
+        }
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.step() {
+            None => {
+                let deny_unknown_fields = probe_unknown_field(visitor);
+                let name = self.name;
+
+                self.record(
+                    Variant::Struct(Struct {
+                        name,
+                        key: None,
+                        fields: fields
+                            .iter()
+                            .map(|field| (*field, Shape::Untraced))
+                            .collect(),
+                        deny_unknown_fields,
+                    }),
+                    (0..fields.len()).map(Step::Field),
+                )
+            },
+            Some(Step::Field(i)) => match self.variant {
+                Variant::Struct(Struct { fields, .. }) => {
+                    let (field, shape) = &mut fields[i];
+
+                    visitor.visit_map(EntryAccess {
+                        key: Some(*field),
+                        value: Some(Tracer {
+                            shape,
+                            depth: self.depth + 1,
+                            state: self.state,
+                        }),
+                    })
+                },
+                _ => Err(TraceError::Traced),
+            },
+            Some(_) => Err(TraceError::Traced),
+        }
+    }
+}
+
+fn render(shape: &Shape, definitions: &mut Map<String, Value>) -> Value {
+    match shape {
+        Shape::Untraced | Shape::Any => json!({}),
+        Shape::Bool => json!({ "type": "boolean" }),
+        Shape::Integer {
+            signed: false,
+            bits: 128,
+        } => json!({ "type": "integer", "minimum": 0 }),
+        Shape::Integer {
+            signed: true,
+            bits: 128,
+        } => json!({ "type": "integer" }),
+        Shape::Integer {
+            signed: false,
+            bits,
+        } => json!({ "type": "integer", "minimum": 0, "maximum": u64::MAX >> (64 - bits) }),
+        Shape::Integer { signed: true, bits } => json!({
+            "type": "integer",
+            "minimum": i64::MIN >> (64 - bits),
+            "maximum": i64::MAX >> (64 - bits),
+        }),
+        Shape::Number => json!({ "type": "number" }),
+        Shape::Char => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+        Shape::String => json!({ "type": "string" }),
+        Shape::Null => json!({ "type": "null" }),
+        Shape::Option(inner) => json!({
+            "anyOf": [{ "type": "null" }, render(inner, definitions)],
+        }),
+        Shape::Newtype(inner) => render(inner, definitions),
+        Shape::Seq(element) => json!({
+            "type": "array",
+            "items": render(element, definitions),
+        }),
+        Shape::Tuple { len, elements } => render_tuple(*len, elements, definitions),
+        Shape::Map(value) => json!({
+            "type": "object",
+            "additionalProperties": render(value, definitions),
+        }),
+        Shape::Struct(r#struct) => render_struct(r#struct, definitions),
+        Shape::Enum {
+            name,
+            key,
+            variants,
+        } => {
+            // Enums whose variants are only known at runtime, e.g. reporters
+            if variants.is_empty() {
+                return json!({});
+            }
+
+            let schema = json!({
+                "title": name,
+                "oneOf": variants
+                    .iter()
+                    .map(|(name, variant)| render_variant(name, variant, definitions))
+                    .collect::<Vec<_>>(),
+            });
+
+            define(key.as_deref(), schema, definitions)
+        },
+        Shape::Ref(key) => json!({ "$ref": format!("#/$defs/{key}") }),
+    }
+}
+
+fn render_tuple(len: usize, elements: &[Shape], definitions: &mut Map<String, Value>) -> Value {
+    if len == 0 {
+        return json!({
+            "type": "array",
+            "items": render(&elements[0], definitions),
+        });
+    }
+
+    json!({
+        "type": "array",
+        "prefixItems": elements
+            .iter()
+            .map(|element| render(element, definitions))
+            .collect::<Vec<_>>(),
+        "minItems": len,
+        "maxItems": len,
+    })
+}
+
+fn render_struct(r#struct: &Struct, definitions: &mut Map<String, Value>) -> Value {
+    let mut schema = json!({
+        "title": r#struct.name,
+        "type": "object",
+        "properties": r#struct
+            .fields
+            .iter()
+            .map(|(name, shape)| (String::from(*name), render(shape, definitions)))
+            .collect::<Map<_, _>>(),
+    });
+
+    if r#struct.deny_unknown_fields {
+        schema["additionalProperties"] = json!(false);
+    }
+
+    define(r#struct.key.as_deref(), schema, definitions)
+}
+
+fn render_variant(name: &str, variant: &Variant, definitions: &mut Map<String, Value>) -> Value {
+    let content = match variant {
+        Variant::Unit => return json!({ "const": name }),
+        // The variant could not be traced and may be either a unit or have
+        //  any content
+        Variant::Untraced => {
+            return json!({
+                "anyOf": [{ "const": name }, render_tagged(name, json!({}))],
+            })
+        },
+        Variant::Newtype(inner) => render(inner, definitions),
+        Variant::Tuple { len, elements } => render_tuple(*len, elements, definitions),
+        Variant::Struct(r#struct) => render_struct(r#struct, definitions),
+    };
+
+    render_tagged(name, content)
+}
+
+// Enum variants with content are externally tagged by their name
+fn render_tagged(name: &str, content: Value) -> Value {
+    let mut properties = Map::new();
+    properties.insert(String::from(name), content);
+
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": [name],
+        "additionalProperties": false,
+    })
+}
+
+fn define(key: Option<&str>, schema: Value, definitions: &mut Map<String, Value>) -> Value {
+    match key {
+        Some(key) => {
+            definitions.insert(String::from(key), schema);
+            json!({ "$ref": format!("#/$defs/{key}") })
+        },
+        None => schema,
+    }
+}
@@ -85,6 +85,122 @@ pub enum BufferingSerialize {
     },
 }
 
+impl BufferingSerialize {
+    /// Replaces all unit values with empty structs, since TOML has no unit
+    ///  value but can represent an empty table
+    #[must_use]
+    pub fn with_units_as_tables(self) -> Self {
+        fn all(values: Box<[BufferingSerialize]>) -> Box<[BufferingSerialize]> {
+            values
+                .into_vec()
+                .into_iter()
+                .map(BufferingSerialize::with_units_as_tables)
+                .collect()
+        }
+
+        fn fields(
+            fields: Box<[(&'static str, Option<BufferingSerialize>)]>,
+        ) -> Box<[(&'static str, Option<BufferingSerialize>)]> {
+            fields
+                .into_vec()
+                .into_iter()
+                .map(|(key, value)| (key, value.map(BufferingSerialize::with_units_as_tables)))
+                .collect()
+        }
+
+        match self {
+            Self::Unit => Self::Struct {
+                name: "",
+                len: 0,
+                fields: Box::new([]),
+            },
+            Self::UnitStruct { name } => Self::Struct {
+                name,
+                len: 0,
+                fields: Box::new([]),
+            },
+            Self::Some(value) => Self::Some(Box::new(value.with_units_as_tables())),
+            Self::NewtypeStruct { name, value } => Self::NewtypeStruct {
+                name,
+                value: Box::new(value.with_units_as_tables()),
+            },
+            Self::NewtypeVariant {
+                name,
+                variant_index,
+                variant,
+                value,
+            } => Self::NewtypeVariant {
+                name,
+                variant_index,
+                variant,
+                value: Box::new(value.with_units_as_tables()),
+            },
+            Self::Seq { len, elements } => Self::Seq {
+                len,
+                elements: all(elements),
+            },
+            Self::Tuple { len, fields } => Self::Tuple {
+                len,
+                fields: all(fields),
+            },
+            Self::TupleStruct { name, len, fields } => Self::TupleStruct {
+                name,
+                len,
+                fields: all(fields),
+            },
+            Self::TupleVariant {
+                name,
+                variant_index,
+                variant,
+                len,
+                fields,
+            } => Self::TupleVariant {
+                name,
+                variant_index,
+                variant,
+                len,
+                fields: all(fields),
+            },
+            Self::Map { len, entries } => Self::Map {
+                len,
+                entries: entries
+                    .into_vec()
+                    .into_iter()
+                    .map(|(key, value)| {
+                        (
+                            key.map(Self::with_units_as_tables),
+                            value.map(Self::with_units_as_tables),
+                        )
+                    })
+                    .collect(),
+            },
+            Self::Struct {
+                name,
+                len,
+                fields: struct_fields,
+            } => Self::Struct {
+                name,
+                len,
+                fields: fields(struct_fields),
+            },
+            Self::StructVariant {
+                name,
+                variant_index,
+                variant,
+                len,
+                fields: struct_fields,
+            } => Self::StructVariant {
+                name,
+                variant_index,
+                variant,
+                len,
+                fields: fields(struct_fields),
+            },
+            value => value,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct BufferingError(Box<str>);
 
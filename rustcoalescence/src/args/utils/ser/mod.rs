@@ -13,6 +13,15 @@ impl<S: Serialize> From<&S> for BufferingSerializeResult {
     }
 }
 
+impl BufferingSerializeResult {
+    /// Replaces all unit values with empty tables, which can be printed as
+    ///  TOML
+    #[must_use]
+    pub fn with_units_as_tables(self) -> Self {
+        Self(self.0.map(BufferingSerialize::with_units_as_tables))
+    }
+}
+
 impl Serialize for BufferingSerializeResult {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match &self.0 {
@@ -0,0 +1,356 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::BufWriter,
+    num::NonZeroUsize,
+    path::Path,
+};
+
+use anyhow::{Context, Result};
+
+use necsim_core::event::{PackedEvent, TypedEvent};
+use necsim_core_bond::PositiveF64;
+
+use necsim_impls_std::event_log::{
+    format,
+    replay::{filter::EventLogFilter, EventLogReplay},
+    EventLogHeader, EventLogProvenance,
+};
+
+use crate::{
+    args::cli::LogCompactArgs,
+    milestone::{self, Milestone},
+};
+
+/// In-memory buffering capacity of each input segment, which is kept small
+///  as compaction often merges thousands of segments
+// Safety: 4096 is non-zero
+const INPUT_SEGMENT_CAPACITY: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(4096) };
+
+#[derive(Default)]
+struct CompactionSummary {
+    input_segments: usize,
+    output_segments: usize,
+    read: u64,
+    duplicates: u64,
+    speciation: u64,
+    dispersal: u64,
+    min_time: Option<PositiveF64>,
+    max_time: Option<PositiveF64>,
+}
+
+struct CompactedLog<'a> {
+    directory: &'a Path,
+    with_speciation: bool,
+    with_dispersal: bool,
+    provenance: Option<EventLogProvenance>,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub(super) fn compact(compact_args: LogCompactArgs) -> Result<()> {
+    let summary = compact_segments(
+        &compact_args.segments,
+        &compact_args.output,
+        compact_args.capacity,
+    )?;
+
+    if milestone::banners_enabled() {
+        println!("\n{:=^80}\n", " Event Log Compaction ");
+        println!(
+            "Compacted {} segment(s) into {} segment(s) in {:?}.\n",
+            summary.input_segments, summary.output_segments, compact_args.output,
+        );
+        println!(" - events read:        {}", summary.read);
+        println!(" - duplicates removed: {}", summary.duplicates);
+        println!(" - speciation events:  {}", summary.speciation);
+        println!(" - dispersal events:   {}", summary.dispersal);
+        if let (Some(min_time), Some(max_time)) = (summary.min_time, summary.max_time) {
+            println!(" - time range:         {min_time} - {max_time}");
+        }
+        println!("\n{:=^80}\n", " Event Log Compaction ");
+    }
+
+    Milestone::LogCompacted {
+        output: &compact_args.output,
+        input_segments: summary.input_segments,
+        output_segments: summary.output_segments,
+        read: summary.read,
+        duplicates: summary.duplicates,
+        speciation: summary.speciation,
+        dispersal: summary.dispersal,
+        min_time: summary.min_time.map(PositiveF64::get),
+        max_time: summary.max_time.map(PositiveF64::get),
+    }
+    .report();
+
+    Ok(())
+}
+
+/// Merges the event log `segments` into sorted and deduplicated segments of
+/// at most `capacity` events each, which are written into the empty `output`
+/// directory together with their sidecar indices
+fn compact_segments(
+    segments: &[String],
+    output: &Path,
+    capacity: NonZeroUsize,
+) -> Result<CompactionSummary> {
+    let replay = EventLogReplay::try_from_globs(
+        segments.iter().map(String::as_str),
+        INPUT_SEGMENT_CAPACITY,
+        EventLogFilter::default(),
+    )
+    .context("Failed to open the event log segments.")?;
+
+    let provenance = match replay.runs() {
+        [provenance] => provenance.clone(),
+        runs => anyhow::bail!(
+            "The event log segments were recorded by {} different simulation runs, which cannot \
+             be compacted together.",
+            runs.len()
+        ),
+    };
+
+    fs::create_dir_all(output).with_context(|| format!("Failed to create {output:?}."))?;
+
+    anyhow::ensure!(
+        fs::read_dir(output)?.next().is_none(),
+        "{output:?} is not an empty directory."
+    );
+
+    if let Some(provenance) = &provenance {
+        provenance.write(output).with_context(|| {
+            format!("Failed to write the event log provenance into {output:?}.")
+        })?;
+    }
+
+    let log = CompactedLog {
+        directory: output,
+        with_speciation: replay.with_speciation(),
+        with_dispersal: replay.with_dispersal(),
+        provenance,
+    };
+
+    let mut summary = CompactionSummary {
+        input_segments: replay.segments().count(),
+        ..CompactionSummary::default()
+    };
+
+    let mut buffer = Vec::with_capacity(capacity.get().min(replay.length()));
+    // All events at the current event time, used to detect duplicates
+    let mut concurrent: Vec<PackedEvent> = Vec::new();
+
+    for event in replay {
+        let event = event.context("Failed to read the event log.")?;
+
+        summary.read += 1;
+
+        if concurrent
+            .last()
+            .map_or(false, |last| last.event_time() != event.event_time())
+        {
+            concurrent.clear();
+        }
+
+        if concurrent.contains(&event) {
+            summary.duplicates += 1;
+            continue;
+        }
+
+        concurrent.push(event.clone());
+
+        match TypedEvent::from(event.clone()) {
+            TypedEvent::Speciation(_) => summary.speciation += 1,
+            TypedEvent::Dispersal(_) => summary.dispersal += 1,
+        }
+
+        summary.min_time.get_or_insert(event.event_time());
+        summary.max_time = Some(event.event_time());
+
+        buffer.push(event);
+
+        if buffer.len() >= capacity.get() {
+            log.write_segment(summary.output_segments, &buffer)?;
+            summary.output_segments += 1;
+
+            buffer.clear();
+        }
+    }
+
+    if !buffer.is_empty() {
+        log.write_segment(summary.output_segments, &buffer)?;
+        summary.output_segments += 1;
+    }
+
+    Ok(summary)
+}
+
+impl<'a> CompactedLog<'a> {
+    fn write_segment(&self, index: usize, events: &[PackedEvent]) -> Result<()> {
+        let segment_path = self.directory.join(format!("{index}"));
+
+        let segment_file = OpenOptions::new()
+            .create_new(true)
+            .write(true)
+            .open(&segment_path)
+            .with_context(|| format!("Failed to create the segment {segment_path:?}."))?;
+        let mut buf_writer = BufWriter::new(segment_file);
+
+        let header = EventLogHeader::new(
+            events[0].event_time(),
+            events[events.len() - 1].event_time(),
+            events.len(),
+            self.with_speciation,
+            self.with_dispersal,
+            self.provenance.as_ref().map(EventLogProvenance::run),
+        );
+
+        let segment_index = format::write_segment(&mut buf_writer, &header, events)
+            .with_context(|| format!("Failed to write the segment {segment_path:?}."))?;
+
+        buf_writer
+            .into_inner()
+            .with_context(|| format!("Failed to write the segment {segment_path:?}."))?;
+
+        segment_index.write(&segment_path).with_context(|| {
+            format!("Failed to write the index of the segment {segment_path:?}.")
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs::{self, File},
+        io::BufWriter,
+        num::NonZeroUsize,
+        ops::Range,
+        path::Path,
+    };
+
+    use necsim_core::{
+        event::{PackedEvent, SpeciationEvent},
+        landscape::{IndexedLocation, Location},
+        lineage::GlobalLineageReference,
+    };
+    use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+    use necsim_impls_std::event_log::{
+        format,
+        index::SegmentIndex,
+        replay::{filter::EventLogFilter, EventLogReplay},
+        EventLogHeader,
+    };
+
+    use super::compact_segments;
+
+    /// Speciation events of lineage `i` at time `i + 1` for every `i` in
+    /// `range`
+    fn events(range: Range<u64>) -> Vec<PackedEvent> {
+        range
+            .map(|i| {
+                #[allow(clippy::cast_precision_loss)]
+                let time = (i + 1) as f64;
+
+                PackedEvent::from(SpeciationEvent {
+                    global_lineage_reference: unsafe { GlobalLineageReference::from_inner(i) },
+                    prior_time: NonNegativeF64::new(time - 1.0).unwrap(),
+                    event_time: PositiveF64::new(time).unwrap(),
+                    origin: IndexedLocation::new(Location::new(0, 0), 0),
+                })
+            })
+            .collect()
+    }
+
+    /// Writes an unindexed segment of sorted `events` to `path`
+    fn write(path: &Path, events: &[PackedEvent]) {
+        let header = EventLogHeader::new(
+            events[0].event_time(),
+            events[events.len() - 1].event_time(),
+            events.len(),
+            true,
+            false,
+            None,
+        );
+
+        format::write_segment(BufWriter::new(File::create(path).unwrap()), &header, events)
+            .unwrap();
+    }
+
+    fn replay(pattern: &Path, filter: &str) -> Vec<PackedEvent> {
+        EventLogReplay::try_from_globs(
+            [pattern.to_str().unwrap()],
+            NonZeroUsize::new(10).unwrap(),
+            ron::from_str::<EventLogFilter>(filter).unwrap(),
+        )
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap()
+    }
+
+    #[test]
+    fn compaction_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("input");
+        let output = dir.path().join("output");
+
+        fs::create_dir(&input).unwrap();
+        fs::create_dir(input.join("resumed")).unwrap();
+
+        // The events 50..100 are recorded twice, e.g. by a resumed simulation
+        write(&input.join("0"), &events(0..100));
+        write(&input.join("resumed").join("0"), &events(50..150));
+        write(&input.join("1"), &events(150..200));
+
+        let summary = compact_segments(
+            &[input.join("**").join("*").to_str().unwrap().to_owned()],
+            &output,
+            NonZeroUsize::new(30).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(summary.input_segments, 3);
+        assert_eq!(summary.output_segments, 7);
+        assert_eq!(summary.read, 250);
+        assert_eq!(summary.duplicates, 50);
+        assert_eq!(summary.speciation, 200);
+        assert_eq!(summary.dispersal, 0);
+        assert_eq!(summary.min_time, Some(PositiveF64::new(1.0).unwrap()));
+        assert_eq!(summary.max_time, Some(PositiveF64::new(200.0).unwrap()));
+
+        for segment in 0..summary.output_segments {
+            assert!(SegmentIndex::try_read(&output.join(format!("{segment}"))).is_some());
+        }
+
+        let mut deduplicated = replay(&input.join("**").join("*"), "()");
+        assert_eq!(deduplicated.len(), 250);
+        deduplicated.dedup();
+        assert_eq!(deduplicated, events(0..200));
+
+        // Filtered replays of the compacted log seek using its indices
+        for filter in [
+            "()",
+            "(after: Some(42.0))",
+            "(before: Some(123.5))",
+            "(after: Some(60.0), before: Some(90.0))",
+            "(lineages: Some([7, 77, 177]))",
+        ] {
+            let parsed: EventLogFilter = ron::from_str(filter).unwrap();
+            let expected: Vec<_> = deduplicated
+                .iter()
+                .filter(|event| parsed.contains(event))
+                .cloned()
+                .collect();
+
+            assert_eq!(replay(&output.join("*"), filter), expected, "{filter}");
+        }
+
+        // The output directory must be empty
+        assert!(compact_segments(
+            &[input.join("0").to_str().unwrap().to_owned()],
+            &output,
+            NonZeroUsize::new(30).unwrap(),
+        )
+        .is_err());
+    }
+}
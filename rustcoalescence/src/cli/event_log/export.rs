@@ -0,0 +1,235 @@
+use std::{
+    collections::BTreeMap,
+    io::{BufWriter, Write},
+    num::NonZeroUsize,
+};
+
+use anyhow::{Context, Result};
+use arrow2::{
+    array::{Array, PrimitiveArray, Utf8Array},
+    chunk::Chunk,
+    datatypes::{DataType, Field, Schema},
+    io::ipc::write::{FileWriter, WriteOptions},
+};
+
+use necsim_core::{
+    event::{PackedEvent, TypedEvent},
+    landscape::IndexedLocation,
+    lineage::{GlobalLineageReference, LineageInteraction},
+};
+
+use necsim_impls_std::event_log::replay::{filter::EventLogFilter, EventLogReplay};
+
+use crate::args::cli::LogExportArgs;
+
+/// In-memory buffering capacity of each input segment
+// Safety: 4096 is non-zero
+const INPUT_SEGMENT_CAPACITY: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(4096) };
+
+#[allow(clippy::needless_pass_by_value)]
+pub(super) fn export(export_args: LogExportArgs) -> Result<()> {
+    let replay = EventLogReplay::try_from_globs(
+        export_args.segments.iter().map(String::as_str),
+        INPUT_SEGMENT_CAPACITY,
+        EventLogFilter::default(),
+    )
+    .context("Failed to open the event log segments.")?;
+
+    let mut metadata = BTreeMap::new();
+    metadata.insert(
+        String::from("runs"),
+        replay
+            .runs()
+            .iter()
+            .map(|provenance| match provenance {
+                Some(provenance) => provenance.run().to_string(),
+                None => String::from("unknown"),
+            })
+            .collect::<Vec<_>>()
+            .join(","),
+    );
+    if let [Some(provenance)] = replay.runs() {
+        metadata.insert(String::from("config"), provenance.config().to_owned());
+    }
+
+    let file = super::create_output(&export_args.output)?;
+
+    let mut writer = FileWriter::try_new(
+        BufWriter::new(file.as_file()),
+        Schema {
+            fields: EventColumns::fields(),
+            metadata,
+        },
+        None,
+        WriteOptions { compression: None },
+    )
+    .context("Failed to start the Arrow IPC file.")?;
+
+    let mut columns = EventColumns::with_capacity(export_args.batch.get().min(replay.length()));
+    let mut batches = 0_usize;
+    let mut events = 0_u64;
+
+    for event in replay {
+        columns.push(event.context("Failed to read the event log.")?);
+        events += 1;
+
+        if columns.len() >= export_args.batch.get() {
+            writer
+                .write(&columns.take_chunk()?, None)
+                .context("Failed to write to the Arrow IPC file.")?;
+            batches += 1;
+        }
+    }
+
+    if columns.len() > 0 {
+        writer
+            .write(&columns.take_chunk()?, None)
+            .context("Failed to write to the Arrow IPC file.")?;
+        batches += 1;
+    }
+
+    writer
+        .finish()
+        .context("Failed to finish the Arrow IPC file.")?;
+    writer
+        .into_inner()
+        .flush()
+        .context("Failed to finish the Arrow IPC file.")?;
+
+    super::persist_output(file, &export_args.output)?;
+
+    info!(
+        "Exported {} event(s) in {} record batch(es) into {:?}.",
+        events, batches, export_args.output
+    );
+
+    Ok(())
+}
+
+/// Columnar buffers of events, where the target and parent columns are null
+/// for speciation events
+#[derive(Default)]
+struct EventColumns {
+    reference: Vec<u64>,
+    prior_time: Vec<f64>,
+    event_time: Vec<f64>,
+    origin_x: Vec<u32>,
+    origin_y: Vec<u32>,
+    origin_index: Vec<u32>,
+    target_x: Vec<Option<u32>>,
+    target_y: Vec<Option<u32>>,
+    target_index: Vec<Option<u32>>,
+    interaction: Vec<&'static str>,
+    parent: Vec<Option<u64>>,
+}
+
+impl EventColumns {
+    fn fields() -> Vec<Field> {
+        vec![
+            Field::new("reference", DataType::UInt64, false),
+            Field::new("prior_time", DataType::Float64, false),
+            Field::new("event_time", DataType::Float64, false),
+            Field::new("origin_x", DataType::UInt32, false),
+            Field::new("origin_y", DataType::UInt32, false),
+            Field::new("origin_index", DataType::UInt32, false),
+            Field::new("target_x", DataType::UInt32, true),
+            Field::new("target_y", DataType::UInt32, true),
+            Field::new("target_index", DataType::UInt32, true),
+            Field::new("interaction", DataType::Utf8, false),
+            Field::new("parent", DataType::UInt64, true),
+        ]
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            reference: Vec::with_capacity(capacity),
+            prior_time: Vec::with_capacity(capacity),
+            event_time: Vec::with_capacity(capacity),
+            origin_x: Vec::with_capacity(capacity),
+            origin_y: Vec::with_capacity(capacity),
+            origin_index: Vec::with_capacity(capacity),
+            target_x: Vec::with_capacity(capacity),
+            target_y: Vec::with_capacity(capacity),
+            target_index: Vec::with_capacity(capacity),
+            interaction: Vec::with_capacity(capacity),
+            parent: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.reference.len()
+    }
+
+    fn push(&mut self, event: PackedEvent) {
+        let (reference, prior_time, event_time, origin, target, interaction) =
+            match TypedEvent::from(event) {
+                TypedEvent::Speciation(speciation) => (
+                    speciation.global_lineage_reference,
+                    speciation.prior_time,
+                    speciation.event_time,
+                    speciation.origin,
+                    None,
+                    None,
+                ),
+                TypedEvent::Dispersal(dispersal) => (
+                    dispersal.global_lineage_reference,
+                    dispersal.prior_time,
+                    dispersal.event_time,
+                    dispersal.origin,
+                    Some(dispersal.target),
+                    Some(dispersal.interaction),
+                ),
+            };
+
+        self.reference.push(lineage_to_u64(reference));
+        self.prior_time.push(prior_time.get());
+        self.event_time.push(event_time.get());
+
+        self.origin_x.push(origin.location().x());
+        self.origin_y.push(origin.location().y());
+        self.origin_index.push(origin.index());
+
+        self.target_x
+            .push(target.as_ref().map(|target| target.location().x()));
+        self.target_y
+            .push(target.as_ref().map(|target| target.location().y()));
+        self.target_index
+            .push(target.as_ref().map(IndexedLocation::index));
+
+        self.interaction.push(match &interaction {
+            None => "speciation",
+            Some(LineageInteraction::None) => "none",
+            Some(LineageInteraction::Maybe) => "maybe",
+            Some(LineageInteraction::Coalescence(_)) => "coalescence",
+        });
+        self.parent.push(
+            interaction
+                .and_then(|interaction| interaction.parent())
+                .map(lineage_to_u64),
+        );
+    }
+
+    fn take_chunk(&mut self) -> Result<Chunk<Box<dyn Array>>> {
+        let columns = std::mem::take(self);
+
+        Chunk::try_new(vec![
+            PrimitiveArray::from_vec(columns.reference).boxed(),
+            PrimitiveArray::from_vec(columns.prior_time).boxed(),
+            PrimitiveArray::from_vec(columns.event_time).boxed(),
+            PrimitiveArray::from_vec(columns.origin_x).boxed(),
+            PrimitiveArray::from_vec(columns.origin_y).boxed(),
+            PrimitiveArray::from_vec(columns.origin_index).boxed(),
+            PrimitiveArray::from(columns.target_x).boxed(),
+            PrimitiveArray::from(columns.target_y).boxed(),
+            PrimitiveArray::from(columns.target_index).boxed(),
+            Utf8Array::<i32>::from_slice(columns.interaction).boxed(),
+            PrimitiveArray::from(columns.parent).boxed(),
+        ])
+        .context("Failed to assemble an Arrow record batch.")
+    }
+}
+
+fn lineage_to_u64(reference: GlobalLineageReference) -> u64 {
+    // Safety: the lineage reference is only exported, never turned back
+    unsafe { reference.into_inner() }
+}
@@ -0,0 +1,386 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use anyhow::{Context, Result};
+
+use necsim_core::event::{PackedEvent, TypedEvent};
+use necsim_core_bond::PositiveF64;
+
+use necsim_impls_std::event_log::{
+    format::{SegmentFormatError, SegmentReader},
+    replay::glob_segment_paths,
+    EventLogHeader, EventLogRunId,
+};
+
+use crate::{
+    args::cli::LogInspectArgs,
+    milestone::{self, Milestone},
+};
+
+/// Maximum number of overlapping segment pairs that are listed individually
+const MAX_LISTED_OVERLAPS: usize = 10;
+
+struct InspectedSegment {
+    path: PathBuf,
+    header: EventLogHeader,
+    scan: Option<SegmentScan>,
+}
+
+#[derive(Default)]
+struct SegmentScan {
+    speciation: u64,
+    dispersal: u64,
+    unsorted: bool,
+    outside_header: bool,
+    error: Option<SegmentFormatError>,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub(super) fn inspect(inspect_args: LogInspectArgs) -> Result<()> {
+    let mut paths = Vec::new();
+
+    for pattern in &inspect_args.segments {
+        paths.extend(
+            glob_segment_paths(pattern)
+                .with_context(|| format!("Failed to expand the glob pattern {pattern:?}."))?,
+        );
+    }
+
+    paths.sort();
+    paths.dedup();
+
+    let mut segments = Vec::with_capacity(paths.len());
+    let mut unreadable = Vec::new();
+    let mut formats = BTreeMap::new();
+
+    for path in paths {
+        match SegmentReader::try_new(&path) {
+            Ok((header, mut segment_reader)) => {
+                *formats
+                    .entry(segment_reader.format().to_string())
+                    .or_insert(0_usize) += 1;
+
+                let scan = if inspect_args.scan {
+                    Some(scan_segment(&header, &mut segment_reader))
+                } else {
+                    None
+                };
+
+                segments.push(InspectedSegment { path, header, scan });
+            },
+            Err(err) => unreadable.push((path, err)),
+        }
+    }
+
+    segments.sort_by(|a, b| {
+        a.header
+            .min_time()
+            .cmp(&b.header.min_time())
+            .then_with(|| a.path.cmp(&b.path))
+    });
+
+    let runs = simulation_runs(&segments);
+    let scan = inspect_args.scan.then(|| ScanTotals::new(&segments));
+    let overlaps = find_overlaps(&segments);
+
+    if milestone::banners_enabled() {
+        println!("\n{:=^80}\n", " Event Log Inspection ");
+
+        print_summary(&segments, &runs, &formats, unreadable.len());
+
+        if let Some(scan) = &scan {
+            print_scan(scan);
+        }
+
+        print_overlaps(&overlaps);
+
+        if !unreadable.is_empty() {
+            println!("\nUnreadable segments:");
+
+            for (path, err) in &unreadable {
+                println!(" - {path:?}: {err}");
+            }
+        }
+
+        println!("\n{:=^80}\n", " Event Log Inspection ");
+    }
+
+    Milestone::LogInspected {
+        segments: segments.len(),
+        events: segments.iter().map(|segment| segment.header.length()).sum(),
+        runs: runs
+            .iter()
+            .map(|run| run.map(|run| run.to_string()))
+            .collect(),
+        formats: &formats,
+        min_time: min_time(&segments).map(PositiveF64::get),
+        max_time: max_time(&segments).map(PositiveF64::get),
+        speciation_segments: segments
+            .iter()
+            .filter(|segment| segment.header.with_speciation())
+            .count(),
+        dispersal_segments: segments
+            .iter()
+            .filter(|segment| segment.header.with_dispersal())
+            .count(),
+        scanned_speciation: scan.as_ref().map(|scan| scan.speciation),
+        scanned_dispersal: scan.as_ref().map(|scan| scan.dispersal),
+        damaged: scan.as_ref().map_or(&[], |scan| scan.problems.as_slice()),
+        overlaps: overlaps.len(),
+        unreadable: unreadable
+            .iter()
+            .map(|(path, err)| format!("{path:?}: {err}"))
+            .collect(),
+    }
+    .report();
+
+    let damaged = scan.as_ref().map_or(0, |scan| scan.damaged);
+
+    anyhow::ensure!(
+        unreadable.is_empty() && damaged == 0,
+        "{} segment(s) of the event log are unreadable and {} segment(s) are out-of-order or \
+         damaged.",
+        unreadable.len(),
+        damaged,
+    );
+
+    Ok(())
+}
+
+fn scan_segment(header: &EventLogHeader, segment_reader: &mut SegmentReader) -> SegmentScan {
+    let mut scan = SegmentScan::default();
+    let mut previous: Option<PackedEvent> = None;
+
+    loop {
+        let event = match segment_reader.read_event() {
+            Ok(Some(event)) => event,
+            Ok(None) => break,
+            Err(err) => {
+                scan.error = Some(err);
+                break;
+            },
+        };
+
+        if previous
+            .as_ref()
+            .map_or(false, |previous| previous > &event)
+        {
+            scan.unsorted = true;
+        }
+
+        if event.event_time() < header.min_time() || event.event_time() > header.max_time() {
+            scan.outside_header = true;
+        }
+
+        match TypedEvent::from(event.clone()) {
+            TypedEvent::Speciation(_) => scan.speciation += 1,
+            TypedEvent::Dispersal(_) => scan.dispersal += 1,
+        }
+
+        previous = Some(event);
+    }
+
+    scan
+}
+
+/// Returns the distinct simulation runs that recorded the `segments`
+fn simulation_runs(segments: &[InspectedSegment]) -> Vec<Option<EventLogRunId>> {
+    let mut runs = Vec::new();
+
+    for segment in segments {
+        let run = segment.header.run();
+
+        if !runs.contains(&run) {
+            runs.push(run);
+        }
+    }
+
+    runs
+}
+
+fn min_time(segments: &[InspectedSegment]) -> Option<PositiveF64> {
+    segments
+        .iter()
+        .map(|segment| segment.header.min_time())
+        .min()
+}
+
+fn max_time(segments: &[InspectedSegment]) -> Option<PositiveF64> {
+    segments
+        .iter()
+        .map(|segment| segment.header.max_time())
+        .max()
+}
+
+fn print_summary(
+    segments: &[InspectedSegment],
+    runs: &[Option<EventLogRunId>],
+    formats: &BTreeMap<String, usize>,
+    unreadable: usize,
+) {
+    let events: usize = segments.iter().map(|segment| segment.header.length()).sum();
+
+    println!(
+        "Inspected {} segment(s) with {} event(s) from {} simulation run(s).\n",
+        segments.len(),
+        events,
+        runs.len(),
+    );
+
+    if unreadable > 0 {
+        println!(" - unreadable segments: {unreadable}");
+    }
+
+    println!(
+        " - segment formats:    {}",
+        formats
+            .iter()
+            .map(|(format, count)| format!("{format} x {count}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    println!(
+        " - simulation runs:    {}",
+        runs.iter()
+            .map(|run| run.map_or_else(|| String::from("unknown"), |run| run.to_string()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    if let (Some(min_time), Some(max_time)) = (min_time(segments), max_time(segments)) {
+        println!(" - time range:         {min_time} - {max_time}");
+    }
+
+    println!(
+        " - speciation events:  recorded in {} of {} segment(s)",
+        segments
+            .iter()
+            .filter(|segment| segment.header.with_speciation())
+            .count(),
+        segments.len(),
+    );
+    println!(
+        " - dispersal events:   recorded in {} of {} segment(s)",
+        segments
+            .iter()
+            .filter(|segment| segment.header.with_dispersal())
+            .count(),
+        segments.len(),
+    );
+}
+
+/// Scanned event counts and problems of all scanned segments
+struct ScanTotals {
+    speciation: u64,
+    dispersal: u64,
+    problems: Vec<String>,
+    /// Number of segments that are out-of-order or damaged
+    damaged: usize,
+}
+
+impl ScanTotals {
+    fn new(segments: &[InspectedSegment]) -> Self {
+        let scans = segments
+            .iter()
+            .filter_map(|segment| Some((&segment.path, segment.scan.as_ref()?)));
+
+        let mut totals = Self {
+            speciation: 0,
+            dispersal: 0,
+            problems: Vec::new(),
+            damaged: 0,
+        };
+
+        for (path, scan) in scans {
+            totals.speciation += scan.speciation;
+            totals.dispersal += scan.dispersal;
+
+            if scan.unsorted || scan.outside_header || scan.error.is_some() {
+                totals.damaged += 1;
+            }
+
+            if scan.unsorted {
+                totals
+                    .problems
+                    .push(format!("{path:?}: the events are not sorted"));
+            }
+            if scan.outside_header {
+                totals.problems.push(format!(
+                    "{path:?}: some events lie outside the time range of the header"
+                ));
+            }
+            if let Some(err) = &scan.error {
+                totals.problems.push(format!("{path:?}: {err}"));
+            }
+        }
+
+        totals
+    }
+}
+
+fn print_scan(scan: &ScanTotals) {
+    println!("\n - scanned speciation: {}", scan.speciation);
+    println!(" - scanned dispersal:  {}", scan.dispersal);
+
+    if scan.problems.is_empty() {
+        println!(" - all scanned segments are sorted and intact");
+    } else {
+        println!("\nOut-of-order or damaged segments:");
+
+        for problem in &scan.problems {
+            println!(" - {problem}");
+        }
+    }
+}
+
+/// Returns all pairs of a segment and an earlier segment that it overlaps
+///  with in time
+fn find_overlaps(segments: &[InspectedSegment]) -> Vec<(&InspectedSegment, &InspectedSegment)> {
+    let mut overlaps = Vec::new();
+
+    // Segments are sorted by their minimum time, so every segment that
+    //  overlaps with a later one must start before the latest end so far
+    let mut latest: Option<(&InspectedSegment, PositiveF64)> = None;
+
+    for segment in segments {
+        if let Some((previous, max_time)) = latest {
+            if segment.header.min_time() <= max_time {
+                overlaps.push((previous, segment));
+            }
+        }
+
+        if latest.map_or(true, |(_, max_time)| segment.header.max_time() > max_time) {
+            latest = Some((segment, segment.header.max_time()));
+        }
+    }
+
+    overlaps
+}
+
+fn print_overlaps(overlaps: &[(&InspectedSegment, &InspectedSegment)]) {
+    if overlaps.is_empty() {
+        println!("\n - no segments overlap in time");
+        return;
+    }
+
+    println!(
+        "\n{} segment(s) overlap in time with an earlier segment, which is expected for \
+         partitioned or resumed simulations:",
+        overlaps.len()
+    );
+
+    for (previous, segment) in overlaps.iter().take(MAX_LISTED_OVERLAPS) {
+        println!(
+            " - {:?} [{} - {}] overlaps {:?} [{} - {}]",
+            segment.path,
+            segment.header.min_time(),
+            segment.header.max_time(),
+            previous.path,
+            previous.header.min_time(),
+            previous.header.max_time(),
+        );
+    }
+
+    if overlaps.len() > MAX_LISTED_OVERLAPS {
+        println!(" - ... and {} more", overlaps.len() - MAX_LISTED_OVERLAPS);
+    }
+}
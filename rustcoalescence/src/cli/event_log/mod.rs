@@ -0,0 +1,54 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use log::LevelFilter;
+use tempfile::NamedTempFile;
+
+use crate::args::cli::LogArgs;
+
+mod compact;
+mod export;
+mod inspect;
+mod trace;
+
+/// # Errors
+///
+/// Returns an error if the event log cannot be processed.
+#[allow(clippy::module_name_repetitions)]
+pub fn event_log_with_logger(log_args: LogArgs) -> Result<()> {
+    log::set_max_level(LevelFilter::Info);
+
+    match log_args {
+        LogArgs::Compact(compact_args) => compact::compact(compact_args),
+        LogArgs::Export(export_args) => export::export(export_args),
+        LogArgs::Inspect(inspect_args) => inspect::inspect(inspect_args),
+        LogArgs::Trace(trace_args) => trace::trace(trace_args),
+    }
+}
+
+/// Creates a hidden temporary file next to the `output` path, such that a
+/// failed command does not leave a partially written `output` behind
+fn create_output(output: &Path) -> Result<NamedTempFile> {
+    anyhow::ensure!(!output.exists(), "{output:?} already exists.");
+
+    let directory = match output.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    tempfile::Builder::new()
+        .prefix(".")
+        .suffix(".tmp")
+        .tempfile_in(directory)
+        .with_context(|| format!("Failed to create {output:?}."))
+}
+
+/// Moves the completely written temporary file to the `output` path, which
+/// must still not exist
+fn persist_output(file: NamedTempFile, output: &Path) -> Result<()> {
+    file.persist_noclobber(output)
+        .map_err(|err| err.error)
+        .with_context(|| format!("Failed to create {output:?}."))?;
+
+    Ok(())
+}
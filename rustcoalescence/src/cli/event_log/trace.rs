@@ -0,0 +1,318 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    io::{BufWriter, Write},
+    num::NonZeroUsize,
+};
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+
+use necsim_core::{
+    event::{PackedEvent, TypedEvent},
+    landscape::IndexedLocation,
+    lineage::{GlobalLineageReference, LineageInteraction},
+};
+
+use necsim_impls_std::event_log::replay::{filter::EventLogFilter, EventLogReplay};
+
+use crate::{
+    args::cli::{LogTraceArgs, LogTraceFormat},
+    milestone::{self, Milestone},
+};
+
+/// In-memory buffering capacity of each input segment
+// Safety: 4096 is non-zero
+const INPUT_SEGMENT_CAPACITY: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(4096) };
+
+/// Ancestry trace of a single lineage, which is complete once the lineage or
+/// one of its ancestors has speciated
+#[derive(Default)]
+struct LineageTrace {
+    events: Vec<PackedEvent>,
+    complete: bool,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub(super) fn trace(trace_args: LogTraceArgs) -> Result<()> {
+    let replay = EventLogReplay::try_from_globs(
+        trace_args.segments.iter().map(String::as_str),
+        INPUT_SEGMENT_CAPACITY,
+        EventLogFilter::default(),
+    )
+    .context("Failed to open the event log segments.")?;
+
+    if !replay.with_dispersal() {
+        warn!("The event log does not contain dispersal events, only speciation is traced.");
+    }
+
+    let format = trace_args.format.unwrap_or_else(|| {
+        match trace_args
+            .output
+            .extension()
+            .and_then(|extension| extension.to_str())
+        {
+            Some("geojson" | "json") => LogTraceFormat::Geojson,
+            _ => LogTraceFormat::Csv,
+        }
+    });
+
+    let file = super::create_output(&trace_args.output)?;
+
+    let traces = trace_lineages(replay, &trace_args.lineages)?;
+
+    let mut missing = 0_usize;
+    let mut incomplete = 0_usize;
+
+    for (lineage, trace) in &traces {
+        if trace.events.is_empty() {
+            warn!("The lineage {lineage} does not occur in the event log.");
+            missing += 1;
+        } else if !trace.complete {
+            warn!("The ancestry of the lineage {lineage} ends before its speciation.");
+            incomplete += 1;
+        }
+    }
+
+    let mut writer = BufWriter::new(file.as_file());
+
+    match format {
+        LogTraceFormat::Csv => write_csv(&mut writer, &traces),
+        LogTraceFormat::Geojson => write_geojson(&mut writer, &traces),
+    }
+    .and_then(|()| writer.flush().map_err(anyhow::Error::from))
+    .with_context(|| format!("Failed to write the traces to {:?}.", trace_args.output))?;
+
+    std::mem::drop(writer);
+    super::persist_output(file, &trace_args.output)?;
+
+    if milestone::banners_enabled() {
+        info!(
+            "Traced the ancestry of {} lineage(s) into {:?}.",
+            traces.len(),
+            trace_args.output
+        );
+    }
+
+    Milestone::LogTraced {
+        output: &trace_args.output,
+        lineages: traces.len(),
+        missing,
+        incomplete,
+    }
+    .report();
+
+    Ok(())
+}
+
+fn trace_lineages(
+    replay: EventLogReplay,
+    lineages: &[GlobalLineageReference],
+) -> Result<BTreeMap<GlobalLineageReference, LineageTrace>> {
+    let mut traces: BTreeMap<GlobalLineageReference, LineageTrace> = lineages
+        .iter()
+        .map(|lineage| (lineage.clone(), LineageTrace::default()))
+        .collect();
+
+    // Current ancestor -> traced lineages that descend from it
+    let mut ancestors: HashMap<GlobalLineageReference, Vec<GlobalLineageReference>> = traces
+        .keys()
+        .map(|lineage| (lineage.clone(), vec![lineage.clone()]))
+        .collect();
+
+    // All events at the current event time, used to skip duplicates
+    let mut concurrent: Vec<PackedEvent> = Vec::new();
+
+    for event in replay {
+        let event = event.context("Failed to read the event log.")?;
+
+        if concurrent
+            .last()
+            .map_or(false, |last| last.event_time() != event.event_time())
+        {
+            concurrent.clear();
+        }
+
+        if concurrent.contains(&event) {
+            continue;
+        }
+
+        concurrent.push(event.clone());
+
+        let Some(descendants) = ancestors.remove(event.global_lineage_reference()) else {
+            continue;
+        };
+
+        for lineage in &descendants {
+            if let Some(trace) = traces.get_mut(lineage) {
+                trace.events.push(event.clone());
+            }
+        }
+
+        match TypedEvent::from(event) {
+            TypedEvent::Speciation(_) => {
+                for lineage in &descendants {
+                    if let Some(trace) = traces.get_mut(lineage) {
+                        trace.complete = true;
+                    }
+                }
+            },
+            TypedEvent::Dispersal(dispersal) => {
+                let ancestor = dispersal
+                    .interaction
+                    .parent()
+                    .unwrap_or(dispersal.global_lineage_reference);
+
+                ancestors.entry(ancestor).or_default().extend(descendants);
+            },
+        }
+
+        if ancestors.is_empty() {
+            break;
+        }
+    }
+
+    Ok(traces)
+}
+
+fn write_csv(
+    writer: &mut BufWriter<&File>,
+    traces: &BTreeMap<GlobalLineageReference, LineageTrace>,
+) -> Result<()> {
+    writeln!(
+        writer,
+        "lineage,ancestor,kind,prior_time,event_time,origin_x,origin_y,origin_index,target_x,\
+         target_y,target_index,parent"
+    )?;
+
+    for (lineage, trace) in traces {
+        for event in &trace.events {
+            let (ancestor, kind, prior_time, event_time, origin, target, parent) =
+                match TypedEvent::from(event.clone()) {
+                    TypedEvent::Speciation(speciation) => (
+                        speciation.global_lineage_reference,
+                        "speciation",
+                        speciation.prior_time.get(),
+                        speciation.event_time.get(),
+                        speciation.origin.clone(),
+                        speciation.origin,
+                        None,
+                    ),
+                    TypedEvent::Dispersal(dispersal) => (
+                        dispersal.global_lineage_reference,
+                        event_kind(&dispersal.interaction),
+                        dispersal.prior_time.get(),
+                        dispersal.event_time.get(),
+                        dispersal.origin,
+                        dispersal.target,
+                        dispersal.interaction.parent(),
+                    ),
+                };
+
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{},{},{},{},{},{}",
+                lineage,
+                ancestor,
+                kind,
+                prior_time,
+                event_time,
+                origin.location().x(),
+                origin.location().y(),
+                origin.index(),
+                target.location().x(),
+                target.location().y(),
+                target.index(),
+                parent.map_or_else(String::new, |parent| parent.to_string()),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes every traced lineage as a `LineString` trajectory feature through
+/// the landscape's `(x, y)` cell coordinates, followed by one `Point` feature
+/// per event on the trajectory
+fn write_geojson(
+    writer: &mut BufWriter<&File>,
+    traces: &BTreeMap<GlobalLineageReference, LineageTrace>,
+) -> Result<()> {
+    let mut features = Vec::new();
+
+    for (lineage, trace) in traces {
+        let mut trajectory = Vec::with_capacity(trace.events.len() + 1);
+        let mut points = Vec::with_capacity(trace.events.len());
+
+        for event in &trace.events {
+            let (ancestor, kind, event_time, origin, target, parent) =
+                match TypedEvent::from(event.clone()) {
+                    TypedEvent::Speciation(speciation) => (
+                        speciation.global_lineage_reference,
+                        "speciation",
+                        speciation.event_time.get(),
+                        speciation.origin.clone(),
+                        speciation.origin,
+                        None,
+                    ),
+                    TypedEvent::Dispersal(dispersal) => (
+                        dispersal.global_lineage_reference,
+                        event_kind(&dispersal.interaction),
+                        dispersal.event_time.get(),
+                        dispersal.origin,
+                        dispersal.target,
+                        dispersal.interaction.parent(),
+                    ),
+                };
+
+            if trajectory.is_empty() {
+                trajectory.push(coordinates(&origin));
+            }
+            trajectory.push(coordinates(&target));
+
+            points.push(json!({
+                "type": "Feature",
+                "geometry": { "type": "Point", "coordinates": coordinates(&target) },
+                "properties": {
+                    "lineage": lineage.to_string(),
+                    "ancestor": ancestor.to_string(),
+                    "kind": kind,
+                    "event_time": event_time,
+                    "index": target.index(),
+                    "parent": parent.map(|parent| parent.to_string()),
+                },
+            }));
+        }
+
+        features.push(json!({
+            "type": "Feature",
+            "geometry": { "type": "LineString", "coordinates": trajectory },
+            "properties": {
+                "lineage": lineage.to_string(),
+                "events": trace.events.len(),
+                "complete": trace.complete,
+            },
+        }));
+        features.append(&mut points);
+    }
+
+    serde_json::to_writer_pretty(
+        &mut *writer,
+        &json!({ "type": "FeatureCollection", "features": features }),
+    )?;
+    writeln!(writer)?;
+
+    Ok(())
+}
+
+fn event_kind(interaction: &LineageInteraction) -> &'static str {
+    if interaction.is_coalescence() {
+        "coalescence"
+    } else {
+        "dispersal"
+    }
+}
+
+fn coordinates(location: &IndexedLocation) -> Value {
+    json!([location.location().x(), location.location().y()])
+}
@@ -0,0 +1,271 @@
+use anyhow::{Context, Result};
+use log::LevelFilter;
+
+use necsim_plugins_core::{
+    describe::ReporterPluginDescription,
+    import::{plugin_search_path, resolve_plugin_library, ReporterPluginLibraryDescription},
+};
+
+use crate::{args::cli::InfoArgs, milestone, reporter::BUILTIN_REPORTER_PLUGINS};
+
+/// Lists the cargo features alongside whether they are enabled, which a test
+/// checks against the features declared in `Cargo.toml`
+macro_rules! features {
+    ($($feature:tt),* $(,)?) => {
+        [$(($feature, cfg!(feature = $feature))),*]
+    };
+}
+
+pub(super) const FEATURES: [(&str, bool); 20] = features![
+    "gillespie-algorithms",
+    "independent-algorithm",
+    "cuda-algorithm",
+    "almost-infinite-normal-dispersal-scenario",
+    "almost-infinite-clark2dt-dispersal-scenario",
+    "non-spatial-scenario",
+    "spatially-explicit-uniform-turnover-scenario",
+    "spatially-explicit-turnover-map-scenario",
+    "spatially-implicit-scenario",
+    "wrapping-noise-scenario",
+    "mpi-partitioning",
+    "common-reporters",
+    "statistics-reporters",
+    "species-reporters",
+    "csv-reporters",
+    "tskit-reporters",
+    "metacommunity-reporters",
+    "json-reporters",
+    "python-reporters",
+    "python-extension-reporters",
+];
+
+const SCENARIOS: [(&str, bool); 7] = [
+    (
+        "SpatiallyExplicit(turnover: Uniform)",
+        cfg!(feature = "spatially-explicit-uniform-turnover-scenario"),
+    ),
+    (
+        "SpatiallyExplicit(turnover: Map)",
+        cfg!(feature = "spatially-explicit-turnover-map-scenario"),
+    ),
+    ("NonSpatial", cfg!(feature = "non-spatial-scenario")),
+    (
+        "SpatiallyImplicit",
+        cfg!(feature = "spatially-implicit-scenario"),
+    ),
+    (
+        "AlmostInfinite(dispersal: Normal)",
+        cfg!(feature = "almost-infinite-normal-dispersal-scenario"),
+    ),
+    (
+        "AlmostInfinite(dispersal: Clark2Dt)",
+        cfg!(feature = "almost-infinite-clark2dt-dispersal-scenario"),
+    ),
+    ("WrappingNoise", cfg!(feature = "wrapping-noise-scenario")),
+];
+
+const ALGORITHMS: [(&str, bool); 5] = [
+    ("Gillespie", cfg!(feature = "gillespie-algorithms")),
+    ("EventSkipping", cfg!(feature = "gillespie-algorithms")),
+    ("Independent", cfg!(feature = "independent-algorithm")),
+    ("CUDA", cfg!(feature = "cuda-algorithm")),
+    (
+        "Auto",
+        cfg!(any(
+            feature = "gillespie-algorithms",
+            feature = "independent-algorithm"
+        )),
+    ),
+];
+
+const PARTITIONINGS: [(&str, bool); 2] = [
+    ("Monolithic", true),
+    ("MPI", cfg!(feature = "mpi-partitioning")),
+];
+
+/// # Errors
+///
+/// Returns an error if the plugin libraries cannot be loaded.
+#[allow(clippy::module_name_repetitions)]
+pub fn info_with_logger(info_args: InfoArgs) -> Result<()> {
+    log::set_max_level(LevelFilter::Info);
+
+    let plugins = info_args
+        .plugins
+        .into_iter()
+        .map(|plugin| {
+            // Plugins can also be given by name, e.g. `common`
+            let plugin = match plugin.to_str() {
+                Some(name) if !plugin.exists() && plugin.components().count() == 1 => {
+                    resolve_plugin_library(name).with_context(|| {
+                        format!("Failed to find the reporter plugin library {name}.")
+                    })?
+                },
+                _ => plugin,
+            };
+
+            ReporterPluginLibraryDescription::try_load(plugin.clone()).with_context(|| {
+                format!(
+                    "Failed to load the reporter plugin library {}.",
+                    plugin.display()
+                )
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    for plugin in &plugins {
+        if plugin.reporters().is_empty() {
+            warn!(
+                "The reporter plugin library {} does not export any reporters.",
+                plugin.path().display()
+            );
+        }
+    }
+
+    // The plain-text info is replaced by a single JSON object with
+    //  `--log-format json`
+    if milestone::milestones_enabled() {
+        println!("{}", info_json(&plugins));
+    } else {
+        print_info(&plugins);
+    }
+
+    Ok(())
+}
+
+fn print_info(plugins: &[ReporterPluginLibraryDescription]) {
+    println!("\n{:=^80}\n", " rustcoalescence ");
+    println!("rustcoalescence version: {}", env!("CARGO_PKG_VERSION"));
+    println!(
+        "reporter plugin system version: {}",
+        necsim_plugins_core::CORE_VERSION
+    );
+    println!(
+        "reporter plugin rustc version: {}",
+        necsim_plugins_core::RUSTC_VERSION
+    );
+    println!(
+        "reporter plugin C ABI version: {}",
+        necsim_plugins_core::ffi::NECSIM_FFI_REPORTER_PLUGIN_ABI_VERSION
+    );
+
+    println!("\nreporter plugin search path:");
+    for directory in plugin_search_path() {
+        println!(" - {}", directory.display());
+    }
+
+    print_enabled("cargo features", &FEATURES);
+    print_enabled("scenarios", &SCENARIOS);
+    print_enabled("algorithms", &ALGORITHMS);
+    print_enabled("partitionings", &PARTITIONINGS);
+    println!("\n{:=^80}\n", " rustcoalescence ");
+
+    let builtin_reporters = BUILTIN_REPORTER_PLUGINS.describe();
+
+    if !builtin_reporters.is_empty() {
+        println!("{:=^80}\n", " Built-in Reporters ");
+        println!("usage: Builtin(Reporter(...), ...)");
+
+        for reporter in &builtin_reporters {
+            println!("\n - {reporter}");
+            println!("   reports: {}", reporter.filter);
+        }
+
+        println!("\n{:=^80}\n", " Built-in Reporters ");
+    }
+
+    for plugin in plugins {
+        println!("{:=^80}\n", " Reporter Plugin ");
+        println!("library: {}", plugin.path().display());
+
+        for reporter in plugin.reporters() {
+            println!("\n - {reporter}");
+            println!("   reports: {}", reporter.filter);
+        }
+
+        println!("\n{:=^80}\n", " Reporter Plugin ");
+    }
+}
+
+fn print_enabled(category: &str, options: &[(&str, bool)]) {
+    println!("\n{category}:");
+
+    for (option, enabled) in options {
+        println!(" [{}] {}", if *enabled { "x" } else { " " }, option);
+    }
+}
+
+fn info_json(plugins: &[ReporterPluginLibraryDescription]) -> serde_json::Value {
+    fn enabled(options: &[(&str, bool)]) -> serde_json::Value {
+        options
+            .iter()
+            .map(|(option, enabled)| (String::from(*option), serde_json::Value::from(*enabled)))
+            .collect::<serde_json::Map<_, _>>()
+            .into()
+    }
+
+    fn reporters(reporters: &[ReporterPluginDescription]) -> serde_json::Value {
+        reporters
+            .iter()
+            .map(|reporter| {
+                serde_json::json!({
+                    "reporter": reporter.to_string(),
+                    "reports": reporter.filter.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    serde_json::json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "plugin_system_version": necsim_plugins_core::CORE_VERSION,
+        "plugin_rustc_version": necsim_plugins_core::RUSTC_VERSION,
+        "plugin_abi_version": necsim_plugins_core::ffi::NECSIM_FFI_REPORTER_PLUGIN_ABI_VERSION,
+        "plugin_search_path": plugin_search_path()
+            .iter()
+            .map(|directory| directory.display().to_string())
+            .collect::<Vec<_>>(),
+        "cargo_features": enabled(&FEATURES),
+        "scenarios": enabled(&SCENARIOS),
+        "algorithms": enabled(&ALGORITHMS),
+        "partitionings": enabled(&PARTITIONINGS),
+        "builtin_reporters": reporters(&BUILTIN_REPORTER_PLUGINS.describe()),
+        "plugins": plugins
+            .iter()
+            .map(|plugin| {
+                serde_json::json!({
+                    "library": plugin.path().display().to_string(),
+                    "reporters": reporters(plugin.reporters()),
+                })
+            })
+            .collect::<Vec<_>>(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FEATURES;
+
+    #[test]
+    fn features_match_cargo_manifest() {
+        let manifest: toml::Table = toml::from_str(include_str!("../../Cargo.toml")).unwrap();
+
+        // The default and all-* features only enable other features
+        let mut declared = manifest["features"]
+            .as_table()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .filter(|feature| *feature != "default" && !feature.starts_with("all-"))
+            .collect::<Vec<_>>();
+        declared.sort_unstable();
+
+        let mut listed = FEATURES
+            .iter()
+            .map(|(feature, _enabled)| *feature)
+            .collect::<Vec<_>>();
+        listed.sort_unstable();
+
+        assert_eq!(listed, declared);
+    }
+}
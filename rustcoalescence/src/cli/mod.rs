@@ -1,2 +1,7 @@
+pub mod event_log;
+pub mod info;
 pub mod replay;
+pub mod schema;
 pub mod simulate;
+pub mod sweep;
+pub mod validate;
@@ -5,23 +5,50 @@ use necsim_core::{event::TypedEvent, reporter::Reporter};
 
 use necsim_plugins_core::match_any_reporter_plugin_vec;
 
-use crate::args::{
-    cli::{replay::ReplayArgs, CommandArgs},
-    utils::parse::{try_parse, try_print},
+use crate::{
+    args::{
+        cli::{replay::ReplayArgs, CommandArgs},
+        utils::{
+            parse::{try_parse, try_print_as, Config},
+            schema::ConfigSchema,
+        },
+    },
+    milestone::{self, Milestone},
 };
 
+/// # Errors
+///
+/// Returns an error if the replay arguments are invalid, or the event log
+/// cannot be read.
 #[allow(clippy::module_name_repetitions)]
 pub fn replay_with_logger(replay_args: CommandArgs) -> Result<()> {
     log::set_max_level(LevelFilter::Info);
 
-    let replay_args: ReplayArgs = try_parse("replay", &replay_args.into_config_string())?;
-
-    let config_str =
-        try_print(&replay_args).context("Failed to normalise the event replay config.")?;
+    replay(&replay_args.into_config()?)
+}
 
-    println!("\n{:=^80}\n", " Replay Configuration ");
-    println!("{}", config_str.trim_start_matches("Replay"));
-    println!("\n{:=^80}\n", " Replay Configuration ");
+/// Replays the event log described by the `config` to its reporters.
+///
+/// # Errors
+///
+/// Returns an error if the replay arguments are invalid, or the event log
+/// cannot be read.
+pub fn replay(config: &Config) -> Result<()> {
+    let replay_args: ReplayArgs = try_parse("replay", config)?;
+
+    if milestone::banners_enabled() {
+        let config_str = try_print_as(&replay_args, config.normalised_format())
+            .context("Failed to normalise the event replay config.")?;
+
+        println!("\n{:=^80}\n", " Replay Configuration ");
+        println!("{}", config_str.trim_start_matches("Replay"));
+        println!("\n{:=^80}\n", " Replay Configuration ");
+    } else if milestone::milestones_enabled() {
+        let config = serde_json::to_value(&replay_args)
+            .context("Failed to normalise the event replay config.")?;
+
+        Milestone::ReplayStarted { config: &config }.report();
+    }
 
     info!("Starting event replay ...");
 
@@ -33,6 +60,8 @@ pub fn replay_with_logger(replay_args: CommandArgs) -> Result<()> {
         reporter.report_progress(&remaining.into());
 
         for event in replay_args.event_log {
+            let event = event.context("Failed to read the event log.")?;
+
             remaining -= 1;
             reporter.report_progress(&remaining.into());
 
@@ -46,13 +75,18 @@ pub fn replay_with_logger(replay_args: CommandArgs) -> Result<()> {
             }
         }
 
-        if log::log_enabled!(log::Level::Info) {
+        // Events which were skipped by the filter are never reported
+        if remaining > 0 {
+            reporter.report_progress(&0_u64.into());
+        }
+
+        if milestone::banners_enabled() {
             println!("\n");
             println!("{:=^80}", " Reporter Summary ");
             println!();
         }
         reporter.finalise();
-        if log::log_enabled!(log::Level::Info) {
+        if milestone::banners_enabled() {
             println!();
             println!("{:=^80}", " Reporter Summary ");
             println!();
@@ -63,3 +97,28 @@ pub fn replay_with_logger(replay_args: CommandArgs) -> Result<()> {
 
     Ok(())
 }
+
+/// Checks the `replay` config, including that its event log segments and
+/// reporter plugin libraries can be loaded, without replaying any events.
+///
+/// Returns the normalised config, printed in its normalised format.
+///
+/// # Errors
+///
+/// Returns an error if the replay arguments are invalid.
+pub fn validate_replay(config: &Config) -> Result<String> {
+    let replay_args: ReplayArgs = try_parse("replay", config)?;
+
+    try_print_as(&replay_args, config.normalised_format())
+        .context("Failed to normalise the event replay config.")
+}
+
+/// Traces the JSON Schema of the `replay` config from its config types.
+#[must_use]
+pub fn replay_schema() -> serde_json::Value {
+    let mut schema = ConfigSchema::new("Replay");
+
+    schema.trace::<ReplayArgs>();
+
+    schema.to_json()
+}
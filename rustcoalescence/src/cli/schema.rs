@@ -0,0 +1,239 @@
+use anyhow::{Context, Result};
+
+use crate::{
+    args::cli::{SchemaArgs, SchemaConfig},
+    cli::{replay::replay_schema, simulate::simulate_schema},
+};
+
+/// # Errors
+///
+/// Returns an error if the schema cannot be printed.
+#[allow(clippy::module_name_repetitions, clippy::needless_pass_by_value)]
+pub fn schema_with_logger(schema_args: SchemaArgs) -> Result<()> {
+    let schema = match schema_args.config {
+        SchemaConfig::Simulate => simulate_schema(),
+        SchemaConfig::Replay => replay_schema(),
+    };
+
+    let schema =
+        serde_json::to_string_pretty(&schema).context("Failed to print the config schema.")?;
+
+    println!("{schema}");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::cli::{replay::replay_schema, simulate::simulate_schema};
+
+    /// Checks the `value` against the subset of JSON Schema that is generated
+    /// from the config types
+    fn is_valid(schema: &Value, root: &Value, value: &Value) -> bool {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let key = reference.trim_start_matches("#/$defs/");
+            return is_valid(&root["$defs"][key], root, value);
+        }
+
+        let r#type = schema.get("type").and_then(Value::as_str);
+        let valid_type = match r#type {
+            None => true,
+            Some("null") => value.is_null(),
+            Some("boolean") => value.is_boolean(),
+            Some("integer") => value.is_i64() || value.is_u64(),
+            Some("number") => value.is_number(),
+            Some("string") => value.is_string(),
+            Some("array") => value.is_array(),
+            Some("object") => value.is_object(),
+            Some(_) => false,
+        };
+
+        let bound = |keyword: &str| schema.get(keyword).and_then(Value::as_f64);
+        let length = |keyword: &str| {
+            schema
+                .get(keyword)
+                .and_then(Value::as_u64)
+                .and_then(|length| usize::try_from(length).ok())
+        };
+
+        let valid_bounds = value.as_f64().map_or(true, |number| {
+            bound("minimum").map_or(true, |minimum| number >= minimum)
+                && bound("maximum").map_or(true, |maximum| number <= maximum)
+        }) && value.as_str().map_or(true, |string| {
+            let chars = string.chars().count();
+            length("minLength").map_or(true, |min| chars >= min)
+                && length("maxLength").map_or(true, |max| chars <= max)
+        });
+
+        let valid_alternatives = schema
+            .get("const")
+            .map_or(true, |constant| value == constant)
+            && schema
+                .get("anyOf")
+                .and_then(Value::as_array)
+                .map_or(true, |schemas| {
+                    schemas.iter().any(|schema| is_valid(schema, root, value))
+                })
+            && schema
+                .get("oneOf")
+                .and_then(Value::as_array)
+                .map_or(true, |schemas| {
+                    schemas
+                        .iter()
+                        .filter(|schema| is_valid(schema, root, value))
+                        .count()
+                        == 1
+                });
+
+        let valid_elements = value.as_array().map_or(true, |elements| {
+            let prefix = schema
+                .get("prefixItems")
+                .and_then(Value::as_array)
+                .map_or(&[][..], Vec::as_slice);
+
+            length("minItems").map_or(true, |min| elements.len() >= min)
+                && length("maxItems").map_or(true, |max| elements.len() <= max)
+                && elements
+                    .iter()
+                    .enumerate()
+                    .all(|(i, element)| match prefix.get(i) {
+                        Some(schema) => is_valid(schema, root, element),
+                        None => schema
+                            .get("items")
+                            .map_or(true, |schema| is_valid(schema, root, element)),
+                    })
+        });
+
+        let valid_fields = value.as_object().map_or(true, |fields| {
+            let required = schema
+                .get("required")
+                .and_then(Value::as_array)
+                .map_or(&[][..], Vec::as_slice);
+
+            required.iter().all(|field| {
+                field
+                    .as_str()
+                    .map_or(false, |field| fields.contains_key(field))
+            }) && fields.iter().all(|(name, field)| {
+                match schema
+                    .get("properties")
+                    .and_then(|properties| properties.get(name))
+                {
+                    Some(schema) => is_valid(schema, root, field),
+                    None => match schema.get("additionalProperties") {
+                        Some(Value::Bool(additional)) => *additional,
+                        Some(schema) => is_valid(schema, root, field),
+                        None => true,
+                    },
+                }
+            })
+        });
+
+        valid_type && valid_bounds && valid_alternatives && valid_elements && valid_fields
+    }
+
+    fn plugin() -> Value {
+        json!({ "Plugin": {
+            "library": "target/release/deps/libnecsim_plugins_common.so",
+            "reporters": [{ "Biodiversity": {} }, { "Execution": {} }, { "Progress": {} }],
+        } })
+    }
+
+    #[test]
+    fn replay_schema_accepts_the_example_config() {
+        let schema = replay_schema();
+
+        // JSON conversion of the example `replay.ron` config
+        let mut config = json!({
+            "log": { "segments": ["event-log/**/*"] },
+            "reporters": [plugin()],
+        });
+        assert!(is_valid(&schema, &schema, &config));
+
+        config["mode"] = json!("WarnOnly");
+        config["log"]["filter"] = json!({
+            "after": 1.0,
+            "origin": { "origin": { "x": 0, "y": 0 }, "width": 10, "height": 10 },
+            "lineages": [1, 2],
+        });
+        assert!(is_valid(&schema, &schema, &config));
+    }
+
+    #[test]
+    fn replay_schema_rejects_unknown_fields() {
+        let schema = replay_schema();
+
+        let config = json!({
+            "log": { "segments": ["event-log/**/*"], "filter": {} },
+            "reporters": [plugin()],
+        });
+        assert!(is_valid(&schema, &schema, &config));
+
+        for (pointer, field) in [("", "logs"), ("/log", "segment"), ("/log/filter", "afer")] {
+            let mut config = config.clone();
+            config.pointer_mut(pointer).unwrap()[field] = json!(true);
+            assert!(!is_valid(&schema, &schema, &config), "{pointer}/{field}");
+        }
+
+        let mut config = config.clone();
+        config["mode"] = json!("Lenient");
+        assert!(!is_valid(&schema, &schema, &config));
+    }
+
+    #[test]
+    fn simulate_schema_lists_fields_and_aliases() {
+        let schema = simulate_schema();
+
+        for field in [
+            "speciation",
+            "speciation_probability_per_generation",
+            "sample",
+            "pause",
+            "rng",
+            "randomness",
+            "scenario",
+            "algorithm",
+            "partitioning",
+            "log",
+            "event_log",
+            "reporters",
+        ] {
+            assert!(schema["properties"].get(field).is_some(), "{field}");
+        }
+
+        assert!(!is_valid(
+            &schema,
+            &schema,
+            &json!({ "speciation": 0.1, "seed": 42 })
+        ));
+    }
+
+    #[test]
+    #[cfg(all(feature = "gillespie-algorithms", feature = "non-spatial-scenario"))]
+    fn simulate_schema_accepts_the_example_config() {
+        let schema = simulate_schema();
+
+        // JSON conversion of the example `simulate.ron` config
+        let config = json!({
+            "speciation": 0.1,
+            "sample": { "percentage": 1.0 },
+            "rng": "Entropy",
+            "algorithm": { "EventSkipping": {} },
+            "scenario": { "NonSpatial": { "area": [100, 100], "deme": 100 } },
+            "reporters": [plugin()],
+        });
+        assert!(is_valid(&schema, &schema, &config));
+
+        for (pointer, field) in [
+            ("", "seed"),
+            ("/sample", "size"),
+            ("/scenario/NonSpatial", "demes"),
+        ] {
+            let mut config = config.clone();
+            config.pointer_mut(pointer).unwrap()[field] = json!(1);
+            assert!(!is_valid(&schema, &schema, &config), "{pointer}/{field}");
+        }
+    }
+}
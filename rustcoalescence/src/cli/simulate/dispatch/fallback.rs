@@ -1,15 +1,18 @@
 use necsim_core_bond::{NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64};
-use necsim_impls_std::event_log::recorder::EventLogRecorder;
+use necsim_impls_std::event_log::recorder::{EventLogProvenanceHandle, EventLogRecorder};
 use necsim_plugins_core::import::AnyReporterPluginVec;
 
 use crate::{
-    args::config::{
-        algorithm::Algorithm, partitioning::Partitioning, sample::Sample, scenario::Scenario,
+    args::{
+        config::{
+            algorithm::Algorithm, partitioning::Partitioning, sample::Sample, scenario::Scenario,
+        },
+        utils::parse::Config,
     },
     cli::simulate::SimulationOutcome,
 };
 
-use super::super::BufferingSimulateArgsBuilder;
+use super::super::{BufferingSimulateArgsBuilder, ManifestRecorder};
 
 #[allow(clippy::too_many_arguments, clippy::needless_pass_by_value)]
 pub(in super::super) fn dispatch(
@@ -22,9 +25,11 @@ pub(in super::super) fn dispatch(
     _scenario: Scenario,
     _algorithm: Algorithm,
     _pause_before: Option<NonNegativeF64>,
+    _event_log_provenance: Option<&EventLogProvenanceHandle>,
 
-    _ron_args: &str,
+    _config: &Config,
     _normalised_args: &mut BufferingSimulateArgsBuilder,
+    _manifest: &mut ManifestRecorder,
 ) -> anyhow::Result<SimulationOutcome> {
     extern "C" {
         fn simulate_dispatch_without_algorithm() -> !;
@@ -32,3 +37,18 @@ pub(in super::super) fn dispatch(
 
     unsafe { simulate_dispatch_without_algorithm() }
 }
+
+#[allow(clippy::needless_pass_by_value)]
+pub(in super::super) fn estimate(
+    _partitioning: &Partitioning,
+    _speciation_probability_per_generation: PositiveUnitF64,
+    _sample: &Sample,
+    _scenario: &Scenario,
+    _algorithm: Algorithm,
+) -> anyhow::Result<()> {
+    extern "C" {
+        fn simulate_dispatch_without_algorithm() -> !;
+    }
+
+    unsafe { simulate_dispatch_without_algorithm() }
+}
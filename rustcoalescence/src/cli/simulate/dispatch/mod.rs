@@ -17,11 +17,11 @@ mod fallback;
     feature = "independent-algorithm",
     feature = "cuda-algorithm"
 ))]
-pub(super) use valid::dispatch;
+pub(super) use valid::{dispatch, estimate};
 
 #[cfg(not(any(
     feature = "gillespie-algorithms",
     feature = "independent-algorithm",
     feature = "cuda-algorithm"
 )))]
-pub(super) use fallback::dispatch;
+pub(super) use fallback::{dispatch, estimate};
@@ -1,5 +1,6 @@
 use necsim_core::reporter::Reporter;
 use necsim_core_bond::{NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64};
+use necsim_impls_std::event_log::recorder::EventLogProvenanceHandle;
 use necsim_partitioning_core::LocalPartition;
 
 use rustcoalescence_algorithms::AlgorithmDefaults;
@@ -30,14 +31,20 @@ use rustcoalescence_scenarios::wrapping_noise::WrappingNoiseScenario;
 use rustcoalescence_scenarios::Scenario;
 
 use crate::{
-    args::config::{
-        algorithm::Algorithm as AlgorithmArgs, sample::Sample as SampleArgs,
-        scenario::Scenario as ScenarioArgs,
+    args::{
+        config::{
+            algorithm::Algorithm as AlgorithmArgs, sample::Sample as SampleArgs,
+            scenario::Scenario as ScenarioArgs,
+        },
+        utils::parse::Config,
     },
     cli::simulate::SimulationOutcome,
 };
 
-use super::{super::super::BufferingSimulateArgsBuilder, rng};
+use super::{
+    super::super::{BufferingSimulateArgsBuilder, ManifestRecorder},
+    auto, rng,
+};
 
 macro_rules! match_scenario_algorithm {
     (
@@ -45,6 +52,7 @@ macro_rules! match_scenario_algorithm {
             $($(#[$algmeta:meta])* $algpat:pat => $algcode:block),*
             <=>
             $($(#[$scenmeta:meta])* $scenpat:pat => $scencode:block),*
+            $(<=> $($(#[$othermeta:meta])* $otherpat:pat => $othercode:block),*)?
         }
     ) => {
         match_scenario_algorithm! {
@@ -53,6 +61,7 @@ macro_rules! match_scenario_algorithm {
                 <=>
                 $($(#[$scenmeta])* $scenpat => $scencode),*
                 <=>
+                $($($(#[$othermeta])* $otherpat => $othercode)*)?
             }
         }
     };
@@ -107,7 +116,7 @@ macro_rules! match_scenario_algorithm {
     };
 }
 
-#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
 pub(super) fn dispatch<'p, R: Reporter, P: LocalPartition<'p, R>>(
     local_partition: P,
 
@@ -116,9 +125,11 @@ pub(super) fn dispatch<'p, R: Reporter, P: LocalPartition<'p, R>>(
     scenario: ScenarioArgs,
     algorithm: AlgorithmArgs,
     pause_before: Option<NonNegativeF64>,
+    event_log_provenance: Option<&EventLogProvenanceHandle>,
 
-    ron_args: &str,
+    config: &Config,
     normalised_args: &mut BufferingSimulateArgsBuilder,
+    manifest: &mut ManifestRecorder,
 ) -> anyhow::Result<SimulationOutcome> {
     match_scenario_algorithm!(
         (algorithm, scenario => scenario)
@@ -130,7 +141,7 @@ pub(super) fn dispatch<'p, R: Reporter, P: LocalPartition<'p, R>>(
                 GillespieAlgorithm, _, R, P,
             >(
                 local_partition, sample, algorithm_args, scenario,
-                pause_before, ron_args, normalised_args,
+                pause_before, event_log_provenance, config, normalised_args, manifest,
             )
         },
         #[cfg(feature = "gillespie-algorithms")]
@@ -140,7 +151,7 @@ pub(super) fn dispatch<'p, R: Reporter, P: LocalPartition<'p, R>>(
                 EventSkippingAlgorithm, _, R, P,
             >(
                 local_partition, sample, algorithm_args, scenario,
-                pause_before, ron_args, normalised_args,
+                pause_before, event_log_provenance, config, normalised_args, manifest,
             )
         },
         #[cfg(feature = "independent-algorithm")]
@@ -150,7 +161,7 @@ pub(super) fn dispatch<'p, R: Reporter, P: LocalPartition<'p, R>>(
                 IndependentAlgorithm, _, R, P,
             >(
                 local_partition, sample, algorithm_args, scenario,
-                pause_before, ron_args, normalised_args,
+                pause_before, event_log_provenance, config, normalised_args, manifest,
             )
         },
         #[cfg(feature = "cuda-algorithm")]
@@ -160,7 +171,7 @@ pub(super) fn dispatch<'p, R: Reporter, P: LocalPartition<'p, R>>(
                 CudaAlgorithm, _, R, P,
             >(
                 local_partition, sample, algorithm_args, scenario,
-                pause_before, ron_args, normalised_args,
+                pause_before, event_log_provenance, config, normalised_args, manifest,
             )
         }
         <=>
@@ -218,5 +229,21 @@ pub(super) fn dispatch<'p, R: Reporter, P: LocalPartition<'p, R>>(
             )
             .into_ok()
         }
+        <=>
+        AlgorithmArgs::Auto => {
+            let algorithm = auto::select_algorithm(
+                speciation_probability_per_generation,
+                &sample,
+                &scenario,
+                local_partition.get_partition(),
+            )?;
+
+            normalised_args.algorithm(&algorithm);
+
+            dispatch(
+                local_partition, speciation_probability_per_generation, sample, scenario,
+                algorithm, pause_before, event_log_provenance, config, normalised_args, manifest,
+            )
+        }
     })
 }
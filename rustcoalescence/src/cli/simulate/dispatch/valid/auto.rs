@@ -0,0 +1,343 @@
+use std::num::{NonZeroU32, NonZeroUsize};
+
+use necsim_core_bond::OpenClosedUnitF64 as PositiveUnitF64;
+use necsim_partitioning_core::partition::Partition;
+
+use crate::args::{
+    config::{
+        algorithm::Algorithm as AlgorithmArgs, sample::Sample as SampleArgs,
+        scenario::Scenario as ScenarioArgs,
+    },
+    utils::parse::try_parse_state,
+};
+
+use super::inspect::{inspect_scenario_args, ScenarioInspection};
+
+/// Below this ratio of sampled lineages to habitat, coalescence is rare
+const INDEPENDENT_MAX_SAMPLE_DENSITY: f64 = 0.01;
+/// Above this self-dispersal probability, most events are skippable
+const EVENT_SKIPPING_MIN_SELF_DISPERSAL: f64 = 0.5;
+/// Above this number of lineages, the independent algorithm uses threads
+const THREADED_MIN_SAMPLE_SIZE: f64 = 100_000.0;
+/// Below this number of lineages per worker, more threads only add overhead
+const THREADED_MIN_LINEAGES_PER_WORKER: f64 = 25_000.0;
+/// Capacity of the threaded event buffer relative to the number of lineages
+const THREADED_EVENT_SLICE_FACTOR: f64 = 2.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AutoAlgorithm {
+    Gillespie,
+    EventSkipping,
+    Independent,
+}
+
+impl AutoAlgorithm {
+    fn is_available(self) -> bool {
+        match self {
+            Self::Gillespie | Self::EventSkipping => cfg!(feature = "gillespie-algorithms"),
+            Self::Independent => cfg!(feature = "independent-algorithm"),
+        }
+    }
+}
+
+pub(super) fn select_algorithm(
+    speciation_probability_per_generation: PositiveUnitF64,
+    sample: &SampleArgs,
+    scenario: &ScenarioArgs,
+    partition: Partition,
+) -> anyhow::Result<AlgorithmArgs> {
+    info!("The algorithm will be selected automatically by inspecting the scenario ...");
+
+    let inspection =
+        inspect_scenario_args(speciation_probability_per_generation, sample, scenario)?;
+
+    select_inspected_algorithm(&inspection, partition)
+}
+
+pub(super) fn select_inspected_algorithm(
+    inspection: &ScenarioInspection,
+    mut partition: Partition,
+) -> anyhow::Result<AlgorithmArgs> {
+    info!(
+        "The scenario {} with a total habitat of {} individuals and a habitat density of {:.3} \
+         individuals per location.",
+        if inspection.is_finite {
+            "is finite"
+        } else {
+            "is infinite"
+        },
+        inspection.total_habitat,
+        inspection.habitat_density,
+    );
+    info!(
+        "The individuals have a mean self-dispersal probability of {:.3}.",
+        inspection.mean_self_dispersal,
+    );
+    info!(
+        "The simulation will sample approximately {} lineages.",
+        inspection.sample_size.round(),
+    );
+
+    let Some(algorithm) = algorithm_preferences(inspection)
+        .into_iter()
+        .find(|algorithm| algorithm.is_available())
+    else {
+        anyhow::bail!("rustcoalescence must be compiled to support at least one CPU algorithm")
+    };
+
+    let algorithm_args = match algorithm {
+        AutoAlgorithm::Gillespie => String::from("Gillespie()"),
+        AutoAlgorithm::EventSkipping => String::from("EventSkipping()"),
+        AutoAlgorithm::Independent => {
+            let available_parallelism = std::thread::available_parallelism().ok();
+
+            match threaded_workers(inspection, partition.size(), available_parallelism) {
+                Some(workers) => {
+                    info!(
+                        "The large sample will be split across {} worker threads, which share the \
+                         read-only habitat and dispersal sampler, {}.",
+                        workers,
+                        match available_parallelism {
+                            Some(available) if available > workers => format!(
+                                "capped below the {available} available cores such that each \
+                                 worker simulates at least {THREADED_MIN_LINEAGES_PER_WORKER} \
+                                 lineages"
+                            ),
+                            _ => String::from("one per available core"),
+                        },
+                    );
+
+                    format!(
+                        "Independent(parallelism_mode: Threaded(workers: {workers}, event_slice: \
+                         Relative(factor: {THREADED_EVENT_SLICE_FACTOR:?})))"
+                    )
+                },
+                None => String::from("Independent()"),
+            }
+        },
+    };
+
+    let algorithm: AlgorithmArgs = try_parse_state("simulate", &algorithm_args, &mut partition)?;
+
+    info!("The algorithm {} has been selected.", algorithm_args);
+
+    Ok(algorithm)
+}
+
+fn algorithm_preferences(inspection: &ScenarioInspection) -> [AutoAlgorithm; 3] {
+    let sample_density = inspection.sample_size / inspection.total_habitat;
+
+    if !inspection.is_finite || sample_density < INDEPENDENT_MAX_SAMPLE_DENSITY {
+        info!(
+            "Only {:.3}% of the habitat is sampled, so lineages rarely coalesce and can be \
+             simulated independently.",
+            sample_density * 100.0,
+        );
+
+        [
+            AutoAlgorithm::Independent,
+            AutoAlgorithm::EventSkipping,
+            AutoAlgorithm::Gillespie,
+        ]
+    } else if inspection.mean_self_dispersal >= EVENT_SKIPPING_MIN_SELF_DISPERSAL {
+        info!(
+            "Most dispersal events stay within the same location, so self-dispersal events \
+             without coalescence are worth skipping."
+        );
+
+        [
+            AutoAlgorithm::EventSkipping,
+            AutoAlgorithm::Gillespie,
+            AutoAlgorithm::Independent,
+        ]
+    } else {
+        info!(
+            "Most dispersal events leave their location, so skipping self-dispersal events is not \
+             worth its overhead."
+        );
+
+        [
+            AutoAlgorithm::Gillespie,
+            AutoAlgorithm::EventSkipping,
+            AutoAlgorithm::Independent,
+        ]
+    }
+}
+
+fn threaded_workers(
+    inspection: &ScenarioInspection,
+    partition_size: NonZeroU32,
+    available_parallelism: Option<NonZeroUsize>,
+) -> Option<NonZeroUsize> {
+    if partition_size.get() != 1 || inspection.sample_size < THREADED_MIN_SAMPLE_SIZE {
+        return None;
+    }
+
+    // Each worker should simulate enough lineages to outweigh its overhead
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let max_workers = (inspection.sample_size / THREADED_MIN_LINEAGES_PER_WORKER).floor() as usize;
+
+    NonZeroUsize::new(available_parallelism?.get().min(max_workers))
+        .filter(|workers| workers.get() > 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::{NonZeroU32, NonZeroUsize};
+
+    use super::{
+        super::inspect::{ScenarioFootprint, ScenarioInspection},
+        algorithm_preferences, threaded_workers, AutoAlgorithm, EVENT_SKIPPING_MIN_SELF_DISPERSAL,
+        INDEPENDENT_MAX_SAMPLE_DENSITY, THREADED_MIN_LINEAGES_PER_WORKER, THREADED_MIN_SAMPLE_SIZE,
+    };
+
+    const TOTAL_HABITAT: f64 = 1_000_000.0;
+
+    fn inspection(
+        is_finite: bool,
+        sample_size: f64,
+        mean_self_dispersal: f64,
+    ) -> ScenarioInspection {
+        ScenarioInspection {
+            is_finite,
+            total_habitat: TOTAL_HABITAT,
+            habitat_density: 1.0,
+            mean_self_dispersal,
+            sample_size,
+            footprint: ScenarioFootprint::default(),
+        }
+    }
+
+    #[test]
+    fn sparse_samples_prefer_independent() {
+        let sample_size = TOTAL_HABITAT * INDEPENDENT_MAX_SAMPLE_DENSITY * 0.5;
+
+        for mean_self_dispersal in [0.0, 1.0] {
+            assert_eq!(
+                algorithm_preferences(&inspection(true, sample_size, mean_self_dispersal))[0],
+                AutoAlgorithm::Independent
+            );
+        }
+    }
+
+    #[test]
+    fn infinite_habitats_prefer_independent() {
+        assert_eq!(
+            algorithm_preferences(&inspection(false, TOTAL_HABITAT, 1.0))[0],
+            AutoAlgorithm::Independent
+        );
+    }
+
+    #[test]
+    fn dense_samples_prefer_gillespie_algorithms() {
+        let sample_size = TOTAL_HABITAT * INDEPENDENT_MAX_SAMPLE_DENSITY;
+
+        assert_eq!(
+            algorithm_preferences(&inspection(
+                true,
+                sample_size,
+                EVENT_SKIPPING_MIN_SELF_DISPERSAL
+            ))[0],
+            AutoAlgorithm::EventSkipping
+        );
+        assert_eq!(
+            algorithm_preferences(&inspection(
+                true,
+                sample_size,
+                EVENT_SKIPPING_MIN_SELF_DISPERSAL * 0.5
+            ))[0],
+            AutoAlgorithm::Gillespie
+        );
+    }
+
+    #[test]
+    fn preferences_contain_every_algorithm() {
+        for (is_finite, sample_size, mean_self_dispersal) in [
+            (false, 1.0, 0.0),
+            (true, TOTAL_HABITAT, 0.0),
+            (true, TOTAL_HABITAT, 1.0),
+        ] {
+            let mut preferences =
+                algorithm_preferences(&inspection(is_finite, sample_size, mean_self_dispersal));
+            preferences.sort_by_key(|algorithm| *algorithm as u8);
+
+            assert_eq!(
+                preferences,
+                [
+                    AutoAlgorithm::Gillespie,
+                    AutoAlgorithm::EventSkipping,
+                    AutoAlgorithm::Independent
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn large_monolithic_samples_use_threads() {
+        let monolithic = NonZeroU32::new(1).unwrap();
+        let workers = NonZeroUsize::new(4);
+
+        assert_eq!(
+            threaded_workers(
+                &inspection(true, THREADED_MIN_SAMPLE_SIZE, 0.0),
+                monolithic,
+                workers
+            ),
+            workers
+        );
+        assert_eq!(
+            threaded_workers(
+                &inspection(true, THREADED_MIN_SAMPLE_SIZE - 1.0, 0.0),
+                monolithic,
+                workers
+            ),
+            None
+        );
+        assert_eq!(
+            threaded_workers(
+                &inspection(true, THREADED_MIN_SAMPLE_SIZE, 0.0),
+                NonZeroU32::new(2).unwrap(),
+                workers
+            ),
+            None
+        );
+        assert_eq!(
+            threaded_workers(
+                &inspection(true, THREADED_MIN_SAMPLE_SIZE, 0.0),
+                monolithic,
+                NonZeroUsize::new(1)
+            ),
+            None
+        );
+        assert_eq!(
+            threaded_workers(
+                &inspection(true, THREADED_MIN_SAMPLE_SIZE, 0.0),
+                monolithic,
+                None
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn threads_are_capped_by_the_sample_size() {
+        let monolithic = NonZeroU32::new(1).unwrap();
+
+        assert_eq!(
+            threaded_workers(
+                &inspection(true, THREADED_MIN_LINEAGES_PER_WORKER * 8.0, 0.0),
+                monolithic,
+                NonZeroUsize::new(64)
+            ),
+            NonZeroUsize::new(8)
+        );
+        assert_eq!(
+            threaded_workers(
+                &inspection(true, THREADED_MIN_LINEAGES_PER_WORKER * 8.5, 0.0),
+                monolithic,
+                NonZeroUsize::new(4)
+            ),
+            NonZeroUsize::new(4)
+        );
+    }
+}
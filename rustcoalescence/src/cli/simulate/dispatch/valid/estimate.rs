@@ -0,0 +1,253 @@
+use std::{mem::size_of, num::NonZeroUsize};
+
+use necsim_core::{
+    landscape::IndexedLocation,
+    lineage::{GlobalLineageReference, Lineage},
+};
+use necsim_core_bond::{ClosedUnitF64, OpenClosedUnitF64 as PositiveUnitF64};
+use necsim_impls_no_std::{
+    alias::AliasMethodSampler, cogs::lineage_reference::in_memory::InMemoryLineageReference,
+};
+
+use crate::{
+    args::config::{
+        algorithm::Algorithm as AlgorithmArgs, partitioning::Partitioning, sample::Sample,
+        scenario::Scenario as ScenarioArgs,
+    },
+    milestone::{self, Milestone},
+};
+
+use super::{
+    auto,
+    inspect::{inspect_scenario_args, ScenarioInspection},
+};
+
+/// Rough single-core throughput of each algorithm, in nanoseconds per event
+const GILLESPIE_NANOS_PER_EVENT: f64 = 400.0;
+const EVENT_SKIPPING_NANOS_PER_EVENT: f64 = 600.0;
+const INDEPENDENT_NANOS_PER_EVENT: f64 = 100.0;
+#[cfg(feature = "cuda-algorithm")]
+const CUDA_NANOS_PER_EVENT: f64 = 10.0;
+
+struct AlgorithmCost {
+    name: &'static str,
+    dispersal_bytes: f64,
+    lineage_store_bytes: f64,
+    events: f64,
+    nanos_per_event: f64,
+    /// Number of threads that simulate each partition
+    workers: f64,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub(in super::super::super) fn estimate(
+    partitioning: &Partitioning,
+    speciation_probability_per_generation: PositiveUnitF64,
+    sample: &Sample,
+    scenario: &ScenarioArgs,
+    algorithm: AlgorithmArgs,
+) -> anyhow::Result<()> {
+    let inspection =
+        inspect_scenario_args(speciation_probability_per_generation, sample, scenario)?;
+
+    let algorithm = match algorithm {
+        AlgorithmArgs::Auto => {
+            auto::select_inspected_algorithm(&inspection, partitioning.get_partition())?
+        },
+        algorithm => algorithm,
+    };
+
+    let speciation = speciation_probability_per_generation.get();
+
+    #[allow(unreachable_code, unused_variables)]
+    let cost = match algorithm {
+        #[cfg(feature = "gillespie-algorithms")]
+        AlgorithmArgs::Gillespie(_) => AlgorithmCost {
+            name: "Gillespie",
+            dispersal_bytes: alias_dispersal_bytes(&inspection),
+            lineage_store_bytes: coherent_lineage_store_bytes(&inspection),
+            events: max_events(&inspection, speciation),
+            nanos_per_event: GILLESPIE_NANOS_PER_EVENT,
+            workers: 1.0,
+        },
+        #[cfg(feature = "gillespie-algorithms")]
+        AlgorithmArgs::EventSkipping(_) => AlgorithmCost {
+            name: "EventSkipping",
+            dispersal_bytes: alias_dispersal_bytes(&inspection)
+                + separable_self_dispersal_bytes(&inspection),
+            lineage_store_bytes: coherent_lineage_store_bytes(&inspection),
+            // Self-dispersal events without coalescence are skipped
+            events: max_events(&inspection, speciation)
+                * (1.0 - inspection.mean_self_dispersal).max(speciation),
+            nanos_per_event: EVENT_SKIPPING_NANOS_PER_EVENT,
+            workers: 1.0,
+        },
+        #[cfg(feature = "cuda-algorithm")]
+        AlgorithmArgs::Cuda(_) => AlgorithmCost {
+            name: "CUDA",
+            dispersal_bytes: alias_dispersal_bytes(&inspection),
+            lineage_store_bytes: independent_lineage_store_bytes(&inspection),
+            events: max_events(&inspection, speciation),
+            nanos_per_event: CUDA_NANOS_PER_EVENT,
+            workers: 1.0,
+        },
+        #[cfg(feature = "independent-algorithm")]
+        AlgorithmArgs::Independent(args) => AlgorithmCost {
+            name: "Independent",
+            dispersal_bytes: alias_dispersal_bytes(&inspection),
+            lineage_store_bytes: independent_lineage_store_bytes(&inspection),
+            events: max_events(&inspection, speciation),
+            nanos_per_event: INDEPENDENT_NANOS_PER_EVENT,
+            workers: threaded_workers(args.threaded_workers()),
+        },
+        AlgorithmArgs::Auto => unreachable!("the algorithm has already been selected"),
+    };
+
+    #[allow(unreachable_code)]
+    let processing_units = f64::from(partitioning.get_partition().size().get()) * cost.workers;
+
+    #[allow(clippy::cast_precision_loss)]
+    let habitat_bytes = inspection.footprint.habitat_bytes as f64;
+
+    let lineage_store_bytes = cost.lineage_store_bytes / processing_units;
+    let runtime_nanos = cost.events * cost.nanos_per_event / processing_units;
+
+    if milestone::banners_enabled() {
+        println!("\n{:=^80}\n", " Simulation Estimate ");
+        println!(
+            "The simulation would sample {} lineages with the {} algorithm.\n",
+            inspection.sample_size.round(),
+            cost.name,
+        );
+        println!(
+            "Memory footprint (per processing unit):\n - habitat:                {}\n - dispersal \
+             alias tables: {}\n - lineage store:          {}\n",
+            format_bytes(habitat_bytes),
+            format_bytes(cost.dispersal_bytes),
+            format_bytes(lineage_store_bytes),
+        );
+        println!(
+            "The speciation probability per generation of {} implies at most {:.3e} events, fewer \
+             if many lineages coalesce.",
+            speciation, cost.events,
+        );
+        println!(
+            "The simulation might take roughly {} across {} processing unit(s).",
+            format_duration(runtime_nanos),
+            processing_units,
+        );
+        println!("\n{:=^80}\n", " Simulation Estimate ");
+    }
+
+    Milestone::Estimated {
+        algorithm: cost.name,
+        sample_size: inspection.sample_size.round(),
+        habitat_bytes,
+        dispersal_bytes: cost.dispersal_bytes,
+        lineage_store_bytes,
+        events: cost.events,
+        runtime_seconds: runtime_nanos * 1e-9,
+        processing_units,
+    }
+    .report();
+
+    Ok(())
+}
+
+/// Upper bound on the number of events, which ignores coalescence
+///
+/// Without coalescence, every lineage independently undergoes a geometrically
+/// distributed number of events with mean `1 / speciation` until it speciates.
+/// Every coalescence removes a lineage and thus all of its remaining events,
+/// so densely sampled scenarios can require far fewer events than estimated.
+/// Estimating how many lineages coalesce would require simulating them.
+#[allow(dead_code)]
+fn max_events(inspection: &ScenarioInspection, speciation: f64) -> f64 {
+    inspection.sample_size / speciation
+}
+
+#[allow(dead_code)]
+fn alias_dispersal_bytes(inspection: &ScenarioInspection) -> f64 {
+    // Each location stores an optional alias table with one probability and two
+    //  events per non-zero dispersal entry
+    let bytes = inspection.footprint.dispersal_locations
+        * size_of::<Option<AliasMethodSampler<usize>>>()
+        + inspection.footprint.dispersal_entries
+            * (size_of::<ClosedUnitF64>() + size_of::<usize>() * 2);
+
+    #[allow(clippy::cast_precision_loss)]
+    {
+        bytes as f64
+    }
+}
+
+#[allow(dead_code)]
+fn separable_self_dispersal_bytes(inspection: &ScenarioInspection) -> f64 {
+    let bytes = inspection.footprint.dispersal_locations * size_of::<ClosedUnitF64>();
+
+    #[allow(clippy::cast_precision_loss)]
+    {
+        bytes as f64
+    }
+}
+
+#[allow(dead_code)]
+fn coherent_lineage_store_bytes(inspection: &ScenarioInspection) -> f64 {
+    // Lineages are stored in a slab and indexed by their location and their
+    //  indexed location
+    let bytes_per_lineage = size_of::<Lineage>()
+        + size_of::<InMemoryLineageReference>()
+        + size_of::<IndexedLocation>()
+        + size_of::<(GlobalLineageReference, usize)>();
+
+    #[allow(clippy::cast_precision_loss)]
+    {
+        inspection.sample_size * (bytes_per_lineage as f64)
+    }
+}
+
+#[allow(dead_code)]
+fn independent_lineage_store_bytes(inspection: &ScenarioInspection) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    {
+        inspection.sample_size * (size_of::<Lineage>() as f64)
+    }
+}
+
+#[allow(dead_code)]
+fn threaded_workers(workers: Option<NonZeroUsize>) -> f64 {
+    // The workers of the `Threaded` mode share the habitat and dispersal sampler
+    //  but split the lineages between them
+    #[allow(clippy::cast_precision_loss)]
+    {
+        workers.map_or(1.0, |workers| workers.get() as f64)
+    }
+}
+
+fn format_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut value = bytes;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < (UNITS.len() - 1) {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+fn format_duration(nanos: f64) -> String {
+    let seconds = nanos * 1e-9;
+
+    if seconds < 60.0 {
+        format!("{seconds:.1}s")
+    } else if seconds < 3600.0 {
+        format!("{:.1}min", seconds / 60.0)
+    } else if seconds < 86400.0 {
+        format!("{:.1}h", seconds / 3600.0)
+    } else {
+        format!("{:.1}d", seconds / 86400.0)
+    }
+}
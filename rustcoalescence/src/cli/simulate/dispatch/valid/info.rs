@@ -1,6 +1,7 @@
 use std::fmt::Write;
 
 use anyhow::{Context, Result};
+use tiny_keccak::{Hasher, Keccak};
 
 use rustcoalescence_algorithms::{result::SimulationOutcome, Algorithm};
 
@@ -9,19 +10,26 @@ use necsim_core::{
     reporter::{boolean::Boolean, Reporter},
 };
 use necsim_core_bond::NonNegativeF64;
+use necsim_impls_std::event_log::{recorder::EventLogProvenanceHandle, EventLogProvenance};
 use necsim_partitioning_core::LocalPartition;
 
 use rustcoalescence_scenarios::Scenario;
 
-use crate::args::{
-    config::sample::{Sample, SampleMode, SampleModeRestart},
-    utils::parse::try_print,
+use crate::{
+    args::{
+        config::sample::{Sample, SampleMode, SampleModeRestart},
+        utils::parse::{try_print_as, ConfigFormat},
+    },
+    milestone::{self, Milestone},
 };
 
-use super::{super::super::BufferingSimulateArgsBuilder, launch};
+use super::{
+    super::super::{BufferingSimulateArgsBuilder, ManifestRecorder},
+    launch,
+};
 
 #[allow(dead_code)]
-#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
 pub(super) fn dispatch<
     'p,
     M: MathsCore,
@@ -36,20 +44,25 @@ pub(super) fn dispatch<
     sample: Sample,
     pause_before: Option<NonNegativeF64>,
     mut local_partition: P,
+    event_log_provenance: Option<&EventLogProvenanceHandle>,
 
-    normalised_args: &BufferingSimulateArgsBuilder,
+    normalised_format: ConfigFormat,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+    manifest: &mut ManifestRecorder,
 ) -> anyhow::Result<SimulationOutcome<M, A::Rng>>
 where
     Result<SimulationOutcome<M, A::Rng>, A::Error>:
         anyhow::Context<SimulationOutcome<M, A::Rng>, A::Error>,
 {
-    let config_str = normalised_args
-        .build()
-        .map_err(anyhow::Error::new)
-        .and_then(|simulate_args| try_print(&simulate_args))
-        .context("Failed to normalise the simulation config.")?;
+    let config_str = normalise_config(normalised_args, event_log_provenance)?;
+    let config = manifest.record_config(normalised_args)?;
+
+    if milestone::banners_enabled() {
+        let config_str = match normalised_format {
+            ConfigFormat::Ron => config_str,
+            format => try_print_config(normalised_args, format)?,
+        };
 
-    if log::log_enabled!(log::Level::Info) {
         println!("\n{:=^80}\n", " Simulation Configuration ");
         println!("{}", config_str.trim_start_matches("Simulate"));
         println!("\n{:=^80}\n", " Simulation Configuration ");
@@ -118,6 +131,8 @@ where
         warn!("The simulation will report no events.");
     }
 
+    Milestone::SimulationStarted { config }.report();
+
     let result = launch::simulate::<M, A, O, R, P>(
         algorithm_args,
         rng,
@@ -127,7 +142,7 @@ where
         &mut local_partition,
     )?;
 
-    if log::log_enabled!(log::Level::Info) {
+    if milestone::banners_enabled() {
         println!("\n");
         println!(
             "{:=^80}",
@@ -142,7 +157,7 @@ where
 
     local_partition.finalise_reporting();
 
-    if log::log_enabled!(log::Level::Info) {
+    if milestone::banners_enabled() {
         println!();
         println!(
             "{:=^80}",
@@ -157,3 +172,45 @@ where
 
     Ok(result)
 }
+
+fn normalise_config(
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+    event_log_provenance: Option<&EventLogProvenanceHandle>,
+) -> Result<String> {
+    let mut config_str = try_print_config(normalised_args, ConfigFormat::Ron)?;
+
+    if let Some(event_log_provenance) = event_log_provenance {
+        // A fresh simulation derives its run identifier from its normalised
+        //  config, while a resumed simulation keeps its original identifier
+        let run = event_log_provenance.run().unwrap_or_else(|| {
+            let mut run = [0_u8; 8];
+
+            let mut sponge = Keccak::v256();
+            sponge.update(config_str.as_bytes());
+            sponge.finalize(&mut run);
+
+            u64::from_le_bytes(run).into()
+        });
+
+        normalised_args.log(&Some(event_log_provenance.with_run(run)));
+        config_str = try_print_config(normalised_args, ConfigFormat::Ron)?;
+
+        event_log_provenance.set(EventLogProvenance::new(
+            run,
+            config_str.trim_start_matches("Simulate").to_owned(),
+        ))?;
+    }
+
+    Ok(config_str)
+}
+
+fn try_print_config(
+    normalised_args: &BufferingSimulateArgsBuilder,
+    format: ConfigFormat,
+) -> Result<String> {
+    normalised_args
+        .build()
+        .map_err(anyhow::Error::new)
+        .and_then(|simulate_args| try_print_as(&simulate_args, format))
+        .context("Failed to normalise the simulation config.")
+}
@@ -0,0 +1,274 @@
+use std::mem::size_of;
+
+use necsim_core::cogs::{
+    Habitat, SeedableRng, SeparableDispersalSampler, UniformlySampleableHabitat,
+};
+use necsim_core_bond::{NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64};
+use necsim_impls_no_std::{
+    array2d::Array2D,
+    cogs::{
+        dispersal_sampler::in_memory::separable_alias::InMemorySeparableAliasDispersalSampler,
+        habitat::in_memory::InMemoryHabitat,
+        maths::intrinsics::IntrinsicsMathsCore,
+        origin_sampler::{pre_sampler::OriginPreSampler, UntrustedOriginSampler},
+        rng::wyhash::WyHash,
+    },
+};
+
+#[cfg(feature = "almost-infinite-clark2dt-dispersal-scenario")]
+use rustcoalescence_scenarios::almost_infinite::clark2dt::AlmostInfiniteClark2DtDispersalScenario;
+#[cfg(feature = "almost-infinite-normal-dispersal-scenario")]
+use rustcoalescence_scenarios::almost_infinite::normal::AlmostInfiniteNormalDispersalScenario;
+#[cfg(feature = "non-spatial-scenario")]
+use rustcoalescence_scenarios::non_spatial::NonSpatialScenario;
+#[cfg(feature = "spatially-implicit-scenario")]
+use rustcoalescence_scenarios::spatially_implicit::SpatiallyImplicitScenario;
+#[cfg(feature = "wrapping-noise-scenario")]
+use rustcoalescence_scenarios::wrapping_noise::WrappingNoiseScenario;
+use rustcoalescence_scenarios::Scenario;
+
+use crate::args::config::{
+    sample::{Sample as SampleArgs, SampleOrigin},
+    scenario::Scenario as ScenarioArgs,
+};
+
+type InspectionMathsCore = IntrinsicsMathsCore;
+type InspectionRng = WyHash<InspectionMathsCore>;
+
+/// Number of uniformly sampled individuals whose location is inspected
+const INSPECTION_SAMPLES: usize = 1024;
+const INSPECTION_SEED: u64 = 42;
+
+pub(super) struct ScenarioInspection {
+    pub is_finite: bool,
+    pub total_habitat: f64,
+    pub habitat_density: f64,
+    pub mean_self_dispersal: f64,
+    pub sample_size: f64,
+    pub footprint: ScenarioFootprint,
+}
+
+/// Approximate size of the scenario's habitat and the dimensions of the
+/// dispersal map that in-memory dispersal samplers are built from
+#[derive(Default)]
+pub(super) struct ScenarioFootprint {
+    pub habitat_bytes: usize,
+    pub dispersal_locations: usize,
+    pub dispersal_entries: usize,
+}
+
+pub(super) fn inspect_scenario_args(
+    speciation_probability_per_generation: PositiveUnitF64,
+    sample: &SampleArgs,
+    scenario: &ScenarioArgs,
+) -> anyhow::Result<ScenarioInspection> {
+    #[allow(unreachable_code, unused_variables)]
+    let inspection = match scenario {
+        #[cfg(feature = "spatially-explicit-uniform-turnover-scenario")]
+        ScenarioArgs::SpatiallyExplicitUniformTurnover(scenario_args) => inspect_maps(
+            &scenario_args.habitat_map,
+            &scenario_args.dispersal_map,
+            None,
+            sample,
+        )?,
+        #[cfg(feature = "spatially-explicit-turnover-map-scenario")]
+        ScenarioArgs::SpatiallyExplicitTurnoverMap(scenario_args) => inspect_maps(
+            &scenario_args.habitat_map,
+            &scenario_args.dispersal_map,
+            Some(&scenario_args.turnover_map),
+            sample,
+        )?,
+        #[cfg(feature = "non-spatial-scenario")]
+        ScenarioArgs::NonSpatial(scenario_args) => inspect_scenario(
+            NonSpatialScenario::initialise(
+                scenario_args.clone(),
+                speciation_probability_per_generation,
+            )
+            .into_ok(),
+            sample,
+        ),
+        #[cfg(feature = "almost-infinite-normal-dispersal-scenario")]
+        ScenarioArgs::AlmostInfiniteNormalDispersal(scenario_args) => inspect_scenario(
+            AlmostInfiniteNormalDispersalScenario::initialise(
+                scenario_args.clone(),
+                speciation_probability_per_generation,
+            )
+            .into_ok(),
+            sample,
+        ),
+        #[cfg(feature = "almost-infinite-clark2dt-dispersal-scenario")]
+        ScenarioArgs::AlmostInfiniteClark2DtDispersal(scenario_args) => inspect_scenario(
+            AlmostInfiniteClark2DtDispersalScenario::initialise(
+                scenario_args.clone(),
+                speciation_probability_per_generation,
+            )
+            .into_ok(),
+            sample,
+        ),
+        #[cfg(feature = "spatially-implicit-scenario")]
+        ScenarioArgs::SpatiallyImplicit(scenario_args) => inspect_scenario(
+            SpatiallyImplicitScenario::initialise(
+                scenario_args.clone(),
+                speciation_probability_per_generation,
+            )
+            .into_ok(),
+            sample,
+        ),
+        #[cfg(feature = "wrapping-noise-scenario")]
+        ScenarioArgs::WrappingNoise(scenario_args) => inspect_scenario(
+            WrappingNoiseScenario::initialise(
+                scenario_args.clone(),
+                speciation_probability_per_generation,
+            )
+            .into_ok(),
+            sample,
+        ),
+    };
+
+    #[allow(unreachable_code)]
+    Ok(inspection)
+}
+
+/// Inspects a spatially explicit scenario directly from its maps, without
+/// copying them or building the scenario's in-memory dispersal sampler
+#[allow(dead_code)]
+fn inspect_maps(
+    habitat_map: &Array2D<u32>,
+    dispersal_map: &Array2D<NonNegativeF64>,
+    turnover_map: Option<&Array2D<NonNegativeF64>>,
+    sample: &SampleArgs,
+) -> anyhow::Result<ScenarioInspection> {
+    let habitat_area = habitat_map.num_elements();
+
+    anyhow::ensure!(
+        dispersal_map.num_rows() == habitat_area && dispersal_map.num_columns() == habitat_area,
+        "invalid dispersal map: inconsistent dispersal map size"
+    );
+
+    let mut total_habitat = 0_u64;
+    let mut weighted_self_dispersal = 0.0_f64;
+    let mut dispersal_entries = 0_usize;
+
+    for (location_index, (habitat, dispersal_row)) in habitat_map
+        .elements_row_major_iter()
+        .zip(dispersal_map.rows_iter())
+        .enumerate()
+    {
+        let mut total_dispersal = 0.0_f64;
+        let mut self_dispersal = 0.0_f64;
+
+        for (target_index, dispersal) in dispersal_row.enumerate() {
+            if *dispersal > 0.0_f64 {
+                dispersal_entries += 1;
+                total_dispersal += dispersal.get();
+
+                if target_index == location_index {
+                    self_dispersal = dispersal.get();
+                }
+            }
+        }
+
+        total_habitat += u64::from(*habitat);
+
+        if total_dispersal > 0.0_f64 {
+            weighted_self_dispersal += f64::from(*habitat) * self_dispersal / total_dispersal;
+        }
+    }
+
+    anyhow::ensure!(
+        total_habitat > 0,
+        "invalid habitat map: no habitable locations"
+    );
+
+    #[allow(clippy::cast_precision_loss)]
+    let total_habitat = total_habitat as f64;
+    #[allow(clippy::cast_precision_loss)]
+    let habitat_density = total_habitat / (habitat_area as f64);
+
+    Ok(ScenarioInspection {
+        is_finite: true,
+        total_habitat,
+        habitat_density,
+        mean_self_dispersal: weighted_self_dispersal / total_habitat,
+        sample_size: sample_size(sample, || (total_habitat * sample.percentage.get()).floor()),
+        footprint: ScenarioFootprint {
+            habitat_bytes: size_of::<InMemoryHabitat<InspectionMathsCore>>()
+                + habitat_area * (size_of::<u32>() + size_of::<u64>())
+                + turnover_map.map_or(0, |turnover_map| {
+                    turnover_map.num_elements() * size_of::<NonNegativeF64>()
+                }),
+            dispersal_locations: habitat_area,
+            dispersal_entries,
+        },
+    })
+}
+
+/// Inspects an analytically defined scenario, whose habitat and dispersal
+/// sampler do not depend on any maps and are thus cheap to build
+#[allow(dead_code)]
+fn inspect_scenario<O: Scenario<InspectionMathsCore, InspectionRng>>(
+    scenario: O,
+    sample: &SampleArgs,
+) -> ScenarioInspection
+where
+    O::DispersalSampler<
+        InMemorySeparableAliasDispersalSampler<InspectionMathsCore, O::Habitat, InspectionRng>,
+    >: SeparableDispersalSampler<InspectionMathsCore, O::Habitat, InspectionRng>,
+{
+    let (habitat, dispersal_sampler, _, _, origin_sampler_auxiliary, _) =
+        scenario.build::<InMemorySeparableAliasDispersalSampler<
+            InspectionMathsCore,
+            O::Habitat,
+            InspectionRng,
+        >>();
+
+    let total_habitat = f64::from(habitat.get_total_habitat());
+
+    let extent = habitat.get_extent();
+    let habitat_density = total_habitat / f64::from(extent.width()) / f64::from(extent.height());
+
+    let mut rng = InspectionRng::seed_from_u64(INSPECTION_SEED);
+
+    #[allow(clippy::cast_precision_loss)]
+    let mean_self_dispersal = (0..INSPECTION_SAMPLES)
+        .map(|_| {
+            let indexed_location = habitat.sample_habitable_indexed_location(&mut rng);
+
+            dispersal_sampler
+                .get_self_dispersal_probability_at_location(indexed_location.location(), &habitat)
+                .get()
+        })
+        .sum::<f64>()
+        / (INSPECTION_SAMPLES as f64);
+
+    #[allow(clippy::cast_precision_loss)]
+    let sample_size = sample_size(sample, || {
+        O::sample_habitat(
+            &habitat,
+            OriginPreSampler::all().percentage(sample.percentage),
+            origin_sampler_auxiliary,
+        )
+        .full_upper_bound_size_hint() as f64
+    });
+
+    ScenarioInspection {
+        is_finite: habitat.is_finite(),
+        total_habitat,
+        habitat_density,
+        mean_self_dispersal,
+        sample_size,
+        footprint: ScenarioFootprint {
+            habitat_bytes: size_of::<O::Habitat>(),
+            ..ScenarioFootprint::default()
+        },
+    }
+}
+
+#[allow(dead_code, clippy::cast_precision_loss)]
+fn sample_size(sample: &SampleArgs, habitat_sample_size: impl FnOnce() -> f64) -> f64 {
+    match &sample.origin {
+        SampleOrigin::Habitat => habitat_sample_size(),
+        SampleOrigin::List(lineages) => lineages.len() as f64,
+        SampleOrigin::Bincode(loader) => loader.get_lineages().len() as f64,
+    }
+}
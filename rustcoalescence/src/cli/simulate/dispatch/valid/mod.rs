@@ -1,22 +1,30 @@
 use necsim_core_bond::{NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64};
-use necsim_impls_std::event_log::recorder::EventLogRecorder;
+use necsim_impls_std::event_log::recorder::{EventLogProvenanceHandle, EventLogRecorder};
 use necsim_plugins_core::import::AnyReporterPluginVec;
 
 use crate::{
-    args::config::{
-        algorithm::Algorithm, partitioning::Partitioning, sample::Sample, scenario::Scenario,
+    args::{
+        config::{
+            algorithm::Algorithm, partitioning::Partitioning, sample::Sample, scenario::Scenario,
+        },
+        utils::parse::Config,
     },
     cli::simulate::SimulationOutcome,
 };
 
-use super::super::BufferingSimulateArgsBuilder;
+use super::super::{BufferingSimulateArgsBuilder, ManifestRecorder};
 
 mod algorithm_scenario;
+mod auto;
+mod estimate;
 mod info;
+mod inspect;
 mod launch;
 mod partitioning;
 mod rng;
 
+pub(in super::super) use estimate::estimate;
+
 #[allow(clippy::too_many_arguments)]
 pub(in super::super) fn dispatch(
     partitioning: Partitioning,
@@ -28,9 +36,11 @@ pub(in super::super) fn dispatch(
     scenario: Scenario,
     algorithm: Algorithm,
     pause_before: Option<NonNegativeF64>,
+    event_log_provenance: Option<&EventLogProvenanceHandle>,
 
-    ron_args: &str,
+    config: &Config,
     normalised_args: &mut BufferingSimulateArgsBuilder,
+    manifest: &mut ManifestRecorder,
 ) -> anyhow::Result<SimulationOutcome> {
     partitioning::dispatch(
         partitioning,
@@ -41,7 +51,9 @@ pub(in super::super) fn dispatch(
         scenario,
         algorithm,
         pause_before,
-        ron_args,
+        event_log_provenance,
+        config,
         normalised_args,
+        manifest,
     )
 }
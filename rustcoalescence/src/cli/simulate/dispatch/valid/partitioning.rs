@@ -1,5 +1,5 @@
 use necsim_core_bond::{NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64};
-use necsim_impls_std::event_log::recorder::EventLogRecorder;
+use necsim_impls_std::event_log::recorder::{EventLogProvenanceHandle, EventLogRecorder};
 use necsim_partitioning_core::Partitioning as _;
 use necsim_plugins_core::{import::AnyReporterPluginVec, match_any_reporter_plugin_vec};
 
@@ -8,14 +8,20 @@ use necsim_partitioning_monolithic::MonolithicLocalPartition;
 use necsim_partitioning_mpi::MpiLocalPartition;
 
 use crate::{
-    args::config::{
-        algorithm::Algorithm, partitioning::Partitioning, sample::Sample, scenario::Scenario,
+    args::{
+        config::{
+            algorithm::Algorithm, partitioning::Partitioning, sample::Sample, scenario::Scenario,
+        },
+        utils::parse::Config,
     },
     cli::simulate::SimulationOutcome,
     reporter::DynamicReporterContext,
 };
 
-use super::{super::super::BufferingSimulateArgsBuilder, algorithm_scenario};
+use super::{
+    super::super::{BufferingSimulateArgsBuilder, ManifestRecorder},
+    algorithm_scenario,
+};
 
 #[allow(clippy::too_many_arguments)]
 pub(super) fn dispatch(
@@ -28,9 +34,11 @@ pub(super) fn dispatch(
     scenario: Scenario,
     algorithm: Algorithm,
     pause_before: Option<NonNegativeF64>,
+    event_log_provenance: Option<&EventLogProvenanceHandle>,
 
-    ron_args: &str,
+    config: &Config,
     normalised_args: &mut BufferingSimulateArgsBuilder,
+    manifest: &mut ManifestRecorder,
 ) -> anyhow::Result<SimulationOutcome> {
     match_any_reporter_plugin_vec!(reporters => |reporter| {
         // Initialise the local partition and the simulation
@@ -39,11 +47,13 @@ pub(super) fn dispatch(
                 DynamicReporterContext::new(reporter), event_log, |partition| match partition {
                     MonolithicLocalPartition::Live(partition) => algorithm_scenario::dispatch(
                         *partition, speciation_probability_per_generation, sample, scenario,
-                        algorithm, pause_before, ron_args, normalised_args,
+                        algorithm, pause_before, event_log_provenance, config, normalised_args,
+                        manifest,
                     ),
                     MonolithicLocalPartition::Recorded(partition) => algorithm_scenario::dispatch(
                         *partition, speciation_probability_per_generation, sample, scenario,
-                        algorithm, pause_before, ron_args, normalised_args,
+                        algorithm, pause_before, event_log_provenance, config, normalised_args,
+                        manifest,
                     ),
                 },
             ),
@@ -52,11 +62,13 @@ pub(super) fn dispatch(
                 DynamicReporterContext::new(reporter), event_log, |partition| match partition {
                     MpiLocalPartition::Root(partition) => algorithm_scenario::dispatch(
                         *partition, speciation_probability_per_generation, sample, scenario,
-                        algorithm, pause_before, ron_args, normalised_args,
+                        algorithm, pause_before, event_log_provenance, config, normalised_args,
+                        manifest,
                     ),
                     MpiLocalPartition::Parallel(partition) => algorithm_scenario::dispatch(
                         *partition, speciation_probability_per_generation, sample, scenario,
-                        algorithm, pause_before, ron_args, normalised_args,
+                        algorithm, pause_before, event_log_provenance, config, normalised_args,
+                        manifest,
                     ),
                 },
             ),
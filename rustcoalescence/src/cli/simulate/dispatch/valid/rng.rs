@@ -7,23 +7,29 @@ use necsim_core::{
     reporter::Reporter,
 };
 use necsim_core_bond::NonNegativeF64;
+use necsim_impls_std::event_log::recorder::EventLogProvenanceHandle;
 use necsim_partitioning_core::LocalPartition;
 
 use rustcoalescence_scenarios::Scenario;
 
 use crate::{
-    args::config::{
-        rng::{Base32RngState, Rng as RngArgs},
-        sample::Sample,
+    args::{
+        config::{
+            rng::{Base32RngState, Rng as RngArgs},
+            sample::Sample,
+        },
+        utils::parse::Config,
     },
     cli::simulate::parse,
+    milestone::Milestone,
 };
 
 use super::{
-    super::super::{BufferingSimulateArgsBuilder, SimulationOutcome},
+    super::super::{BufferingSimulateArgsBuilder, ManifestRecorder, SimulationOutcome},
     info,
 };
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn dispatch<
     'p,
     M: MathsCore,
@@ -38,16 +44,20 @@ pub(super) fn dispatch<
     algorithm_args: A::Arguments,
     scenario: O,
     pause_before: Option<NonNegativeF64>,
+    event_log_provenance: Option<&EventLogProvenanceHandle>,
 
-    ron_args: &str,
+    config: &Config,
     normalised_args: &mut BufferingSimulateArgsBuilder,
+    manifest: &mut ManifestRecorder,
 ) -> anyhow::Result<SimulationOutcome>
 where
     Result<AlgorithmOutcome<M, A::Rng>, A::Error>:
         anyhow::Context<AlgorithmOutcome<M, A::Rng>, A::Error>,
 {
+    Milestone::ScenarioBuilt.report();
+
     let rng: A::Rng = match parse::rng::parse_and_normalise(
-        ron_args,
+        config,
         normalised_args,
         &mut A::get_logical_partition(&algorithm_args, &local_partition),
     )? {
@@ -64,6 +74,8 @@ where
         RngArgs::State(state) => state.into(),
     };
 
+    manifest.record_initial_rng(&RngArgs::State(Base32RngState::from(rng.clone())))?;
+
     let result = info::dispatch::<M, A, O, R, P>(
         algorithm_args,
         rng,
@@ -71,11 +83,22 @@ where
         sample,
         pause_before,
         local_partition,
+        event_log_provenance,
+        config.normalised_format(),
         normalised_args,
+        manifest,
     )?;
 
     match result {
-        AlgorithmOutcome::Done { time, steps } => Ok(SimulationOutcome::Done { time, steps }),
+        AlgorithmOutcome::Done {
+            time,
+            steps,
+            rng: final_rng,
+        } => {
+            manifest.record_final_rng(&RngArgs::State(Base32RngState::from(final_rng)))?;
+
+            Ok(SimulationOutcome::Done { time, steps })
+        },
         AlgorithmOutcome::Paused {
             time,
             steps,
@@ -83,7 +106,10 @@ where
             rng: paused_rng,
             ..
         } => {
-            normalised_args.rng(&RngArgs::State(Base32RngState::from(paused_rng)));
+            let paused_rng = RngArgs::State(Base32RngState::from(paused_rng));
+
+            normalised_args.rng(&paused_rng);
+            manifest.record_final_rng(&paused_rng)?;
 
             Ok(SimulationOutcome::Paused {
                 time,
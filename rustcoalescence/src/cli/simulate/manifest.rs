@@ -0,0 +1,187 @@
+use std::{
+    fs::File,
+    io,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::cli::info::FEATURES;
+
+use super::{BufferingSimulateArgsBuilder, SimulationOutcome, SimulationOutcomeSummary};
+
+/// Records the provenance of a simulation while it runs, from which its
+/// manifest is written once it has finished or paused
+pub(super) struct ManifestRecorder {
+    started: SystemTime,
+    config: Option<serde_json::Value>,
+    initial_rng: Option<serde_json::Value>,
+    final_rng: Option<serde_json::Value>,
+    maps: Vec<PathBuf>,
+    libraries: Vec<PathBuf>,
+}
+
+impl ManifestRecorder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            started: SystemTime::now(),
+            config: None,
+            initial_rng: None,
+            final_rng: None,
+            maps: Vec::new(),
+            libraries: Vec::new(),
+        }
+    }
+
+    pub fn record_inputs(&mut self, maps: Vec<&Path>, libraries: Vec<&Path>) {
+        self.maps = maps.into_iter().map(Path::to_owned).collect();
+        self.libraries = libraries.into_iter().map(Path::to_owned).collect();
+    }
+
+    pub fn write(self, path: &Path, outcome: &SimulationOutcome) -> Result<()> {
+        let finished = SystemTime::now();
+
+        let manifest = SimulationManifest {
+            software: SoftwareManifest {
+                name: env!("CARGO_PKG_NAME"),
+                version: env!("CARGO_PKG_VERSION"),
+                commit: match git_version::git_version!(fallback = "unknown") {
+                    "unknown" => None,
+                    commit => Some(commit),
+                },
+                features: FEATURES
+                    .iter()
+                    .filter_map(|(feature, enabled)| enabled.then_some(*feature))
+                    .collect(),
+                rustc: necsim_plugins_core::RUSTC_VERSION,
+            },
+            host: gethostname::gethostname().to_string_lossy().into_owned(),
+            started: humantime::format_rfc3339_millis(self.started).to_string(),
+            finished: humantime::format_rfc3339_millis(finished).to_string(),
+            config: self.config,
+            outcome: SimulationOutcomeSummary::from(outcome),
+            rng: RngManifest {
+                initial: self.initial_rng,
+                r#final: self.final_rng,
+            },
+            inputs: InputsManifest {
+                maps: self
+                    .maps
+                    .into_iter()
+                    .map(InputManifest::try_hash)
+                    .collect::<Result<_>>()?,
+                libraries: self
+                    .libraries
+                    .into_iter()
+                    .map(InputManifest::try_hash)
+                    .collect::<Result<_>>()?,
+            },
+        };
+
+        let file = File::create(path).with_context(|| format!("Failed to create {path:?}."))?;
+
+        serde_json::to_writer_pretty(file, &manifest)
+            .with_context(|| format!("Failed to write the simulation manifest to {path:?}."))
+    }
+}
+
+// The algorithm dispatch records the config and rng states, but is not
+//  compiled without any algorithm features
+#[cfg_attr(
+    not(any(
+        feature = "gillespie-algorithms",
+        feature = "independent-algorithm",
+        feature = "cuda-algorithm"
+    )),
+    allow(dead_code)
+)]
+impl ManifestRecorder {
+    pub fn record_config(
+        &mut self,
+        normalised_args: &BufferingSimulateArgsBuilder,
+    ) -> Result<&serde_json::Value> {
+        let config = normalised_args
+            .build()
+            .map_err(anyhow::Error::new)
+            .and_then(|simulate_args| {
+                serde_json::to_value(simulate_args).map_err(anyhow::Error::new)
+            })
+            .context("Failed to record the normalised simulation config.")?;
+
+        Ok(self.config.insert(config))
+    }
+
+    pub fn record_initial_rng<S: Serialize>(&mut self, rng: &S) -> Result<()> {
+        self.initial_rng =
+            Some(serde_json::to_value(rng).context("Failed to record the initial rng state.")?);
+
+        Ok(())
+    }
+
+    pub fn record_final_rng<S: Serialize>(&mut self, rng: &S) -> Result<()> {
+        self.final_rng =
+            Some(serde_json::to_value(rng).context("Failed to record the final rng state.")?);
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+struct SimulationManifest {
+    software: SoftwareManifest,
+    host: String,
+    started: String,
+    finished: String,
+    config: Option<serde_json::Value>,
+    outcome: SimulationOutcomeSummary,
+    rng: RngManifest,
+    inputs: InputsManifest,
+}
+
+#[derive(Serialize)]
+struct SoftwareManifest {
+    name: &'static str,
+    version: &'static str,
+    commit: Option<&'static str>,
+    features: Vec<&'static str>,
+    rustc: &'static str,
+}
+
+#[derive(Serialize)]
+struct RngManifest {
+    initial: Option<serde_json::Value>,
+    /// The final rng state of a paused simulation is the state from which it
+    /// resumes, while a partition records the final state of its rng stream
+    r#final: Option<serde_json::Value>,
+}
+
+#[derive(Serialize)]
+struct InputsManifest {
+    maps: Vec<InputManifest>,
+    libraries: Vec<InputManifest>,
+}
+
+#[derive(Serialize)]
+struct InputManifest {
+    path: PathBuf,
+    sha256: String,
+}
+
+impl InputManifest {
+    fn try_hash(path: PathBuf) -> Result<Self> {
+        let mut hasher = Sha256::new();
+
+        File::open(&path)
+            .and_then(|mut file| io::copy(&mut file, &mut hasher))
+            .with_context(|| format!("Failed to hash the input file {path:?}."))?;
+
+        Ok(Self {
+            path,
+            sha256: format!("{:x}", hasher.finalize()),
+        })
+    }
+}
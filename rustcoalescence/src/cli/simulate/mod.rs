@@ -1,20 +1,39 @@
+use std::fs::File;
+
+use anyhow::Context;
 use derive_builder::Builder;
 use log::LevelFilter;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use necsim_core::lineage::Lineage;
 use necsim_core_bond::NonNegativeF64;
+use necsim_impls_std::event_log::recorder::EventLogRecorder;
 
-use crate::args::{cli::CommandArgs, utils::ser::BufferingSerializeResult};
+use crate::{
+    args::{
+        cli::SimulateArgs,
+        config::partitioning::Partitioning,
+        utils::{
+            parse::{try_print_as, Config},
+            schema::ConfigSchema,
+            ser::BufferingSerializeResult,
+        },
+    },
+    milestone::Milestone,
+};
 
 mod dispatch;
+mod manifest;
 mod parse;
 mod pause;
 
-use dispatch::dispatch;
+use dispatch::{dispatch, estimate};
+use manifest::ManifestRecorder;
 
-#[allow(dead_code)]
-enum SimulationOutcome {
+/// Outcome of a simulation, which has either finished or paused
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub enum SimulationOutcome {
     Done {
         time: NonNegativeF64,
         steps: u64,
@@ -22,19 +41,86 @@ enum SimulationOutcome {
     Paused {
         time: NonNegativeF64,
         steps: u64,
+        /// Lineages which remained active when the simulation paused
         lineages: Vec<Lineage>,
     },
 }
 
+/// Summary of a simulation's outcome, which is written to the `--outcome` file
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct SimulationOutcomeSummary {
+    pub paused: bool,
+    pub time: f64,
+    pub steps: u64,
+}
+
+impl From<&SimulationOutcome> for SimulationOutcomeSummary {
+    fn from(outcome: &SimulationOutcome) -> Self {
+        match outcome {
+            SimulationOutcome::Done { time, steps } => Self {
+                paused: false,
+                time: time.get(),
+                steps: *steps,
+            },
+            SimulationOutcome::Paused { time, steps, .. } => Self {
+                paused: true,
+                time: time.get(),
+                steps: *steps,
+            },
+        }
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the simulation arguments are invalid, or the
+/// simulation fails.
 #[allow(clippy::module_name_repetitions)]
-pub fn simulate_with_logger(simulate_args: CommandArgs) -> anyhow::Result<()> {
+pub fn simulate_with_logger(simulate_args: SimulateArgs) -> anyhow::Result<()> {
     log::set_max_level(LevelFilter::Info);
 
-    let ron_args = simulate_args.into_config_string();
-    parse::fields::parse_and_normalise(&ron_args)?;
+    let mut manifest = ManifestRecorder::new();
+
+    let outcome = simulate_and_record(
+        &simulate_args.command.into_config()?,
+        simulate_args.dry_run,
+        &mut manifest,
+    )?;
+
+    if let (Some(path), Some(outcome)) = (&simulate_args.outcome, &outcome) {
+        let file = File::create(path).with_context(|| format!("Failed to create {path:?}."))?;
+
+        serde_json::to_writer_pretty(file, &SimulationOutcomeSummary::from(outcome))
+            .with_context(|| format!("Failed to write the simulation outcome to {path:?}."))?;
+    }
+
+    if let (Some(path), Some(outcome)) = (&simulate_args.manifest, &outcome) {
+        manifest.write(path, outcome)?;
+    }
+
+    Ok(())
+}
+
+/// Runs the simulation described by the `config`, or only estimates its
+/// cost in a `dry_run`, in which case `None` is returned.
+///
+/// # Errors
+///
+/// Returns an error if the simulation arguments are invalid, or the
+/// simulation fails.
+pub fn simulate(config: &Config, dry_run: bool) -> anyhow::Result<Option<SimulationOutcome>> {
+    simulate_and_record(config, dry_run, &mut ManifestRecorder::new())
+}
+
+fn simulate_and_record(
+    config: &Config,
+    dry_run: bool,
+    manifest: &mut ManifestRecorder,
+) -> anyhow::Result<Option<SimulationOutcome>> {
+    parse::fields::parse_and_normalise(config)?;
     let mut normalised_args = BufferingSimulateArgs::builder();
 
-    let partitioning = parse::partitioning::parse_and_normalise(&ron_args, &mut normalised_args)?;
+    let partitioning = parse::partitioning::parse_and_normalise(config, &mut normalised_args)?;
 
     // Only log to stdout/stderr if the partition is the root partition
     log::set_max_level(if partitioning.is_root() {
@@ -43,25 +129,44 @@ pub fn simulate_with_logger(simulate_args: CommandArgs) -> anyhow::Result<()> {
         log::LevelFilter::Off
     });
 
-    let pause = parse::pause::parse_and_normalise(&ron_args, &mut normalised_args, &partitioning)?;
-    let sample = parse::sample::parse_and_normalise(&ron_args, &mut normalised_args, &pause)?;
+    let pause = parse::pause::parse_and_normalise(config, &mut normalised_args, &partitioning)?;
+    let sample = parse::sample::parse_and_normalise(config, &mut normalised_args, &pause)?;
 
     let speciation_probability_per_generation =
-        parse::speciation::parse_and_normalise(&ron_args, &mut normalised_args)?;
+        parse::speciation::parse_and_normalise(config, &mut normalised_args)?;
 
-    let scenario = parse::scenario::parse_and_normalise(&ron_args, &mut normalised_args)?;
+    let scenario = parse::scenario::parse_and_normalise(config, &mut normalised_args)?;
     let algorithm =
-        parse::algorithm::parse_and_normalise(&ron_args, &mut normalised_args, &partitioning)?;
+        parse::algorithm::parse_and_normalise(config, &mut normalised_args, &partitioning)?;
+
+    // A dry run must not create the event log or load the reporter plugins
+    if dry_run {
+        return estimate(
+            &partitioning,
+            speciation_probability_per_generation,
+            &sample,
+            &scenario,
+            algorithm,
+        )
+        .map(|()| None);
+    }
 
     let event_log = parse::event_log::parse_and_normalise(
-        &ron_args,
+        config,
         &mut normalised_args,
         &partitioning,
         &sample,
         &pause,
     )?;
 
-    let reporters = parse::reporters::parse_and_normalise(&ron_args, &mut normalised_args)?;
+    // The provenance can only be set once the rng has been normalised
+    let event_log_provenance = event_log.as_ref().map(EventLogRecorder::provenance);
+
+    let reporters = parse::reporters::parse_and_normalise(config, &mut normalised_args)?;
+
+    manifest.record_inputs(scenario.maps(), reporters.libraries());
+
+    Milestone::ConfigParsed.report();
 
     // Ensure the dynamic reporter plugin libraries are not deallocated prematurely
     reporters.with_lifetime(|reporters| {
@@ -74,31 +179,125 @@ pub fn simulate_with_logger(simulate_args: CommandArgs) -> anyhow::Result<()> {
             scenario,
             algorithm,
             pause.as_ref().map(|pause| pause.before),
-            &ron_args,
+            event_log_provenance.as_ref(),
+            config,
             &mut normalised_args,
+            manifest,
         )?;
 
         match &result {
-            SimulationOutcome::Done { time, steps } => info!(
-                "The simulation finished at time {} after {} steps.\n",
-                time.get(),
-                steps
-            ),
-            SimulationOutcome::Paused { time, steps, .. } => info!(
-                "The simulation paused at time {} after {} steps.\n",
-                time.get(),
-                steps
-            ),
+            SimulationOutcome::Done { time, steps } => {
+                info!(
+                    "The simulation finished at time {} after {} steps.\n",
+                    time.get(),
+                    steps
+                );
+
+                Milestone::Finished {
+                    time: time.get(),
+                    steps: *steps,
+                }
+                .report();
+            },
+            SimulationOutcome::Paused { time, steps, .. } => {
+                info!(
+                    "The simulation paused at time {} after {} steps.\n",
+                    time.get(),
+                    steps
+                );
+
+                Milestone::Paused {
+                    time: time.get(),
+                    steps: *steps,
+                }
+                .report();
+            },
         }
 
-        if let (Some(pause), SimulationOutcome::Paused { lineages, .. }) = (pause, result) {
+        if let (Some(pause), SimulationOutcome::Paused { lineages, .. }) = (pause, &result) {
             pause::write_resume_config(normalised_args, pause, lineages)?;
         }
 
-        Ok(())
+        Ok(Some(result))
     })
 }
 
+/// Checks the `simulate` config, including that its maps, sample files, and
+/// reporter plugin libraries can be loaded, without running the simulation.
+///
+/// The rng is checked against the rng of the configured algorithm, or against
+/// the `WyHash` rng if the algorithm is chosen automatically. The event log
+/// directory is checked to be empty, but not left behind if it was created,
+/// and the reporters do not create their output files until they are
+/// initialised.
+///
+/// Returns the normalised config, printed in its normalised format.
+///
+/// # Errors
+///
+/// Returns an error if the simulation arguments are invalid.
+pub fn validate_simulate(config: &Config) -> anyhow::Result<String> {
+    parse::fields::parse_and_normalise(config)?;
+    let mut normalised_args = BufferingSimulateArgs::builder();
+
+    let partitioning = parse::partitioning::parse_and_normalise(config, &mut normalised_args)?;
+    let pause = parse::pause::parse_and_normalise(config, &mut normalised_args, &partitioning)?;
+    let sample = parse::sample::parse_and_normalise(config, &mut normalised_args, &pause)?;
+    parse::speciation::parse_and_normalise(config, &mut normalised_args)?;
+    parse::scenario::parse_and_normalise(config, &mut normalised_args)?;
+    let algorithm =
+        parse::algorithm::parse_and_normalise(config, &mut normalised_args, &partitioning)?;
+    parse::rng::validate(
+        config,
+        &mut normalised_args,
+        &algorithm,
+        &mut partitioning.get_partition(),
+    )?;
+
+    let event_log = parse::event_log::parse_and_normalise(
+        config,
+        &mut normalised_args,
+        &partitioning,
+        &sample,
+        &pause,
+    )?;
+
+    // A partitioned simulation writes into one sub-directory per partition
+    if let (Partitioning::Monolithic(_), Some(event_log)) = (&partitioning, event_log) {
+        event_log
+            .assert_empty()
+            .context("Failed to create the event log.")?;
+    }
+
+    parse::reporters::parse_and_normalise(config, &mut normalised_args)?;
+
+    normalised_args
+        .build()
+        .map_err(anyhow::Error::new)
+        .and_then(|simulate_args| try_print_as(&simulate_args, config.normalised_format()))
+        .context("Failed to normalise the simulation config.")
+}
+
+/// Traces the JSON Schema of the `simulate` config from its config types.
+#[must_use]
+pub fn simulate_schema() -> serde_json::Value {
+    let mut schema = ConfigSchema::new("Simulate");
+
+    // The overall config structure is traced first to order its fields
+    parse::fields::trace_schema(&mut schema);
+    parse::speciation::trace_schema(&mut schema);
+    parse::sample::trace_schema(&mut schema);
+    parse::pause::trace_schema(&mut schema);
+    parse::rng::trace_schema(&mut schema);
+    parse::scenario::trace_schema(&mut schema);
+    parse::algorithm::trace_schema(&mut schema);
+    parse::partitioning::trace_schema(&mut schema);
+    parse::event_log::trace_schema(&mut schema);
+    parse::reporters::trace_schema(&mut schema);
+
+    schema.to_json()
+}
+
 #[derive(Serialize, Builder)]
 #[builder(setter(into))]
 #[serde(rename = "Simulate")]
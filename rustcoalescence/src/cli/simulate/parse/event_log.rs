@@ -11,13 +11,16 @@ use crate::args::{
         pause::Pause,
         sample::{Sample, SampleMode},
     },
-    utils::parse::try_parse_state,
+    utils::{
+        parse::{try_parse_state, Config},
+        schema::ConfigSchema,
+    },
 };
 
 use super::super::BufferingSimulateArgsBuilder;
 
 pub(in super::super) fn parse_and_normalise(
-    ron_args: &str,
+    config: &Config,
     normalised_args: &mut BufferingSimulateArgsBuilder,
     partitioning: &Partitioning,
     sample: &Sample,
@@ -32,7 +35,7 @@ pub(in super::super) fn parse_and_normalise(
     }
 
     let SimulateArgsEventLogOnly { event_log } =
-        try_parse_state("simulate", ron_args, &mut event_log_check)?;
+        try_parse_state("simulate", config, &mut event_log_check)?;
 
     normalised_args.log(&event_log);
 
@@ -48,6 +51,10 @@ pub(in super::super) fn parse_and_normalise(
     Ok(event_log)
 }
 
+pub(in super::super) fn trace_schema(schema: &mut ConfigSchema) {
+    schema.trace_state::<SimulateArgsEventLogOnly, _>(&mut (Ok(()), Ok(())));
+}
+
 struct SimulateArgsEventLogOnly {
     event_log: Option<EventLogRecorder>,
 }
@@ -1,16 +1,23 @@
 use serde::{de::IgnoredAny, Deserialize};
 
-use crate::args::utils::parse::try_parse;
+use crate::args::utils::{
+    parse::{try_parse, Config},
+    schema::ConfigSchema,
+};
 
-pub fn parse_and_normalise(ron_args: &str) -> anyhow::Result<()> {
+pub fn parse_and_normalise(config: &Config) -> anyhow::Result<()> {
     // Check for the overall config stucture
     //  (1) are all required fields defined
     //  (2) are any unknown fields defined
-    let SimulateArgsFields { .. } = try_parse("simulate", ron_args)?;
+    let SimulateArgsFields { .. } = try_parse("simulate", config)?;
 
     Ok(())
 }
 
+pub fn trace_schema(schema: &mut ConfigSchema) {
+    schema.trace::<SimulateArgsFields>();
+}
+
 #[derive(Deserialize)]
 #[serde(deny_unknown_fields)]
 #[serde(rename = "Simulate")]
@@ -1,20 +1,30 @@
 use serde::Deserialize;
 
-use crate::args::{config::partitioning::Partitioning, utils::parse::try_parse};
+use crate::args::{
+    config::partitioning::Partitioning,
+    utils::{
+        parse::{try_parse, Config},
+        schema::ConfigSchema,
+    },
+};
 
 use super::super::BufferingSimulateArgsBuilder;
 
 pub(in super::super) fn parse_and_normalise(
-    ron_args: &str,
+    config: &Config,
     normalised_args: &mut BufferingSimulateArgsBuilder,
 ) -> anyhow::Result<Partitioning> {
-    let SimulateArgsPartitioningOnly { partitioning } = try_parse("simulate", ron_args)?;
+    let SimulateArgsPartitioningOnly { partitioning } = try_parse("simulate", config)?;
 
     normalised_args.partitioning(&partitioning);
 
     Ok(partitioning)
 }
 
+pub(in super::super) fn trace_schema(schema: &mut ConfigSchema) {
+    schema.trace::<SimulateArgsPartitioningOnly>();
+}
+
 #[derive(Deserialize)]
 #[serde(rename = "Simulate")]
 struct SimulateArgsPartitioningOnly {
@@ -2,24 +2,31 @@ use necsim_partitioning_core::partition::Partition;
 
 use crate::args::{
     config::{partitioning::Partitioning, pause::Pause},
-    utils::parse::try_parse_state,
+    utils::{
+        parse::{try_parse_state, Config},
+        schema::ConfigSchema,
+    },
 };
 
 use super::super::BufferingSimulateArgsBuilder;
 
 pub(in super::super) fn parse_and_normalise(
-    ron_args: &str,
+    config: &Config,
     normalised_args: &mut BufferingSimulateArgsBuilder,
     partitioning: &Partitioning,
 ) -> anyhow::Result<Option<Pause>> {
     let SimulateArgsPauseOnly { pause } =
-        try_parse_state("simulate", ron_args, &mut partitioning.get_partition())?;
+        try_parse_state("simulate", config, &mut partitioning.get_partition())?;
 
     normalised_args.pause(&pause);
 
     Ok(pause)
 }
 
+pub(in super::super) fn trace_schema(schema: &mut ConfigSchema) {
+    schema.trace_state::<SimulateArgsPauseOnly, _>(&mut Partition::monolithic());
+}
+
 #[derive(DeserializeState)]
 #[serde(deserialize_state = "Partition")]
 #[serde(rename = "Simulate")]
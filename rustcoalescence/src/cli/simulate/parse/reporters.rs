@@ -2,23 +2,31 @@ use serde::Deserialize;
 
 use necsim_plugins_core::import::AnyReporterPluginVec;
 
-use crate::args::utils::parse::try_parse;
+use crate::args::utils::{
+    parse::{try_parse, Config},
+    schema::ConfigSchema,
+};
 
 use super::super::BufferingSimulateArgsBuilder;
 
 pub(in super::super) fn parse_and_normalise(
-    ron_args: &str,
+    config: &Config,
     normalised_args: &mut BufferingSimulateArgsBuilder,
 ) -> anyhow::Result<AnyReporterPluginVec> {
-    let SimulateArgsReportersOnly { reporters } = try_parse("simulate", ron_args)?;
+    let SimulateArgsReportersOnly { reporters } = try_parse("simulate", config)?;
 
     normalised_args.reporters(&reporters);
 
     Ok(reporters)
 }
 
+pub(in super::super) fn trace_schema(schema: &mut ConfigSchema) {
+    schema.trace::<SimulateArgsReportersOnly>();
+}
+
 #[derive(Deserialize)]
 #[serde(rename = "Simulate")]
 struct SimulateArgsReportersOnly {
+    #[serde(deserialize_with = "crate::reporter::deserialize_reporters")]
     reporters: AnyReporterPluginVec,
 }
@@ -1,23 +1,72 @@
 use necsim_core::cogs::{MathsCore, RngCore};
+use necsim_impls_no_std::cogs::{maths::intrinsics::IntrinsicsMathsCore, rng::wyhash::WyHash};
 use necsim_partitioning_core::partition::Partition;
 
-use crate::args::{config::rng::Rng, utils::parse::try_parse_state};
+#[cfg(feature = "gillespie-algorithms")]
+use necsim_impls_std::cogs::rng::pcg::Pcg;
+
+use crate::args::{
+    config::{algorithm::Algorithm, rng::Rng},
+    utils::{
+        parse::{try_parse_state, Config},
+        schema::ConfigSchema,
+    },
+};
 
 use super::super::BufferingSimulateArgsBuilder;
 
-#[allow(dead_code)]
 pub(in super::super) fn parse_and_normalise<M: MathsCore, G: RngCore<M>>(
-    ron_args: &str,
+    config: &Config,
     normalised_args: &mut BufferingSimulateArgsBuilder,
     partition: &mut Partition,
 ) -> anyhow::Result<Rng<M, G>> {
-    let SimulateArgsRngOnly { rng } = try_parse_state("simulate", ron_args, partition)?;
+    let SimulateArgsRngOnly { rng } = try_parse_state("simulate", config, partition)?;
 
     normalised_args.rng(&rng);
 
     Ok(rng)
 }
 
+/// Checks the rng config with the rng of the `algorithm`, since the rng is
+/// otherwise only parsed once the algorithm has been dispatched
+pub(in super::super) fn validate(
+    config: &Config,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+    #[cfg_attr(not(feature = "gillespie-algorithms"), allow(unused_variables))]
+    algorithm: &Algorithm,
+    partition: &mut Partition,
+) -> anyhow::Result<()> {
+    #[cfg(feature = "gillespie-algorithms")]
+    if matches!(
+        algorithm,
+        Algorithm::Gillespie(_) | Algorithm::EventSkipping(_)
+    ) {
+        return parse_and_normalise::<IntrinsicsMathsCore, Pcg<IntrinsicsMathsCore>>(
+            config,
+            normalised_args,
+            partition,
+        )
+        .map(|_| ());
+    }
+
+    // The independent and CUDA algorithms share the rng state of `WyHash`,
+    //  which is also checked if the algorithm is only chosen automatically
+
+    parse_and_normalise::<IntrinsicsMathsCore, WyHash<IntrinsicsMathsCore>>(
+        config,
+        normalised_args,
+        partition,
+    )
+    .map(|_| ())
+}
+
+pub(in super::super) fn trace_schema(schema: &mut ConfigSchema) {
+    // The shape of the rng config does not depend on the algorithm's rng
+    type SchemaRngArgs = SimulateArgsRngOnly<IntrinsicsMathsCore, WyHash<IntrinsicsMathsCore>>;
+
+    schema.trace_state::<SchemaRngArgs, _>(&mut Partition::monolithic());
+}
+
 #[derive(DeserializeState)]
 #[serde(bound = "")]
 #[serde(rename = "Simulate")]
@@ -1,22 +1,31 @@
 use crate::args::{
     config::{pause::Pause, sample::Sample},
-    utils::parse::try_parse_state,
+    utils::{
+        parse::{try_parse_state, Config},
+        schema::ConfigSchema,
+    },
 };
 
 use super::super::BufferingSimulateArgsBuilder;
 
 pub(in super::super) fn parse_and_normalise<'a>(
-    ron_args: &'a str,
+    config: &'a Config,
     normalised_args: &mut BufferingSimulateArgsBuilder,
     mut pause: &'a Option<Pause>,
 ) -> anyhow::Result<Sample> {
-    let SimulateArgsSampleOnly { sample } = try_parse_state("simulate", ron_args, &mut pause)?;
+    let SimulateArgsSampleOnly { sample } = try_parse_state("simulate", config, &mut pause)?;
 
     normalised_args.sample(&sample);
 
     Ok(sample)
 }
 
+pub(in super::super) fn trace_schema(schema: &mut ConfigSchema) {
+    let no_pause: Option<Pause> = None;
+
+    schema.trace_state::<SimulateArgsSampleOnly, _>(&mut &no_pause);
+}
+
 #[derive(DeserializeState)]
 #[serde(deserialize_state = "&'de Option<Pause>")]
 #[serde(rename = "Simulate")]
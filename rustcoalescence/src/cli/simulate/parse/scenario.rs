@@ -1,20 +1,30 @@
 use serde::Deserialize;
 
-use crate::args::{config::scenario::Scenario, utils::parse::try_parse};
+use crate::args::{
+    config::scenario::Scenario,
+    utils::{
+        parse::{try_parse, Config},
+        schema::ConfigSchema,
+    },
+};
 
 use super::super::BufferingSimulateArgsBuilder;
 
 pub(in super::super) fn parse_and_normalise(
-    ron_args: &str,
+    config: &Config,
     normalised_args: &mut BufferingSimulateArgsBuilder,
 ) -> anyhow::Result<Scenario> {
-    let SimulateArgsScenarioOnly { scenario } = try_parse("simulate", ron_args)?;
+    let SimulateArgsScenarioOnly { scenario } = try_parse("simulate", config)?;
 
     normalised_args.scenario(&scenario);
 
     Ok(scenario)
 }
 
+pub(in super::super) fn trace_schema(schema: &mut ConfigSchema) {
+    schema.trace::<SimulateArgsScenarioOnly>();
+}
+
 #[derive(Deserialize)]
 #[serde(rename = "Simulate")]
 struct SimulateArgsScenarioOnly {
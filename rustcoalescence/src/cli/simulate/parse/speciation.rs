@@ -2,23 +2,30 @@ use serde::Deserialize;
 
 use necsim_core_bond::OpenClosedUnitF64 as PositiveUnitF64;
 
-use crate::args::utils::parse::try_parse;
+use crate::args::utils::{
+    parse::{try_parse, Config},
+    schema::ConfigSchema,
+};
 
 use super::super::BufferingSimulateArgsBuilder;
 
 pub(in super::super) fn parse_and_normalise(
-    ron_args: &str,
+    config: &Config,
     normalised_args: &mut BufferingSimulateArgsBuilder,
 ) -> anyhow::Result<PositiveUnitF64> {
     let SimulateArgsSpeciationOnly {
         speciation_probability_per_generation,
-    } = try_parse("simulate", ron_args)?;
+    } = try_parse("simulate", config)?;
 
     normalised_args.speciation(&speciation_probability_per_generation);
 
     Ok(speciation_probability_per_generation)
 }
 
+pub(in super::super) fn trace_schema(schema: &mut ConfigSchema) {
+    schema.trace::<SimulateArgsSpeciationOnly>();
+}
+
 #[derive(Deserialize)]
 #[serde(rename = "Simulate")]
 struct SimulateArgsSpeciationOnly {
@@ -20,13 +20,13 @@ use super::BufferingSimulateArgsBuilder;
 pub(super) fn write_resume_config(
     mut normalised_args: BufferingSimulateArgsBuilder,
     pause: Pause,
-    lineages: Vec<Lineage>,
+    lineages: &[Lineage],
 ) -> Result<()> {
     let resume_str = normalised_args
         .sample(&Sample {
             percentage: ClosedUnitF64::one(),
             origin: match pause.destiny {
-                SampleDestiny::List => SampleOrigin::List(lineages),
+                SampleDestiny::List => SampleOrigin::List(lineages.to_vec()),
                 SampleDestiny::Bincode(lineage_file) => {
                     let path = lineage_file.path().to_owned();
 
@@ -0,0 +1,521 @@
+use std::{
+    collections::BTreeSet,
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+use anyhow::{Context, Result};
+use log::LevelFilter;
+
+use crate::{
+    args::{
+        cli::SweepArgs,
+        config::sweep::{SweepAxes, SweepParameters, SweepValue},
+        utils::parse::{try_parse, Config, ConfigFormat},
+    },
+    cli::simulate::SimulationOutcomeSummary,
+};
+
+/// Placeholder for the ID of a run
+const RUN_PLACEHOLDER: &str = "run";
+/// Placeholder for the output directory of a run
+const OUTPUT_PLACEHOLDER: &str = "output";
+
+struct SweepRun {
+    id: String,
+    parameters: SweepParameters,
+    directory: PathBuf,
+}
+
+enum SweepRunOutcome {
+    Finished(SimulationOutcomeSummary),
+    Failed,
+}
+
+/// # Errors
+///
+/// Returns an error if the sweep config is invalid, or the runs cannot be
+/// prepared or launched.
+#[allow(clippy::module_name_repetitions, clippy::needless_pass_by_value)]
+pub fn sweep_with_logger(sweep_args: SweepArgs) -> Result<()> {
+    log::set_max_level(LevelFilter::Info);
+
+    let axes_config = Config::from_file(&sweep_args.axes)?;
+    let axes: SweepAxes = try_parse("sweep", &axes_config)?;
+    let parameters = axes.expand().map_err(anyhow::Error::msg)?;
+
+    anyhow::ensure!(!parameters.is_empty(), "The sweep axes contain no runs.");
+
+    // The base config is only a template until its placeholders are replaced
+    let template = Config::from_file(&sweep_args.config)?;
+    let format = template.format();
+
+    check_placeholders(template.source(), &parameters[0])?;
+
+    fs::create_dir_all(&sweep_args.output)
+        .with_context(|| format!("Failed to create {:?}.", sweep_args.output))?;
+
+    anyhow::ensure!(
+        fs::read_dir(&sweep_args.output)?.next().is_none(),
+        "{:?} is not an empty directory.",
+        sweep_args.output
+    );
+
+    let runs = prepare_runs(template.source(), format, parameters, &sweep_args.output)?;
+
+    let workers = sweep_args.workers.get().min(runs.len());
+
+    info!(
+        "The sweep will perform {} run(s) in {:?} with {} worker process(es).",
+        runs.len(),
+        sweep_args.output,
+        workers
+    );
+
+    // The index is rewritten after every run, so that an interrupted sweep
+    //  still lists the outcomes of its completed runs
+    let index_path = sweep_args.output.join("index.csv");
+    write_index(&index_path, &runs, &[])
+        .with_context(|| format!("Failed to write the sweep index to {index_path:?}."))?;
+
+    let outcomes = perform_runs(&runs, workers, format, &index_path)?;
+
+    write_index(&index_path, &runs, &outcomes)
+        .with_context(|| format!("Failed to write the sweep index to {index_path:?}."))?;
+
+    let failed = outcomes
+        .iter()
+        .filter(|outcome| !matches!(outcome, Some(SweepRunOutcome::Finished(_))))
+        .count();
+
+    if failed > 0 {
+        warn!(
+            "{failed} out of {} run(s) failed, please check their simulate.log files.",
+            runs.len()
+        );
+    }
+
+    info!(
+        "The sweep has completed, its runs are listed in {:?}.",
+        index_path
+    );
+
+    Ok(())
+}
+
+fn prepare_runs(
+    template: &str,
+    format: ConfigFormat,
+    parameters: Vec<SweepParameters>,
+    output: &Path,
+) -> Result<Vec<SweepRun>> {
+    // Zero-pad the run IDs so that they sort in order
+    let width = parameters.len().saturating_sub(1).to_string().len();
+
+    parameters
+        .into_iter()
+        .enumerate()
+        .map(|(i, parameters)| {
+            let id = format!("{i:0width$}");
+            let directory = output.join(&id);
+
+            let config = substitute(template, format, &parameters, &id, &directory)?;
+
+            fs::create_dir(&directory)
+                .with_context(|| format!("Failed to create {directory:?}."))?;
+            fs::write(
+                directory.join(format!("config.{}", format.extension())),
+                config,
+            )
+            .with_context(|| format!("Failed to write the config of run {id}."))?;
+
+            Ok(SweepRun {
+                id,
+                parameters,
+                directory,
+            })
+        })
+        .collect()
+}
+
+fn perform_runs(
+    runs: &[SweepRun],
+    workers: usize,
+    format: ConfigFormat,
+    index_path: &Path,
+) -> Result<Vec<Option<SweepRunOutcome>>> {
+    let executable =
+        std::env::current_exe().context("Failed to locate the rustcoalescence executable.")?;
+
+    let next_run = AtomicUsize::new(0);
+    let outcomes = Mutex::new((0..runs.len()).map(|_| None).collect::<Vec<_>>());
+
+    // Each worker thread launches and waits for one simulation process at a time
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let i = next_run.fetch_add(1, Ordering::Relaxed);
+
+                let Some(run) = runs.get(i) else {
+                    break;
+                };
+
+                let outcome = perform_run(&executable, run, format);
+
+                if let Ok(mut outcomes) = outcomes.lock() {
+                    outcomes[i] = Some(outcome);
+
+                    if let Err(err) = write_index(index_path, runs, &outcomes) {
+                        warn!("Failed to update the sweep index {index_path:?}: {err:#}");
+                    }
+                }
+            });
+        }
+    });
+
+    outcomes
+        .into_inner()
+        .map_err(|_| anyhow::anyhow!("A sweep worker panicked."))
+}
+
+fn perform_run(executable: &Path, run: &SweepRun, format: ConfigFormat) -> SweepRunOutcome {
+    let outcome_path = run.directory.join("outcome.json");
+
+    let outcome = File::create(run.directory.join("simulate.log"))
+        .and_then(|log| {
+            Command::new(executable)
+                .arg("simulate")
+                .arg("--config")
+                .arg(run.directory.join(format!("config.{}", format.extension())))
+                .arg("--outcome")
+                .arg(&outcome_path)
+                .arg("--manifest")
+                .arg(run.directory.join("manifest.json"))
+                .stdout(log.try_clone()?)
+                .stderr(log)
+                .status()
+        })
+        .map_err(anyhow::Error::new)
+        .and_then(|status| {
+            anyhow::ensure!(status.success(), "The simulation exited with {status}.");
+
+            let summary: SimulationOutcomeSummary =
+                serde_json::from_reader(File::open(&outcome_path)?)?;
+
+            Ok(summary)
+        });
+
+    match outcome {
+        Ok(summary) => {
+            info!(
+                "Run {} {} at time {} after {} steps.",
+                run.id,
+                if summary.paused { "paused" } else { "finished" },
+                summary.time,
+                summary.steps
+            );
+
+            SweepRunOutcome::Finished(summary)
+        },
+        Err(err) => {
+            error!("Run {} failed: {:#}", run.id, err);
+
+            SweepRunOutcome::Failed
+        },
+    }
+}
+
+fn check_placeholders(template: &str, parameters: &SweepParameters) -> Result<()> {
+    let mut unused = parameters
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<BTreeSet<_>>();
+
+    for name in placeholders(template) {
+        let name = name?;
+
+        anyhow::ensure!(
+            name == RUN_PLACEHOLDER
+                || name == OUTPUT_PLACEHOLDER
+                || parameters.iter().any(|(parameter, _)| parameter == name),
+            "The config contains the placeholder ${{{name}}}, which is not a swept parameter."
+        );
+
+        unused.remove(name);
+    }
+
+    for name in unused {
+        warn!("The swept parameter {name:?} is not used in the config.");
+    }
+
+    Ok(())
+}
+
+fn substitute(
+    template: &str,
+    format: ConfigFormat,
+    parameters: &SweepParameters,
+    id: &str,
+    directory: &Path,
+) -> Result<String> {
+    let mut config = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("${") {
+        let end = rest[start..]
+            .find('}')
+            .context("The config contains an unclosed ${ placeholder.")?;
+
+        config.push_str(&rest[..start]);
+
+        match &rest[(start + 2)..(start + end)] {
+            RUN_PLACEHOLDER => config.push_str(id),
+            OUTPUT_PLACEHOLDER => {
+                config.push_str(&escape_string(&directory.to_string_lossy(), format)?);
+            },
+            name => match parameters.iter().find(|(parameter, _)| parameter == name) {
+                Some((_, SweepValue::Str(value))) => {
+                    config.push_str(&escape_string(value, format)?);
+                },
+                Some((_, value)) => config.push_str(&value.to_string()),
+                None => anyhow::bail!("The swept parameter {name:?} has no value."),
+            },
+        }
+
+        rest = &rest[(start + end + 1)..];
+    }
+
+    config.push_str(rest);
+
+    Ok(config)
+}
+
+/// Escapes a string value, which is substituted into a double-quoted string
+/// of the config, e.g. `"${output}/species.sqlite"`
+fn escape_string(value: &str, format: ConfigFormat) -> Result<String> {
+    let quoted = match format {
+        ConfigFormat::Ron => ron::to_string(value)?,
+        // JSON escapes are also valid in TOML basic and YAML double-quoted strings
+        ConfigFormat::Toml | ConfigFormat::Json | ConfigFormat::Yaml => {
+            serde_json::to_string(value)?
+        },
+    };
+
+    Ok(String::from(&quoted[1..(quoted.len() - 1)]))
+}
+
+fn placeholders(template: &str) -> impl Iterator<Item = Result<&str>> {
+    template.split("${").skip(1).map(|placeholder| {
+        placeholder
+            .split_once('}')
+            .map(|(name, _)| name)
+            .context("The config contains an unclosed ${ placeholder.")
+    })
+}
+
+/// Writes the index of all `runs`, where runs without an outcome are pending
+fn write_index(path: &Path, runs: &[SweepRun], outcomes: &[Option<SweepRunOutcome>]) -> Result<()> {
+    // The index is replaced atomically, so it is never left half-written
+    let partial_path = path.with_extension("csv.partial");
+
+    let mut writer = BufWriter::new(File::create(&partial_path)?);
+
+    write!(writer, "run")?;
+    for (name, _) in runs.first().map_or(&[][..], |run| &run.parameters[..]) {
+        write!(writer, ",{}", csv_field(name))?;
+    }
+    writeln!(writer, ",status,time,steps")?;
+
+    for (i, run) in runs.iter().enumerate() {
+        write!(writer, "{}", run.id)?;
+        for (_, value) in &run.parameters {
+            write!(writer, ",{}", csv_field(&value.to_string()))?;
+        }
+
+        match outcomes.get(i).and_then(Option::as_ref) {
+            Some(SweepRunOutcome::Finished(SimulationOutcomeSummary {
+                paused,
+                time,
+                steps,
+            })) => writeln!(
+                writer,
+                ",{},{},{}",
+                if *paused { "paused" } else { "done" },
+                time,
+                steps
+            )?,
+            Some(SweepRunOutcome::Failed) => writeln!(writer, ",failed,,")?,
+            None => writeln!(writer, ",pending,,")?,
+        }
+    }
+
+    writer.flush()?;
+    drop(writer);
+
+    fs::rename(partial_path, path)?;
+
+    Ok(())
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        String::from(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use crate::{
+        args::{
+            config::sweep::{SweepParameters, SweepValue},
+            utils::parse::{try_parse, Config, ConfigFormat},
+        },
+        cli::simulate::SimulationOutcomeSummary,
+    };
+
+    use super::{
+        check_placeholders, csv_field, substitute, write_index, SweepRun, SweepRunOutcome,
+    };
+
+    fn parameters() -> SweepParameters {
+        vec![
+            (String::from("speciation"), SweepValue::Float(0.1)),
+            (String::from("seed"), SweepValue::Int(42)),
+            (
+                String::from("name"),
+                SweepValue::Str(String::from("a \"b\" \\ c")),
+            ),
+        ]
+    }
+
+    #[test]
+    fn placeholders_are_substituted() {
+        let config = substitute(
+            "speciation: ${speciation}, rng: Seed(${seed}), id: \"${run}\"",
+            ConfigFormat::Ron,
+            &parameters(),
+            "07",
+            Path::new("runs/07"),
+        )
+        .unwrap();
+
+        assert_eq!(config, "speciation: 0.1, rng: Seed(42), id: \"07\"");
+
+        assert!(substitute(
+            "${missing}",
+            ConfigFormat::Ron,
+            &parameters(),
+            "0",
+            Path::new("0")
+        )
+        .is_err());
+        assert!(substitute(
+            "${seed",
+            ConfigFormat::Ron,
+            &parameters(),
+            "0",
+            Path::new("0")
+        )
+        .is_err());
+
+        assert!(check_placeholders("${seed} ${run} ${output}", &parameters()).is_ok());
+        assert!(check_placeholders("${seed} ${missing}", &parameters()).is_err());
+        assert!(check_placeholders("${seed", &parameters()).is_err());
+    }
+
+    #[test]
+    fn strings_are_escaped_per_format() {
+        let output = Path::new("runs\\\"quoted\"/0");
+
+        for (format, template) in [
+            (
+                ConfigFormat::Ron,
+                "(name: \"${name}\", output: \"${output}\")",
+            ),
+            (
+                ConfigFormat::Toml,
+                "name = \"${name}\"\noutput = \"${output}\"\n",
+            ),
+            (
+                ConfigFormat::Json,
+                "{\"name\": \"${name}\", \"output\": \"${output}\"}",
+            ),
+            (
+                ConfigFormat::Yaml,
+                "name: \"${name}\"\noutput: \"${output}\"\n",
+            ),
+        ] {
+            #[derive(serde::Deserialize)]
+            struct Strings {
+                name: String,
+                output: String,
+            }
+
+            let config = substitute(template, format, &parameters(), "0", output).unwrap();
+            let strings: Strings = try_parse("test", &Config::new(format, config))
+                .unwrap_or_else(|err| panic!("{format}: {err:?}"));
+
+            assert_eq!(strings.name, "a \"b\" \\ c", "{format}");
+            assert_eq!(strings.output, "runs\\\"quoted\"/0", "{format}");
+        }
+    }
+
+    #[test]
+    fn csv_fields_are_quoted() {
+        assert_eq!(csv_field("0.1"), "0.1");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("a \"b\""), "\"a \"\"b\"\"\"");
+        assert_eq!(csv_field("a\nb"), "\"a\nb\"");
+    }
+
+    #[test]
+    fn index_lists_pending_runs() {
+        let dir = tempfile::tempdir().unwrap();
+        let index = dir.path().join("index.csv");
+
+        let runs = (0..3)
+            .map(|i| SweepRun {
+                id: i.to_string(),
+                parameters: vec![(String::from("a,b"), SweepValue::Int(i))],
+                directory: dir.path().join(i.to_string()),
+            })
+            .collect::<Vec<_>>();
+
+        write_index(&index, &runs, &[]).unwrap();
+        assert_eq!(
+            fs::read_to_string(&index).unwrap(),
+            "run,\"a,b\",status,time,steps\n0,0,pending,,\n1,1,pending,,\n2,2,pending,,\n"
+        );
+
+        write_index(
+            &index,
+            &runs,
+            &[
+                None,
+                Some(SweepRunOutcome::Finished(SimulationOutcomeSummary {
+                    paused: false,
+                    time: 1.5,
+                    steps: 7,
+                })),
+                Some(SweepRunOutcome::Failed),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            fs::read_to_string(&index).unwrap(),
+            "run,\"a,b\",status,time,steps\n0,0,pending,,\n1,1,done,1.5,7\n2,2,failed,,\n"
+        );
+
+        assert!(!dir.path().join("index.csv.partial").exists());
+    }
+}
@@ -0,0 +1,83 @@
+use std::{
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use log::LevelFilter;
+
+use crate::{
+    args::{
+        cli::{ValidateArgs, ValidateCommandArgs},
+        utils::parse::{Config, ConfigFormat},
+    },
+    cli::{replay::validate_replay, simulate::validate_simulate},
+};
+
+/// # Errors
+///
+/// Returns an error if the config is invalid, or the files it refers to
+/// cannot be loaded, or the normalised config cannot be written.
+#[allow(clippy::module_name_repetitions)]
+pub fn validate_with_logger(validate_args: ValidateArgs) -> Result<()> {
+    log::set_max_level(LevelFilter::Info);
+
+    match validate_args {
+        ValidateArgs::Simulate(simulate_args) => {
+            let (config, normalised) = into_config(simulate_args)?;
+            let config_str = validate_simulate(&config)?;
+
+            info!("The simulate config is valid.");
+
+            if let Some(path) = normalised {
+                write_normalised(&path, &config_str)?;
+            }
+        },
+        ValidateArgs::Replay(replay_args) => {
+            let (config, normalised) = into_config(replay_args)?;
+            let config_str = validate_replay(&config)?;
+
+            info!("The replay config is valid.");
+
+            if let Some(path) = normalised {
+                write_normalised(&path, &config_str)?;
+            }
+        },
+    }
+
+    Ok(())
+}
+
+fn into_config(validate_args: ValidateCommandArgs) -> Result<(Config, Option<PathBuf>)> {
+    let config = validate_args.command.into_config()?;
+
+    let Some(path) = validate_args.normalised else {
+        return Ok((config, None));
+    };
+
+    // The normalised config is written in the format of its file extension
+    let format = ConfigFormat::from_path(&path).with_context(|| {
+        format!(
+            "The normalised config file {path:?} must have a .ron, .toml, .json, .yaml, or .yml \
+             extension."
+        )
+    })?;
+
+    Ok((config.normalise_to(format), Some(path)))
+}
+
+fn write_normalised(path: &Path, config_str: &str) -> Result<()> {
+    let mut file = File::options()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .with_context(|| format!("Failed to create the normalised config file {path:?}."))?;
+
+    writeln!(file, "{}", config_str.trim_end())
+        .with_context(|| format!("Failed to write the normalised config to {path:?}."))?;
+
+    info!("The normalised config has been written to {path:?}.");
+
+    Ok(())
+}
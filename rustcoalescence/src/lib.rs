@@ -0,0 +1,33 @@
+#![deny(clippy::pedantic)]
+#![feature(unwrap_infallible)]
+#![feature(split_array)]
+#![feature(result_flattening)]
+
+#[macro_use]
+extern crate serde_derive_state;
+
+#[macro_use]
+extern crate log;
+
+mod args;
+mod cli;
+mod milestone;
+mod minimal_logger;
+mod reporter;
+
+pub use args::{
+    cli::{LogFormat, RustcoalescenceArgs, RustcoalescenceCommand},
+    utils::parse::{Config, ConfigFormat},
+};
+pub use cli::{
+    event_log::event_log_with_logger,
+    info::info_with_logger,
+    replay::{replay, replay_schema, replay_with_logger, validate_replay},
+    schema::schema_with_logger,
+    simulate::{
+        simulate, simulate_schema, simulate_with_logger, validate_simulate, SimulationOutcome,
+    },
+    sweep::sweep_with_logger,
+    validate::validate_with_logger,
+};
+pub use minimal_logger::MinimalLogger;
@@ -1,41 +1,49 @@
 #![deny(clippy::pedantic)]
-#![feature(unwrap_infallible)]
-#![feature(split_array)]
-#![feature(result_flattening)]
-
-#[macro_use]
-extern crate serde_derive_state;
-
-#[macro_use]
-extern crate log;
 
 use anyhow::{Context, Result};
 use clap::Parser;
 
-mod args;
-mod cli;
-mod minimal_logger;
-mod reporter;
-
-use args::cli::RustcoalescenceArgs;
-use minimal_logger::MinimalLogger;
-
-static MINIMAL_LOGGER: MinimalLogger = MinimalLogger;
+use rustcoalescence::{LogFormat, MinimalLogger, RustcoalescenceArgs, RustcoalescenceCommand};
 
 fn main() -> Result<()> {
-    // Set up the minimal logger to stdout/stderr
-    log::set_logger(&MINIMAL_LOGGER)?;
-
     // Parse and validate all command line arguments
     let args = RustcoalescenceArgs::parse();
 
-    match args {
-        RustcoalescenceArgs::Simulate(simulate_args) => {
-            cli::simulate::simulate_with_logger(simulate_args)
+    // Set up the minimal logger to stdout/stderr
+    log::set_boxed_logger(Box::new(MinimalLogger::new(args.log_format)))?;
+
+    let result = match args.command {
+        RustcoalescenceCommand::Simulate(simulate_args) => {
+            rustcoalescence::simulate_with_logger(simulate_args)
                 .context("Failed to initialise or perform the simulation.")
         },
-        RustcoalescenceArgs::Replay(replay_args) => {
-            cli::replay::replay_with_logger(replay_args).context("Failed to replay the simulation.")
+        RustcoalescenceCommand::Replay(replay_args) => {
+            rustcoalescence::replay_with_logger(replay_args)
+                .context("Failed to replay the simulation.")
+        },
+        RustcoalescenceCommand::Sweep(sweep_args) => rustcoalescence::sweep_with_logger(sweep_args)
+            .context("Failed to perform the parameter sweep."),
+        RustcoalescenceCommand::Validate(validate_args) => {
+            rustcoalescence::validate_with_logger(validate_args)
+                .context("Failed to validate the config.")
+        },
+        RustcoalescenceCommand::Schema(schema_args) => {
+            rustcoalescence::schema_with_logger(schema_args)
+                .context("Failed to generate the config schema.")
+        },
+        RustcoalescenceCommand::Info(info_args) => rustcoalescence::info_with_logger(info_args)
+            .context("Failed to inspect rustcoalescence."),
+        RustcoalescenceCommand::Log(log_args) => rustcoalescence::event_log_with_logger(log_args)
+            .context("Failed to process the event log."),
+    };
+
+    match result {
+        // Report the error as a log record such that it can also be parsed
+        Err(err) if args.log_format == LogFormat::Json => {
+            log::error!("{err:?}");
+
+            std::process::exit(1)
         },
+        result => result,
     }
 }
@@ -0,0 +1,100 @@
+use std::{collections::BTreeMap, path::Path};
+
+use serde::Serialize;
+
+pub use necsim_plugins_core::milestone::MILESTONE_TARGET;
+
+/// Lifecycle milestone of a simulation or replay, or the summary of an event
+/// log command
+#[derive(Serialize)]
+#[serde(tag = "milestone", rename_all = "snake_case")]
+pub enum Milestone<'a> {
+    ConfigParsed,
+    #[allow(dead_code)]
+    ScenarioBuilt,
+    #[allow(dead_code)]
+    SimulationStarted {
+        config: &'a serde_json::Value,
+    },
+    #[allow(dead_code)]
+    Estimated {
+        algorithm: &'a str,
+        sample_size: f64,
+        habitat_bytes: f64,
+        dispersal_bytes: f64,
+        lineage_store_bytes: f64,
+        events: f64,
+        runtime_seconds: f64,
+        processing_units: f64,
+    },
+    ReplayStarted {
+        config: &'a serde_json::Value,
+    },
+    Paused {
+        time: f64,
+        steps: u64,
+    },
+    Finished {
+        time: f64,
+        steps: u64,
+    },
+    LogCompacted {
+        output: &'a Path,
+        input_segments: usize,
+        output_segments: usize,
+        read: u64,
+        duplicates: u64,
+        speciation: u64,
+        dispersal: u64,
+        min_time: Option<f64>,
+        max_time: Option<f64>,
+    },
+    LogInspected {
+        segments: usize,
+        events: usize,
+        runs: Vec<Option<String>>,
+        formats: &'a BTreeMap<String, usize>,
+        min_time: Option<f64>,
+        max_time: Option<f64>,
+        speciation_segments: usize,
+        dispersal_segments: usize,
+        scanned_speciation: Option<u64>,
+        scanned_dispersal: Option<u64>,
+        damaged: &'a [String],
+        overlaps: usize,
+        unreadable: Vec<String>,
+    },
+    LogTraced {
+        output: &'a Path,
+        lineages: usize,
+        missing: usize,
+        incomplete: usize,
+    },
+}
+
+impl<'a> Milestone<'a> {
+    pub fn report(&self) {
+        if !milestones_enabled() {
+            return;
+        }
+
+        if let Ok(milestone) = serde_json::to_string(self) {
+            info!(target: MILESTONE_TARGET, "{milestone}");
+        }
+    }
+}
+
+/// Checks whether milestones are reported, which they are with
+/// `--log-format json`
+pub fn milestones_enabled() -> bool {
+    log_enabled!(target: MILESTONE_TARGET, log::Level::Info)
+}
+
+/// Checks whether the plain-text banners, e.g. the normalised configuration,
+/// are printed to stdout
+///
+/// With `--log-format json`, the banners are replaced by milestones, so that
+/// stdout only contains the reporters' output.
+pub fn banners_enabled() -> bool {
+    log_enabled!(log::Level::Info) && !milestones_enabled()
+}
@@ -1,34 +1,92 @@
+use std::time::SystemTime;
+
 use colored::Colorize;
 use log::{Level, LevelFilter, Metadata, Record};
 
-pub struct MinimalLogger;
+use crate::{args::cli::LogFormat, milestone::MILESTONE_TARGET};
+
+pub struct MinimalLogger {
+    format: LogFormat,
+}
+
+impl MinimalLogger {
+    #[must_use]
+    pub const fn new(format: LogFormat) -> Self {
+        Self { format }
+    }
+}
 
 impl log::Log for MinimalLogger {
-    fn enabled(&self, _metadata: &Metadata) -> bool {
-        true
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        // Lifecycle milestones are only printed in the JSON format
+        self.format == LogFormat::Json || metadata.target() != MILESTONE_TARGET
     }
 
     fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
         // Filter out INFO messages from commitlog
         if record.level() == Level::Info && record.target().starts_with("commitlog") {
             return;
         }
 
-        // Inspired by https://github.com/borntyping/rust-simple_logger/blob/ce8ec4bbe5f81cfd2f7a852f68e308369ef7fa5f/src/lib.rs#L199-L203
-        let level_string = match record.level() {
-            Level::Error => record.level().to_string().red(),
-            Level::Warn => record.level().to_string().yellow(),
-            Level::Info => record.level().to_string().cyan(),
-            Level::Debug => record.level().to_string().purple(),
-            Level::Trace => record.level().to_string().normal(),
-        };
-
-        if record.level() > LevelFilter::Error {
-            println!("{level_string:<5} {}", record.args());
-        } else {
-            eprintln!("{level_string:<5} {}", record.args());
+        match self.format {
+            LogFormat::Text => log_text(record),
+            LogFormat::Json => log_json(record),
         }
     }
 
     fn flush(&self) {}
 }
+
+fn log_text(record: &Record) {
+    // Inspired by https://github.com/borntyping/rust-simple_logger/blob/ce8ec4bbe5f81cfd2f7a852f68e308369ef7fa5f/src/lib.rs#L199-L203
+    let level_string = match record.level() {
+        Level::Error => record.level().to_string().red(),
+        Level::Warn => record.level().to_string().yellow(),
+        Level::Info => record.level().to_string().cyan(),
+        Level::Debug => record.level().to_string().purple(),
+        Level::Trace => record.level().to_string().normal(),
+    };
+
+    if record.level() > LevelFilter::Error {
+        println!("{level_string:<5} {}", record.args());
+    } else {
+        eprintln!("{level_string:<5} {}", record.args());
+    }
+}
+
+fn log_json(record: &Record) {
+    let message = record.args().to_string();
+
+    // Milestones are already formatted as JSON objects, while all other
+    //  records are wrapped into one
+    let milestone = if record.target() == MILESTONE_TARGET {
+        serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&message).ok()
+    } else {
+        None
+    };
+
+    let mut object = milestone.unwrap_or_else(|| {
+        let mut object = serde_json::Map::new();
+        object.insert(
+            String::from("level"),
+            serde_json::Value::from(record.level().as_str()),
+        );
+        object.insert(
+            String::from("target"),
+            serde_json::Value::from(record.target()),
+        );
+        object.insert(String::from("message"), serde_json::Value::from(message));
+        object
+    });
+
+    object.insert(
+        String::from("timestamp"),
+        serde_json::Value::from(humantime::format_rfc3339_millis(SystemTime::now()).to_string()),
+    );
+
+    eprintln!("{}", serde_json::Value::Object(object));
+}
@@ -4,7 +4,38 @@ use necsim_core::reporter::{boolean::Boolean, FilteredReporter, Reporter};
 
 use necsim_partitioning_core::context::ReporterContext;
 
-use necsim_plugins_core::import::ReporterPluginVec;
+use necsim_plugins_core::import::{
+    AnyReporterPluginVec, BuiltinReporterPlugins, ReporterPluginVec,
+};
+use serde::{de::DeserializeSeed, Deserializer};
+
+/// Reporter plugins which are statically linked into rustcoalescence, and
+/// can be used as `Builtin(...)` reporters without loading a dynamic library
+pub static BUILTIN_REPORTER_PLUGINS: BuiltinReporterPlugins = BuiltinReporterPlugins::new(&[
+    #[cfg(feature = "common-reporters")]
+    &necsim_plugins_common::BUILTIN_REPORTER_PLUGIN_DECLARATION,
+    #[cfg(feature = "statistics-reporters")]
+    &necsim_plugins_statistics::BUILTIN_REPORTER_PLUGIN_DECLARATION,
+    #[cfg(feature = "species-reporters")]
+    &necsim_plugins_species::BUILTIN_REPORTER_PLUGIN_DECLARATION,
+    #[cfg(feature = "csv-reporters")]
+    &necsim_plugins_csv::BUILTIN_REPORTER_PLUGIN_DECLARATION,
+    #[cfg(feature = "tskit-reporters")]
+    &necsim_plugins_tskit::BUILTIN_REPORTER_PLUGIN_DECLARATION,
+    #[cfg(feature = "metacommunity-reporters")]
+    &necsim_plugins_metacommunity::BUILTIN_REPORTER_PLUGIN_DECLARATION,
+    #[cfg(feature = "json-reporters")]
+    &necsim_plugins_json::BUILTIN_REPORTER_PLUGIN_DECLARATION,
+    #[cfg(feature = "python-extension-reporters")]
+    &necsim_plugins_python::BUILTIN_REPORTER_PLUGIN_DECLARATION,
+]);
+
+/// Deserialises the reporter plugins, which may include built-in reporters
+pub fn deserialize_reporters<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<AnyReporterPluginVec, D::Error> {
+    BUILTIN_REPORTER_PLUGINS.deserialize(deserializer)
+}
 
 pub struct DynamicReporterContext<
     ReportSpeciation: Boolean,